@@ -0,0 +1,42 @@
+//! age ASCII Armor Encoding.
+//!
+//! This is the [`pem`](../pem/index.html) encoding with the `AGE
+//! ENCRYPTED FILE` label: the input is encoded with
+//! [`base64`](../base64/index.html), with padding, in lines of exactly
+//! 64 characters except the last one which may be shorter.
+//!
+//! Decoding follows the strict grammar of PEM and rejects any other
+//! label. In particular, whitespace in the encoded data, empty lines,
+//! text before or after the armor, and non-canonical base64 are
+//! rejected. Both `\n` and `\r\n` line terminators are accepted.
+//!
+//! # Conformance
+//!
+//! [age](https://age-encryption.org/v1) compliant for the ASCII
+//! armor. Encoding uses `\n` line terminators.
+
+use decode::Error;
+use decode::Error::*;
+use pem::Mode;
+
+/// The label of the armor.
+pub const LABEL: &'static str = "AGE ENCRYPTED FILE";
+
+/// Encodes with allocation.
+pub fn encode(input: &[u8]) -> String {
+    ::pem::encode(LABEL, input)
+}
+
+/// Decodes with allocation.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`pem::decode`](../pem/fn.decode.html) in strict mode. It also
+/// returns `Error::BadCharacter(0)` if the label is not
+/// [`LABEL`](constant.LABEL.html).
+pub fn decode(input: &[u8]) -> Result<Vec<u8>, Error> {
+    let (label, output) = try!(::pem::decode(input, Mode::Strict));
+    check!(BadCharacter(0), label == LABEL);
+    Ok(output)
+}
@@ -0,0 +1,141 @@
+//! OpenPGP ASCII Armor.
+//!
+//! The data is encoded with [`base64`](../base64/index.html) in lines
+//! of 64 characters, followed by a checksum line made of `=` and the
+//! encoded CRC-24 of the data. They are preceded by a `-----BEGIN
+//! <kind>-----` line, some `Key: Value` armor headers, and an empty
+//! line. They are followed by a `-----END <kind>-----` line.
+//!
+//! Decoding ignores text before the first boundary and after the last
+//! one. The checksum is optional.
+//!
+//! # Conformance
+//!
+//! [RFC 4880](https://tools.ietf.org/html/rfc4880#section-6.2)
+//! compliant.
+
+use checksum::crc24;
+use decode::Error;
+use decode::Error::*;
+use lines::lines;
+
+/// The length of encoded lines.
+pub const LINE: usize = 64;
+
+/// An armored message.
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub struct Armor {
+    /// The kind, like `PGP MESSAGE` or `PGP SIGNATURE`.
+    pub kind: String,
+
+    /// The armor headers, as key-value pairs.
+    pub headers: Vec<(String, String)>,
+
+    /// The data.
+    pub data: Vec<u8>,
+}
+
+const BEGIN: &'static [u8] = b"-----BEGIN ";
+const END: &'static [u8] = b"-----END ";
+
+fn boundary<'a>(line: &'a [u8], prefix: &[u8]) -> Option<&'a [u8]> {
+    let n = line.len();
+    if n < prefix.len() + 5 || !line.starts_with(prefix) || !line.ends_with(b"-----") {
+        return None;
+    }
+    Some(&line[prefix.len() .. n - 5])
+}
+
+fn checksum(data: &[u8]) -> [u8; 3] {
+    let crc = crc24(data);
+    [(crc >> 16) as u8, (crc >> 8) as u8, crc as u8]
+}
+
+/// Encodes with allocation.
+///
+/// # Panics
+///
+/// Panics if the kind or a header contains a line break, or if a key
+/// is empty or contains `:`.
+pub fn encode(armor: &Armor) -> String {
+    let line = |x: &str| !x.contains('\n') && !x.contains('\r');
+    assert!(line(&armor.kind));
+    let mut output = format!("-----BEGIN {}-----\n", armor.kind);
+    for &(ref key, ref value) in &armor.headers {
+        assert!(line(key) && line(value) && !key.is_empty() && !key.contains(':'));
+        output.push_str(&format!("{}: {}\n", key, value));
+    }
+    output.push('\n');
+    for chunk in armor.data.chunks(LINE / 4 * 3) {
+        output.push_str(&::base64::encode(chunk));
+        output.push('\n');
+    }
+    output.push('=');
+    output.push_str(&::base64::encode(&checksum(&armor.data)));
+    output.push_str(&format!("\n-----END {}-----\n", armor.kind));
+    output
+}
+
+/// Decodes with allocation.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadCharacter` if a boundary is invalid or the kinds
+/// differ, if an armor header is not a `Key: Value` pair in UTF-8,
+/// or if the checksum line is invalid. The position of an invalid
+/// line is the one of its first character.
+/// - `Error::BadLength` if a boundary or the empty line is missing.
+/// - `Error::BadChecksum` if the checksum does not match.
+///
+/// Decoding may also fail in the circumstances defined by
+/// [`base64::decode`](../base64/fn.decode.html), once the line breaks
+/// are removed. Positions refer to the input.
+pub fn decode(input: &[u8]) -> Result<Armor, Error> {
+    let mut lines = lines(input).into_iter();
+    let (start, kind) = try!(lines.by_ref()
+                             .filter_map(|(pos, line)| boundary(line, BEGIN).map(|x| (pos, x)))
+                             .next().ok_or(BadLength));
+    let kind = try!(String::from_utf8(kind.to_vec()).map_err(|e| {
+        BadCharacter(start + BEGIN.len() + e.utf8_error().valid_up_to())
+    }));
+    let mut headers = Vec::new();
+    loop {
+        let (pos, line) = try!(lines.next().ok_or(BadLength));
+        if line.iter().all(|&x| x == b' ' || x == b'\t') {
+            break;
+        }
+        let n = try!(line.windows(2).position(|x| x == b": ").ok_or(BadCharacter(pos)));
+        check!(BadCharacter(pos), n > 0 && !line[.. n].contains(&b':'));
+        let header = try!(String::from_utf8(line.to_vec()).map_err(|_| BadCharacter(pos)));
+        headers.push((header[.. n].to_string(), header[n + 2 ..].to_string()));
+    }
+    let mut pos = Vec::with_capacity(input.len());
+    let mut body = Vec::with_capacity(input.len());
+    let mut crc = None;
+    loop {
+        let (start, line) = try!(lines.next().ok_or(BadLength));
+        if let Some(end) = boundary(line, END) {
+            check!(BadCharacter(start), end == kind.as_bytes());
+            break;
+        }
+        check!(BadCharacter(start), crc.is_none());
+        if line.first() == Some(&b'=') {
+            check!(BadCharacter(start), line.len() == 5);
+            let value = try!(::base64::decode(&line[1 ..]).map_err(|e| e.map(|p| start + 1 + p)));
+            crc = Some((value, start));
+            continue;
+        }
+        for (i, &x) in line.iter().enumerate() {
+            pos.push(start + i);
+            body.push(x);
+        }
+    }
+    let data = try!(::base64::decode(&body).map_err(|e| e.map(|p| pos[p])));
+    if let Some((value, start)) = crc {
+        check!(BadCharacter(start), value.len() == 3);
+        check!(BadChecksum, value == checksum(&data));
+    }
+    Ok(Armor { kind: kind, headers: headers, data: data })
+}
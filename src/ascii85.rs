@@ -0,0 +1,165 @@
+//! Ascii85 Encoding.
+//!
+//! Symbols are `!-u`. Each group of 4 bytes is encoded as a big-endian
+//! number with 5 symbols. A group of 4 zero bytes is encoded as `z`.
+//! The last group may be shorter: a group of `n` bytes is padded with
+//! zero bytes and only its first `n + 1` symbols are written.
+//!
+//! Encoding always produces the canonical form: no delimiters and no
+//! whitespace. The [`encode_delimited`](fn.encode_delimited.html)
+//! function surrounds the output with `<~` and `~>`. Decoding is
+//! tolerant as described by the specification: whitespace is ignored,
+//! the `<~` and `~>` delimiters are optional, and groups of zero bytes
+//! may also be written with 5 symbols.
+//!
+//! # Conformance
+//!
+//! [PostScript Language Reference
+//! Manual](https://www.adobe.com/jp/print/postscript/pdfs/PLRM.pdf)
+//! (section 3.13.3) compliant.
+
+use decode::Error;
+use decode::Error::*;
+use tool::div_ceil;
+
+fn is_space(x: u8) -> bool {
+    match x {
+        b'\0' | b'\t' | b'\n' | b'\x0c' | b'\r' | b' ' => true,
+        _ => false,
+    }
+}
+
+fn encode_group(input: &[u8], output: &mut [u8]) {
+    let mut x = 0u32;
+    for i in 0 .. 4 {
+        x = x << 8 | *input.get(i).unwrap_or(&0) as u32;
+    }
+    let mut group = [0u8; 5];
+    for i in (0 .. 5).rev() {
+        group[i] = b'!' + (x % 85) as u8;
+        x /= 85;
+    }
+    output.copy_from_slice(&group[.. output.len()]);
+}
+
+fn decode_group(input: &[u8], pos: usize, output: &mut Vec<u8>) -> Result<(), Error> {
+    let mut x = 0u64;
+    for i in 0 .. 5 {
+        x = 85 * x + *input.get(i).unwrap_or(&84) as u64;
+    }
+    check!(BadCharacter(pos), x >> 32 == 0);
+    for i in 0 .. input.len() - 1 {
+        output.push((x >> 8 * (3 - i)) as u8);
+    }
+    Ok(())
+}
+
+/// Converts an input length to its maximum output length.
+///
+/// This function is meant to be used in conjunction with
+/// [`encode_mut`](fn.encode_mut.html). The actual output length
+/// depends on the number of zero groups and is returned by
+/// [`encode_mut`](fn.encode_mut.html).
+pub fn encode_len(len: usize) -> usize {
+    div_ceil(5 * len, 4)
+}
+
+/// Encodes without allocation.
+///
+/// This function returns the output length.
+///
+/// # Panics
+///
+/// Panics if `output.len() < encode_len(input.len())`.
+pub fn encode_mut(input: &[u8], output: &mut [u8]) -> usize {
+    assert!(output.len() >= encode_len(input.len()));
+    let mut n = 0;
+    for chunk in input.chunks(4) {
+        if chunk == [0; 4] {
+            output[n] = b'z';
+            n += 1;
+        } else {
+            encode_group(chunk, &mut output[n .. n + chunk.len() + 1]);
+            n += chunk.len() + 1;
+        }
+    }
+    n
+}
+
+/// Encodes with allocation.
+///
+/// This function is a wrapper for [`encode_mut`](fn.encode_mut.html)
+/// that allocates an output of sufficient size using
+/// [`encode_len`](fn.encode_len.html).
+pub fn encode(input: &[u8]) -> String {
+    let mut output = vec![0u8; encode_len(input.len())];
+    let len = encode_mut(input, &mut output);
+    output.truncate(len);
+    unsafe {
+        // This is valid because symbols are ascii.
+        String::from_utf8_unchecked(output)
+    }
+}
+
+/// Encodes with allocation and delimiters.
+///
+/// This function behaves like [`encode`](fn.encode.html) and
+/// surrounds the output with `<~` and `~>`.
+pub fn encode_delimited(input: &[u8]) -> String {
+    format!("<~{}~>", encode(input))
+}
+
+/// Decodes tolerantly.
+///
+/// Whitespace is ignored and the `<~` and `~>` delimiters are
+/// optional. Reported positions refer to the input including
+/// delimiters and whitespace.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadCharacter` if the input contains a non-symbol, if `z`
+/// occurs inside a group, or if a group overflows 4 bytes. The
+/// position of a group overflow is the one of its first symbol.
+/// - `Error::BadLength` if the last group has only one symbol.
+pub fn decode(input: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut start = 0;
+    let mut end = input.len();
+    if input.starts_with(b"<~") {
+        start = 2;
+    }
+    if input[start ..].ends_with(b"~>") {
+        end -= 2;
+    }
+    let mut output = Vec::with_capacity(4 * (end - start) / 5);
+    let mut group = [0u8; 5];
+    let mut n = 0;
+    let mut pos = 0;
+    for i in start .. end {
+        let x = input[i];
+        if is_space(x) {
+            continue;
+        }
+        if x == b'z' {
+            check!(BadCharacter(i), n == 0);
+            output.extend_from_slice(&[0; 4]);
+            continue;
+        }
+        check!(BadCharacter(i), b'!' <= x && x <= b'u');
+        if n == 0 {
+            pos = i;
+        }
+        group[n] = x - b'!';
+        n += 1;
+        if n == 5 {
+            try!(decode_group(&group, pos, &mut output));
+            n = 0;
+        }
+    }
+    check!(BadLength, n != 1);
+    if n > 0 {
+        try!(decode_group(&group[.. n], pos, &mut output));
+    }
+    Ok(output)
+}
@@ -3,13 +3,35 @@
 //! This module defines a generic interface, namely
 //! [`Base`](trait.Base.html), and an optimized implementation, namely
 //! [`Opt`](struct.Opt.html), for positional numerical systems with
-//! radix ranging from 2 to 64 (powers of two only). Other base
+//! radix ranging from 2 to 128 (powers of two only). Other base
 //! constraints are described in the [`Base`](trait.Base.html)
 //! interface.
 
 use std::{error, fmt};
 use std::marker::PhantomData;
 
+/// Bit order.
+///
+/// This enum defines in which order the bits of the input are
+/// grouped into values. It is a property of the base, given by
+/// [`Base::bit_order`](trait.Base.html#method.bit_order), honored by
+/// both the generic encoding and decoding functions, including for
+/// the last partial block.
+#[derive(Clone,Copy,Debug,PartialEq,Eq,Hash)]
+pub enum BitOrder {
+    /// Most significant bit first.
+    ///
+    /// The first value of a block contains the most significant bits
+    /// of the first byte. This is the order of RFC 4648.
+    MostSignificantFirst,
+
+    /// Least significant bit first.
+    ///
+    /// The first value of a block contains the least significant bits
+    /// of the first byte. This is the order of DNSCurve for instance.
+    LeastSignificantFirst,
+}
+
 /// Generic interface.
 ///
 /// A base implementation needs to define at least its padding `pad`
@@ -34,14 +56,15 @@ use std::marker::PhantomData;
 /// associated to a value.
 ///
 /// We call _symbol_ the symbols represented as ascii (as such, only
-/// ascii symbols are allowed). For instance, in `base64`, the symbols
+/// ascii symbols are allowed, unless the base has 128 symbols, in
+/// which case any `u8` may be a symbol). For instance, in `base64`, the symbols
 /// are the ascii from `A` to `Z`, the ascii from `a` to `z`, the
 /// ascii from `0` to `9`, the ascii `+`, and the ascii `/` in value
 /// order. And the `base16` symbols are the ascii from `0` to `9` and
 /// the ascii from `A` to `F`.
 ///
 /// We call _padding_ the padding represented as ascii (as such, only
-/// ascii padding is allowed). For instance, the ascii `=` is used as
+/// ascii padding is allowed, with the same exception). For instance, the ascii `=` is used as
 /// the padding for `base64` and `base16`.
 ///
 /// # Constraints
@@ -54,6 +77,24 @@ use std::marker::PhantomData;
 /// in the [`ValidError`](enum.ValidError.html) enum and may be
 /// checked by the [`valid`](fn.valid.html) function. Implementations
 /// should also be pure.
+///
+/// # Trait objects
+///
+/// This trait is object safe and the generic functions accept unsized
+/// bases. A base selected at runtime may thus be stored as a `&dyn
+/// Base` or a `Box<dyn Base>`, for instance in a struct field or a
+/// `HashMap`, at the cost of dynamic dispatch.
+///
+/// ```
+/// use data_encoding::base::Base;
+/// let bases: Vec<Box<dyn Base>> = vec![
+///     Box::new(data_encoding::base16::base()),
+///     Box::new(data_encoding::base64::base()),
+/// ];
+/// let encoded: Vec<String> = bases.iter()
+///     .map(|b| data_encoding::encode::encode(b, b"f")).collect();
+/// assert_eq!(encoded, ["66", "Zg=="]);
+/// ```
 pub trait Base {
     /// Returns the padding.
     fn pad(&self) -> u8;
@@ -76,7 +117,7 @@ pub trait Base {
     /// Returns the power of two of the base.
     fn bit(&self) -> usize {
         let mut n = 0;
-        for s in 0..128u8 {
+        for s in 0..=255u8 {
             if self.val(s).is_some() {
                 n += 1;
             }
@@ -97,7 +138,7 @@ pub trait Base {
     ///
     /// May panic when input is not a value.
     fn sym(&self, x: u8) -> u8 {
-        for s in 0..128u8 {
+        for s in 0..=255u8 {
             match self.val(s) {
                 Some(v) if v == x => return s,
                 _ => (),
@@ -105,6 +146,26 @@ pub trait Base {
         }
         unreachable!();
     }
+
+    /// Returns the bit order.
+    ///
+    /// Bases are most significant bit first by default.
+    fn bit_order(&self) -> BitOrder {
+        BitOrder::MostSignificantFirst
+    }
+
+    /// Returns whether decoding checks the trailing bits.
+    ///
+    /// The symbols of the last partial block may carry bits which are
+    /// not part of the decoded data. When this function returns
+    /// `true`, decoding rejects such bits if they are non-zero with
+    /// `Error::BadPadding`, such that only canonical encodings are
+    /// accepted. When it returns `false`, they are ignored.
+    ///
+    /// Bases check trailing bits by default.
+    fn check_trailing_bits(&self) -> bool {
+        true
+    }
 }
 
 /// Returns the bit-mask of a base.
@@ -115,7 +176,7 @@ pub trait Base {
 /// # Panics
 ///
 /// May panic if `base` does not satisfy the `Base` invariants.
-pub fn mask<B: Base>(base: &B) -> u8 {
+pub fn mask<B: Base + ?Sized>(base: &B) -> u8 {
     (1 << base.bit()) - 1
 }
 
@@ -129,11 +190,12 @@ pub fn mask<B: Base>(base: &B) -> u8 {
 /// # Panics
 ///
 /// May panic if `base` does not satisfy the `Base` invariants.
-pub fn len<B: Base>(base: &B) -> usize {
+pub fn len<B: Base + ?Sized>(base: &B) -> usize {
     match base.bit() {
         1 | 2 | 4 => 8,
         3 | 6 => 24,
         5 => 40,
+        7 => 56,
         _ => unreachable!(),
     }
 }
@@ -147,7 +209,7 @@ pub fn len<B: Base>(base: &B) -> usize {
 /// # Panics
 ///
 /// May panic if `base` does not satisfy the `Base` invariants.
-pub fn enc<B: Base>(base: &B) -> usize {
+pub fn enc<B: Base + ?Sized>(base: &B) -> usize {
     len(base) / 8
 }
 
@@ -160,7 +222,7 @@ pub fn enc<B: Base>(base: &B) -> usize {
 /// # Panics
 ///
 /// May panic if `base` does not satisfy the `Base` invariants.
-pub fn dec<B: Base>(base: &B) -> usize {
+pub fn dec<B: Base + ?Sized>(base: &B) -> usize {
     len(base) / base.bit()
 }
 
@@ -191,6 +253,11 @@ pub struct Opt<T> {
     /// This value defines `pad()` as `pad`.
     pub pad: u8,
 
+    /// The bit order.
+    ///
+    /// This value defines `bit_order()` as `order`.
+    pub order: BitOrder,
+
     pub _phantom: PhantomData<T>,
 }
 
@@ -211,6 +278,10 @@ impl<T> Base for Opt<T> {
     fn sym(&self, x: u8) -> u8 {
         self.sym[x as usize]
     }
+
+    fn bit_order(&self) -> BitOrder {
+        self.order
+    }
 }
 
 /// Specification implementation.
@@ -249,6 +320,58 @@ impl Base for Spec {
     }
 }
 
+impl<'a, B: Base + ?Sized> Base for &'a B {
+    fn pad(&self) -> u8 {
+        (**self).pad()
+    }
+
+    fn val(&self, x: u8) -> Option<u8> {
+        (**self).val(x)
+    }
+
+    fn bit(&self) -> usize {
+        (**self).bit()
+    }
+
+    fn sym(&self, x: u8) -> u8 {
+        (**self).sym(x)
+    }
+
+    fn bit_order(&self) -> BitOrder {
+        (**self).bit_order()
+    }
+
+    fn check_trailing_bits(&self) -> bool {
+        (**self).check_trailing_bits()
+    }
+}
+
+impl<B: Base + ?Sized> Base for Box<B> {
+    fn pad(&self) -> u8 {
+        (**self).pad()
+    }
+
+    fn val(&self, x: u8) -> Option<u8> {
+        (**self).val(x)
+    }
+
+    fn bit(&self) -> usize {
+        (**self).bit()
+    }
+
+    fn sym(&self, x: u8) -> u8 {
+        (**self).sym(x)
+    }
+
+    fn bit_order(&self) -> BitOrder {
+        (**self).bit_order()
+    }
+
+    fn check_trailing_bits(&self) -> bool {
+        (**self).check_trailing_bits()
+    }
+}
+
 /// Validity errors.
 ///
 /// This enum defines the invariants of the [`Base`](trait.Base.html)
@@ -256,15 +379,15 @@ impl Base for Spec {
 /// when a constraint check fails.
 #[derive(Clone,Copy,Debug,PartialEq,Eq)]
 pub enum ValidError {
-    /// The base must be a power of two between 2 and 64
+    /// The base must be a power of two between 2 and 128
     /// inclusive.
     ///
-    /// The check `1 <= bit() && bit() <= 6` failed.
+    /// The check `1 <= bit() && bit() <= 7` failed.
     BadBit,
 
     /// The padding must be an ascii.
     ///
-    /// The check `pad() < 128` failed.
+    /// The check `bit() == 7 || pad() < 128` failed.
     PadNotAscii,
 
     /// The padding must not be a symbol.
@@ -274,8 +397,9 @@ pub enum ValidError {
 
     /// Symbols must be ascii.
     ///
-    /// The check `val(s) == None || s < 128` failed. In other
-    /// words, `s` is a symbol and `s` is not ascii.
+    /// The check `bit() == 7 || val(s) == None || s < 128` failed.
+    /// In other words, `s` is a symbol and `s` is not ascii, although
+    /// the base has less than 128 symbols.
     SymNotAscii(u8),
 
     /// Symbols must be mapped to values.
@@ -310,7 +434,7 @@ impl fmt::Display for ValidError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use self::ValidError::*;
         match self {
-            &BadBit => write!(f, "Size is not 2, 4, 8, 16, 32, or 64."),
+            &BadBit => write!(f, "Size is not 2, 4, 8, 16, 32, 64, or 128."),
             &PadNotAscii => write!(f, "Padding is not ascii."),
             &PadSymbol => write!(f, "Padding is a symbol."),
             &SymNotAscii(s) => write!(f, "Symbol {:?} is not ascii.", s as char),
@@ -325,7 +449,7 @@ impl error::Error for ValidError {
     fn description(&self) -> &str {
         use self::ValidError::*;
         match self {
-            &BadBit => "size must be 2, 4, 8, 16, 32, or 64",
+            &BadBit => "size must be 2, 4, 8, 16, 32, 64, or 128",
             &PadNotAscii => "padding must be ascii",
             &PadSymbol => "padding must not be a symbol",
             &SymNotAscii(_) => "symbols must be ascii",
@@ -341,25 +465,96 @@ impl error::Error for ValidError {
 /// This function checks whether a base satisfies the
 /// [`Base`](trait.Base.html) constraints, given that the
 /// implementation is deterministic.
-pub fn valid<B: Base>(base: &B) -> Result<(), ValidError> {
+pub fn valid<B: Base + ?Sized>(base: &B) -> Result<(), ValidError> {
     use self::ValidError::*;
-    check!(BadBit, 1 <= base.bit() && base.bit() <= 6);
-    check!(PadNotAscii, base.pad() < 128);
+    check!(BadBit, 1 <= base.bit() && base.bit() <= 7);
+    let ascii = base.bit() < 7;
+    check!(PadNotAscii, !ascii || base.pad() < 128);
     check!(PadSymbol, base.val(base.pad()) == None);
-    let mut card = 0u8;
-    for s in 0..128u8 {
+    let mut card = 0usize;
+    for s in 0..=255u8 {
         if let Some(v) = base.val(s) {
+            check!(SymNotAscii(s), !ascii || s < 128);
             check!(NotValue(s), v < 1 << base.bit());
             check!(NotInj(s), base.sym(v) == s);
             card += 1;
         }
-        let x = s + 128;
-        check!(SymNotAscii(x), base.val(x) == None);
     }
     check!(NotSurj, card == 1 << base.bit());
     Ok(())
 }
 
+/// Checking errors.
+///
+/// This struct lists all the constraints of the
+/// [`Base`](trait.Base.html) trait which a base does not satisfy. It
+/// is returned by the [`check`](fn.check.html) function and is never
+/// empty.
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub struct CheckError(pub Vec<ValidError>);
+
+impl fmt::Display for CheckError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, e) in self.0.iter().enumerate() {
+            if i > 0 {
+                try!(write!(f, " "));
+            }
+            try!(write!(f, "{}", e));
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for CheckError {
+    fn description(&self) -> &str {
+        "invalid base"
+    }
+}
+
+/// Checks whether a base is valid and lists all failures.
+///
+/// This function checks the same constraints as
+/// [`valid`](fn.valid.html) but does not stop at the first failure,
+/// such that the error describes exactly what is wrong with the
+/// base. When the size is invalid, the other constraints are not
+/// checked since they depend on it. Symbols are never mapped through
+/// [`sym`](trait.Base.html) unless their value is valid, so this
+/// function does not panic on bases with invalid values.
+///
+/// # Examples
+///
+/// ```
+/// use data_encoding::base::{Spec, ValidError, check};
+/// let spec = Spec { val: &[(b'0', b'9'), (b'a', b'f'), (b'f', b'g')], pad: b'f' };
+/// let errors = check(&spec).unwrap_err().0;
+/// assert_eq!(errors, [ValidError::PadSymbol, ValidError::NotValue(b'g'),
+///                     ValidError::NotSurj]);
+/// ```
+pub fn check<B: Base + ?Sized>(base: &B) -> Result<(), CheckError> {
+    use self::ValidError::*;
+    let mut errors = Vec::new();
+    if base.bit() < 1 || base.bit() > 7 {
+        return Err(CheckError(vec![BadBit]));
+    }
+    let ascii = base.bit() < 7;
+    if ascii && base.pad() >= 128 { errors.push(PadNotAscii); }
+    if base.val(base.pad()) != None { errors.push(PadSymbol); }
+    let mut card = 0usize;
+    for s in 0..=255u8 {
+        if let Some(v) = base.val(s) {
+            if ascii && s >= 128 { errors.push(SymNotAscii(s)); }
+            if v >= 1 << base.bit() {
+                errors.push(NotValue(s));
+            } else if base.sym(v) != s {
+                errors.push(NotInj(s));
+            }
+            card += 1;
+        }
+    }
+    if card != 1 << base.bit() { errors.push(NotSurj); }
+    if errors.is_empty() { Ok(()) } else { Err(CheckError(errors)) }
+}
+
 /// Equality errors.
 #[derive(Clone,Copy,Debug,PartialEq,Eq)]
 pub enum EqualError {
@@ -395,10 +590,10 @@ impl error::Error for EqualError {
 /// This function checks whether the symbols, their associated value,
 /// and the padding of two bases are equal. This is enough if both
 /// bases are valid.
-pub fn equal<B1: Base, B2: Base>(b1: &B1, b2: &B2) -> Result<(), EqualError> {
+pub fn equal<B1: Base + ?Sized, B2: Base + ?Sized>(b1: &B1, b2: &B2) -> Result<(), EqualError> {
     use self::EqualError::*;
     check!(Padding, b1.pad() == b2.pad());
-    for s in 0..128u8 {
+    for s in 0..=255u8 {
         check!(Symbol(s), b1.val(s) == b2.val(s));
     }
     Ok(())
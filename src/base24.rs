@@ -0,0 +1,131 @@
+//! Base 24 Encoding.
+//!
+//! Symbols are `ZAC2B3EF4GH5TK67P8RS9WXY`. Each group of 4 bytes is
+//! encoded as a big-endian number with 7 symbols. There is no
+//! padding: the input length must be a multiple of 4 when encoding
+//! and the number of symbols a multiple of 7 when decoding.
+//!
+//! The alphabet has no easily confused symbols, which makes it
+//! suitable for keys entered by humans. The
+//! [`encode_grouped`](fn.encode_grouped.html) function separates
+//! groups of symbols with hyphens. Decoding is tolerant: lowercase
+//! symbols are accepted and hyphens are ignored.
+//!
+//! # Conformance
+//!
+//! [Base24](https://www.kuon.ch/post/2020-02-27-base24/) compliant.
+
+use decode::Error;
+use decode::Error::*;
+use radix::Opt;
+use tool::{strip, unstrip};
+
+const X_: u8 = 128;
+
+/// Force static dispatch.
+pub enum Static {}
+
+static RADIX: Opt<Static> = Opt {
+    val: ascii!(
+        X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+        X_, X_, 3_, 5_, 8_, 11, 14, 15, 17, 20, X_, X_, X_, X_, X_, X_,
+        X_, 1_, 4_, 2_, X_, 6_, 7_, 9_, 10, X_, X_, 13, X_, X_, X_, X_,
+        16, X_, 18, 19, 12, X_, X_, 21, 22, 23, 0_, X_, X_, X_, X_, X_,
+        X_, 1_, 4_, 2_, X_, 6_, 7_, 9_, 10, X_, X_, 13, X_, X_, X_, X_,
+        16, X_, 18, 19, 12, X_, X_, 21, 22, 23, 0_, X_, X_, X_, X_, X_),
+    sym: b"ZAC2B3EF4GH5TK67P8RS9WXY",
+    _phantom: ::std::marker::PhantomData
+};
+
+/// The block size in bytes.
+pub const BLOCK: usize = 4;
+
+/// Gives access to the radix.
+///
+/// The radix also accepts lowercase symbols.
+pub fn radix() -> &'static Opt<Static> {
+    &RADIX
+}
+
+/// Converts an input length to its output length.
+///
+/// # Panics
+///
+/// Panics if `len` is not a multiple of 4.
+pub fn encode_len(len: usize) -> usize {
+    assert_eq!(len % BLOCK, 0);
+    ::radix::encode_chunked_len(&RADIX, BLOCK, len)
+}
+
+/// Encodes without allocation.
+///
+/// # Panics
+///
+/// Panics if `input.len()` is not a multiple of 4 or if
+/// `output.len() != encode_len(input.len())`.
+pub fn encode_mut(input: &[u8], output: &mut [u8]) {
+    assert_eq!(output.len(), encode_len(input.len()));
+    ::radix::encode_chunked_mut(&RADIX, BLOCK, input, output)
+}
+
+/// Encodes with allocation.
+///
+/// # Panics
+///
+/// Panics if `input.len()` is not a multiple of 4.
+pub fn encode(input: &[u8]) -> String {
+    assert_eq!(input.len() % BLOCK, 0);
+    ::radix::encode_chunked(&RADIX, BLOCK, input)
+}
+
+/// Encodes with allocation and groups of symbols.
+///
+/// This function behaves like [`encode`](fn.encode.html) and inserts
+/// a hyphen between each group of `group` symbols.
+///
+/// # Panics
+///
+/// Panics if `input.len()` is not a multiple of 4 or if `group` is
+/// zero.
+pub fn encode_grouped(input: &[u8], group: usize) -> String {
+    assert!(group > 0);
+    let symbols = encode(input);
+    let mut output = String::with_capacity(symbols.len() + symbols.len() / group);
+    for (i, c) in symbols.chars().enumerate() {
+        if i > 0 && i % group == 0 {
+            output.push('-');
+        }
+        output.push(c);
+    }
+    output
+}
+
+/// Decodes tolerantly.
+///
+/// Hyphens are ignored and lowercase symbols are accepted. Reported
+/// positions refer to the input including hyphens.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`Error`](../decode/enum.Error.html). It returns
+/// `Error::BadLength` if the number of symbols is not a multiple of
+/// 7.
+pub fn decode(input: &[u8]) -> Result<Vec<u8>, Error> {
+    let stripped = strip(input, b'-');
+    check!(BadLength, stripped.len() % 7 == 0);
+    ::radix::decode_chunked(&RADIX, BLOCK, &stripped)
+        .map_err(|e| e.map(|p| unstrip(input, b'-', p)))
+}
+
+#[test]
+fn check() {
+    use radix::Radix;
+    assert_eq!(RADIX.val.len(), 256);
+    assert_eq!(RADIX.radix(), 24);
+    for v in 0 .. 24 {
+        let s = RADIX.sym(v);
+        assert_eq!(RADIX.val(s), Some(v));
+        assert_eq!(RADIX.val(s.to_ascii_lowercase()), Some(v));
+    }
+}
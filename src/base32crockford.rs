@@ -0,0 +1,181 @@
+//! Crockford's Base 32 Encoding.
+//!
+//! Symbols are `0-9A-HJKMNP-TV-Z`. No padding is used.
+//!
+//! Encoding always produces the canonical form: uppercase symbols,
+//! no hyphens. Decoding is tolerant as described by the
+//! specification: lowercase symbols are accepted, `O` and `o` are
+//! read as `0`, `I`, `i`, `L`, and `l` are read as `1`, and hyphens
+//! are ignored.
+//!
+//! The [`encode_check`](fn.encode_check.html) and
+//! [`decode_check`](fn.decode_check.html) functions append and verify
+//! a check symbol. The check symbol is the value of the input, read
+//! as a big-endian number, modulo 37. The 5 additional check symbols
+//! are `*~$=U` (`u` is also accepted when decoding).
+//!
+//! See the generic [`encode`](../encode/index.html) and
+//! [`decode`](../decode/index.html) modules for details about this
+//! module functions.
+//!
+//! # Conformance
+//!
+//! [Crockford's Base32](http://www.crockford.com/wrmg/base32.html)
+//! compliant when encoding byte strings.
+
+use base::{Base, BitOrder, Opt};
+use decode::Error;
+use decode::Error::*;
+use tool::{strip, unstrip};
+
+const X_: u8 = 128;
+
+/// Force static dispatch.
+pub enum Static {}
+
+static BASE: Opt<Static> = Opt {
+    val: ascii!(
+        X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+        0_, 1_, 2_, 3_, 4_, 5_, 6_, 7_, 8_, 9_, X_, X_, X_, X_, X_, X_,
+        X_, 10, 11, 12, 13, 14, 15, 16, 17, X_, 18, 19, X_, 20, 21, X_,
+        22, 23, 24, 25, 26, X_, 27, 28, 29, 30, 31, X_, X_, X_, X_, X_,
+        X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+        X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_),
+    sym: b"0123456789ABCDEFGHJKMNPQRSTVWXYZ", bit: 5, pad: b'=',
+    order: BitOrder::MostSignificantFirst, _phantom: ::std::marker::PhantomData
+};
+
+// Same as `BASE` but with the aliases accepted by decoding.
+static LOOSE: Opt<Static> = Opt {
+    val: ascii!(
+        X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+        0_, 1_, 2_, 3_, 4_, 5_, 6_, 7_, 8_, 9_, X_, X_, X_, X_, X_, X_,
+        X_, 10, 11, 12, 13, 14, 15, 16, 17, 1_, 18, 19, 1_, 20, 21, 0_,
+        22, 23, 24, 25, 26, X_, 27, 28, 29, 30, 31, X_, X_, X_, X_, X_,
+        X_, 10, 11, 12, 13, 14, 15, 16, 17, 1_, 18, 19, 1_, 20, 21, 0_,
+        22, 23, 24, 25, 26, X_, 27, 28, 29, 30, 31, X_, X_, X_, X_, X_),
+    sym: b"0123456789ABCDEFGHJKMNPQRSTVWXYZ", bit: 5, pad: b'=',
+    order: BitOrder::MostSignificantFirst, _phantom: ::std::marker::PhantomData
+};
+
+const CHECK: &'static [u8] = b"*~$=U";
+
+fn check_rem(input: &[u8]) -> u8 {
+    let mut r = 0u32;
+    for &x in input {
+        r = (r * 256 + x as u32) % 37;
+    }
+    r as u8
+}
+
+fn check_sym(r: u8) -> u8 {
+    if r < 32 { BASE.sym[r as usize] } else { CHECK[r as usize - 32] }
+}
+
+fn check_val(x: u8) -> Option<u8> {
+    match x {
+        b'*' => Some(32),
+        b'~' => Some(33),
+        b'$' => Some(34),
+        b'=' => Some(35),
+        b'U' | b'u' => Some(36),
+        _ => LOOSE.val(x),
+    }
+}
+
+/// Gives access to the base.
+///
+/// This base only contains the canonical symbols. It does not know
+/// about the aliases accepted by [`decode`](fn.decode.html).
+pub fn base() -> &'static Opt<Static> {
+    &BASE
+}
+
+/// See the generic
+/// [`encode_nopad_len`](../encode/fn.encode_nopad_len.html) function
+/// for details.
+pub fn encode_len(len: usize) -> usize {
+    ::encode::encode_nopad_len(&BASE, len)
+}
+
+/// See the generic
+/// [`encode_nopad_mut`](../encode/fn.encode_nopad_mut.html) function
+/// for details.
+pub fn encode_mut(input: &[u8], output: &mut [u8]) {
+    ::encode::encode_nopad_mut(&BASE, input, output)
+}
+
+/// See the generic [`encode_nopad`](../encode/fn.encode_nopad.html)
+/// function for details.
+pub fn encode(input: &[u8]) -> String {
+    ::encode::encode_nopad(&BASE, input)
+}
+
+/// Encodes with a trailing check symbol.
+///
+/// This function behaves like [`encode`](fn.encode.html) and appends
+/// the check symbol of the input.
+pub fn encode_check(input: &[u8]) -> String {
+    let mut output = encode(input);
+    output.push(check_sym(check_rem(input)) as char);
+    output
+}
+
+/// Decodes tolerantly.
+///
+/// Hyphens are ignored and aliases are accepted. Reported positions
+/// refer to the input including hyphens.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`Error`](../decode/enum.Error.html).
+pub fn decode(input: &[u8]) -> Result<Vec<u8>, Error> {
+    let stripped = strip(input, b'-');
+    ::decode::decode_nopad(&LOOSE, &stripped)
+        .map_err(|e| e.map(|p| unstrip(input, b'-', p)))
+}
+
+/// Decodes tolerantly and verifies the trailing check symbol.
+///
+/// This function behaves like [`decode`](fn.decode.html) on all but
+/// the last symbol, which must be the check symbol of the decoded
+/// output.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`Error`](../decode/enum.Error.html). A check symbol mismatch
+/// returns `Error::BadChecksum`.
+pub fn decode_check(input: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut stripped = strip(input, b'-');
+    let check = try!(stripped.pop().ok_or(BadLength));
+    let pos = unstrip(input, b'-', stripped.len());
+    let check = try!(check_val(check).ok_or(BadCharacter(pos)));
+    let output = try!(::decode::decode_nopad(&LOOSE, &stripped)
+                      .map_err(|e| e.map(|p| unstrip(input, b'-', p))));
+    check!(BadChecksum, check == check_rem(&output));
+    Ok(output)
+}
+
+#[test]
+fn check() {
+    use base::{Spec, equal, valid};
+    const SPEC: Spec = Spec {
+        val: &[(b'0', b'9'), (b'A', b'H'), (b'J', b'K'), (b'M', b'N'),
+               (b'P', b'T'), (b'V', b'Z')],
+        pad: b'=',
+    };
+    assert_eq!(BASE.val.len(), 256);
+    assert_eq!(LOOSE.val.len(), 256);
+    assert_eq!(BASE.sym.len(), 1 << BASE.bit);
+    valid(&SPEC).unwrap();
+    valid(&BASE).unwrap();
+    equal(&BASE, &SPEC).unwrap();
+    for s in 0..128u8 {
+        if let Some(v) = BASE.val(s) {
+            assert_eq!(LOOSE.val(s), Some(v));
+            assert_eq!(LOOSE.val(s.to_ascii_lowercase()), Some(v));
+        }
+    }
+}
@@ -0,0 +1,142 @@
+//! Base 45 Encoding.
+//!
+//! Symbols are `0-9A-Z $%*+-./:`. Each group of 2 bytes is read as a
+//! big-endian number and encoded with 3 symbols, least significant
+//! first. A last group of 1 byte is encoded with 2 symbols.
+//!
+//! # Conformance
+//!
+//! [RFC 9285](https://tools.ietf.org/html/rfc9285) compliant.
+
+use decode::Error;
+use decode::Error::*;
+use radix::{Opt, Radix};
+
+const X_: u8 = 128;
+
+/// Force static dispatch.
+pub enum Static {}
+
+static RADIX: Opt<Static> = Opt {
+    val: ascii!(
+        36, X_, X_, X_, 37, 38, X_, X_, X_, X_, 39, 40, X_, 41, 42, 43,
+        0_, 1_, 2_, 3_, 4_, 5_, 6_, 7_, 8_, 9_, 44, X_, X_, X_, X_, X_,
+        X_, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+        25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, X_, X_, X_, X_, X_,
+        X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+        X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_),
+    sym: b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:",
+    _phantom: ::std::marker::PhantomData
+};
+
+/// Gives access to the radix.
+pub fn radix() -> &'static Opt<Static> {
+    &RADIX
+}
+
+/// Converts an input length to its output length.
+pub fn encode_len(len: usize) -> usize {
+    len / 2 * 3 + len % 2 * 2
+}
+
+/// Converts an input length to its output length.
+///
+/// # Failures
+///
+/// Returns `Error::BadLength` if `len` is congruent to 1 modulo 3.
+pub fn decode_len(len: usize) -> Result<usize, Error> {
+    check!(BadLength, len % 3 != 1);
+    Ok(len / 3 * 2 + len % 3 / 2)
+}
+
+/// Encodes without allocation.
+///
+/// # Panics
+///
+/// Panics if `output.len() != encode_len(input.len())`.
+pub fn encode_mut(input: &[u8], output: &mut [u8]) {
+    assert_eq!(output.len(), encode_len(input.len()));
+    for (chunk, group) in input.chunks(2).zip(output.chunks_mut(3)) {
+        let mut x = 0u32;
+        for &y in chunk {
+            x = x << 8 | y as u32;
+        }
+        for s in group.iter_mut() {
+            *s = RADIX.sym((x % 45) as u8);
+            x /= 45;
+        }
+    }
+}
+
+/// Decodes without allocation.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadLength` if `input.len()` is congruent to 1 modulo 3.
+/// - `Error::BadCharacter` if the input contains a non-symbol, or if
+/// a group overflows. The position of a group overflow is the one of
+/// its first symbol.
+///
+/// # Panics
+///
+/// Panics if `output.len() != decode_len(input.len()).unwrap()`.
+pub fn decode_mut(input: &[u8], output: &mut [u8]) -> Result<(), Error> {
+    let len = try!(decode_len(input.len()));
+    assert_eq!(output.len(), len);
+    for (i, (group, chunk)) in input.chunks(3).zip(output.chunks_mut(2)).enumerate() {
+        let mut x = 0u32;
+        let mut p = 1;
+        for (j, &s) in group.iter().enumerate() {
+            let v = try!(RADIX.val(s).ok_or(BadCharacter(3 * i + j)));
+            x += p * v as u32;
+            p *= 45;
+        }
+        let n = chunk.len();
+        check!(BadCharacter(3 * i), x >> 8 * n == 0);
+        for (j, y) in chunk.iter_mut().enumerate() {
+            *y = (x >> 8 * (n - 1 - j)) as u8;
+        }
+    }
+    Ok(())
+}
+
+/// Encodes with allocation.
+///
+/// This function is a wrapper for [`encode_mut`](fn.encode_mut.html)
+/// that allocates an output of the correct size using
+/// [`encode_len`](fn.encode_len.html).
+pub fn encode(input: &[u8]) -> String {
+    let mut output = vec![0u8; encode_len(input.len())];
+    encode_mut(input, &mut output);
+    unsafe {
+        // This is valid because symbols are ascii.
+        String::from_utf8_unchecked(output)
+    }
+}
+
+/// Decodes with allocation.
+///
+/// This function is a wrapper for [`decode_mut`](fn.decode_mut.html)
+/// that allocates an output of the correct size using
+/// [`decode_len`](fn.decode_len.html).
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`decode_mut`](fn.decode_mut.html).
+pub fn decode(input: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut output = vec![0u8; try!(decode_len(input.len()))];
+    try!(decode_mut(input, &mut output));
+    Ok(output)
+}
+
+#[test]
+fn check() {
+    assert_eq!(RADIX.val.len(), 256);
+    assert_eq!(RADIX.radix(), 45);
+    for v in 0 .. 45 {
+        assert_eq!(RADIX.val(RADIX.sym(v)), Some(v));
+    }
+}
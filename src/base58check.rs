@@ -0,0 +1,50 @@
+//! Base58Check Encoding.
+//!
+//! A version byte is prepended to the payload and the first 4 bytes
+//! of the double SHA-256 digest of both are appended as a checksum.
+//! The result is encoded with [`base58`](../base58/index.html).
+//!
+//! # Conformance
+//!
+//! [Bitcoin](https://en.bitcoin.it/wiki/Base58Check_encoding)
+//! compliant.
+
+use checksum::sha256;
+use decode::Error;
+use decode::Error::*;
+
+fn checksum(input: &[u8]) -> [u8; 4] {
+    let digest = sha256(&sha256(input));
+    [digest[0], digest[1], digest[2], digest[3]]
+}
+
+/// Encodes a version and a payload.
+pub fn encode(version: u8, payload: &[u8]) -> String {
+    let mut data = Vec::with_capacity(payload.len() + 5);
+    data.push(version);
+    data.extend_from_slice(payload);
+    let check = checksum(&data);
+    data.extend_from_slice(&check);
+    ::base58::encode(&data)
+}
+
+/// Decodes and verifies the checksum.
+///
+/// This function returns the version and the payload.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`Error`](../decode/enum.Error.html). It returns
+/// `Error::BadLength` if the decoded data is shorter than the version
+/// and checksum, and `Error::BadChecksum` if the checksum does not
+/// match.
+pub fn decode(input: &[u8]) -> Result<(u8, Vec<u8>), Error> {
+    let mut data = try!(::base58::decode(input));
+    check!(BadLength, data.len() >= 5);
+    let n = data.len() - 4;
+    check!(BadChecksum, checksum(&data[.. n]) == data[n ..]);
+    data.truncate(n);
+    let version = data.remove(0);
+    Ok((version, data))
+}
@@ -0,0 +1,61 @@
+//! Base 58 Encoding with Monero Blocks.
+//!
+//! Symbols are those of [`base58`](../base58/index.html). The input is
+//! split in blocks of 8 bytes, each encoded with 11 symbols. The last
+//! block may be shorter and is encoded with the minimum number of
+//! symbols able to hold it. Blocks are padded with leading `1`
+//! symbols to their full width.
+//!
+//! See the generic [`radix`](../radix/index.html) chunked functions
+//! for details about this module functions.
+//!
+//! # Conformance
+//!
+//! [Monero](https://monerodocs.org/cryptography/base58/) compliant.
+
+use decode::Error;
+
+/// The block size in bytes.
+pub const BLOCK: usize = 8;
+
+/// See the generic
+/// [`encode_chunked_len`](../radix/fn.encode_chunked_len.html)
+/// function for details.
+pub fn encode_len(len: usize) -> usize {
+    ::radix::encode_chunked_len(::base58::radix(), BLOCK, len)
+}
+
+/// See the generic
+/// [`decode_chunked_len`](../radix/fn.decode_chunked_len.html)
+/// function for details.
+pub fn decode_len(len: usize) -> Result<usize, Error> {
+    ::radix::decode_chunked_len(::base58::radix(), BLOCK, len)
+}
+
+/// See the generic
+/// [`encode_chunked_mut`](../radix/fn.encode_chunked_mut.html)
+/// function for details.
+pub fn encode_mut(input: &[u8], output: &mut [u8]) {
+    ::radix::encode_chunked_mut(::base58::radix(), BLOCK, input, output)
+}
+
+/// See the generic
+/// [`decode_chunked_mut`](../radix/fn.decode_chunked_mut.html)
+/// function for details.
+pub fn decode_mut(input: &[u8], output: &mut [u8]) -> Result<(), Error> {
+    ::radix::decode_chunked_mut(::base58::radix(), BLOCK, input, output)
+}
+
+/// See the generic
+/// [`encode_chunked`](../radix/fn.encode_chunked.html) function for
+/// details.
+pub fn encode(input: &[u8]) -> String {
+    ::radix::encode_chunked(::base58::radix(), BLOCK, input)
+}
+
+/// See the generic
+/// [`decode_chunked`](../radix/fn.decode_chunked.html) function for
+/// details.
+pub fn decode(input: &[u8]) -> Result<Vec<u8>, Error> {
+    ::radix::decode_chunked(::base58::radix(), BLOCK, input)
+}
@@ -0,0 +1,60 @@
+//! Forgiving Base 64 Decoding.
+//!
+//! This is the decoding of browsers, like `atob`. It differs from
+//! [`base64`](../base64/index.html) decoding in the following ways:
+//!
+//! - ASCII whitespace, namely tab, line feed, form feed, carriage
+//! return, and space, is ignored.
+//! - Padding is optional, but if present the input length without
+//! whitespace must be a multiple of 4.
+//! - The trailing bits of the last symbol are ignored.
+//!
+//! Encoding is the one of [`base64`](../base64/index.html).
+//!
+//! # Conformance
+//!
+//! [WHATWG Infra](https://infra.spec.whatwg.org/#forgiving-base64-decode)
+//! compliant.
+
+use base::Base;
+use decode::Error;
+use decode::Error::*;
+
+/// Decodes with allocation.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadLength` if the input length without whitespace and
+/// padding is 1 modulo 4.
+/// - `Error::BadCharacter` if the input contains a non-symbol, or
+/// padding which is misplaced.
+pub fn decode(input: &[u8]) -> Result<Vec<u8>, Error> {
+    let base = ::base64::base();
+    let mut pos = Vec::with_capacity(input.len());
+    let mut data = Vec::with_capacity(input.len());
+    for (i, &x) in input.iter().enumerate() {
+        match x {
+            b'\t' | b'\n' | 0x0c | b'\r' | b' ' => (),
+            _ => {
+                pos.push(i);
+                data.push(x);
+            }
+        }
+    }
+    if data.len() % 4 == 0 {
+        for _ in 0 .. 2 {
+            if data.last() == Some(&b'=') {
+                let _ = data.pop();
+            }
+        }
+    }
+    check!(BadLength, data.len() % 4 != 1);
+    if let Some(&x) = data.last() {
+        let n = data.len() - 1;
+        let v = try!(base.val(x).ok_or(BadCharacter(pos[n])));
+        data[n] = base.sym(v & [0x3f, 0, 0x30, 0x3c][data.len() % 4]);
+    }
+    ::base64::decode_nopad(&data).map_err(|e| e.map(|p| pos[p]))
+}
@@ -0,0 +1,77 @@
+//! MIME Base64 Encoding.
+//!
+//! The input is encoded with [`base64`](../base64/index.html) and the
+//! output is wrapped in lines of 76 characters separated by `\r\n`.
+//! There is no line break after the last line. Decoding ignores all
+//! `\r` and `\n` characters, such that lines of any length are
+//! accepted.
+//!
+//! # Conformance
+//!
+//! [RFC 2045](https://tools.ietf.org/html/rfc2045#section-6.8)
+//! compliant, except that decoding rejects characters which are
+//! neither symbols nor line breaks.
+
+use decode::Error;
+use tool::div_ceil;
+
+/// The maximum line length.
+pub const LINE: usize = 76;
+
+/// Converts an input length to its output length.
+pub fn encode_len(len: usize) -> usize {
+    let n = ::base64::encode_len(len);
+    n + 2 * div_ceil(n, LINE).saturating_sub(1)
+}
+
+/// Encodes without allocation.
+///
+/// # Panics
+///
+/// Panics if `output.len() != encode_len(input.len())`.
+pub fn encode_mut(input: &[u8], output: &mut [u8]) {
+    assert_eq!(output.len(), encode_len(input.len()));
+    let mut j = 0;
+    for (i, chunk) in input.chunks(LINE / 4 * 3).enumerate() {
+        if i > 0 {
+            output[j .. j + 2].copy_from_slice(b"\r\n");
+            j += 2;
+        }
+        let n = ::base64::encode_len(chunk.len());
+        ::base64::encode_mut(chunk, &mut output[j .. j + n]);
+        j += n;
+    }
+}
+
+/// Encodes with allocation.
+///
+/// This function is a wrapper for [`encode_mut`](fn.encode_mut.html)
+/// that allocates an output of the correct size using
+/// [`encode_len`](fn.encode_len.html).
+pub fn encode(input: &[u8]) -> String {
+    let mut output = vec![0u8; encode_len(input.len())];
+    encode_mut(input, &mut output);
+    unsafe {
+        // This is valid because symbols are ascii.
+        String::from_utf8_unchecked(output)
+    }
+}
+
+/// Decodes with allocation.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`base64::decode`](../base64/fn.decode.html), once line breaks
+/// are removed. Positions refer to the input with line breaks.
+pub fn decode(input: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut pos = Vec::with_capacity(input.len());
+    let mut stripped = Vec::with_capacity(input.len());
+    for (i, &x) in input.iter().enumerate() {
+        if x != b'\r' && x != b'\n' {
+            pos.push(i);
+            stripped.push(x);
+        }
+    }
+    ::base64::decode(&stripped).map_err(|e| e.map(|p| pos[p]))
+}
@@ -0,0 +1,92 @@
+//! Base 85 Encoding of Git Binary Patches.
+//!
+//! Symbols are those of [`base85rfc1924`](../base85rfc1924/index.html).
+//! The input is split in lines of at most 52 bytes. Each line starts
+//! with its length, `A-Z` for 1 to 26 and `a-z` for 27 to 52, followed
+//! by groups of 4 bytes encoded as big-endian numbers with 5 symbols,
+//! and ends with `\n`. The last group of a line is padded with zero
+//! bytes.
+//!
+//! # Conformance
+//!
+//! Compatible with the `GIT binary patch` hunks produced by `git diff
+//! --binary`.
+
+use decode::Error;
+use decode::Error::*;
+use tool::div_ceil;
+
+/// The maximum number of bytes per line.
+pub const LINE: usize = 52;
+
+fn encode_line_len(len: usize) -> usize {
+    2 + 5 * div_ceil(len, 4)
+}
+
+/// Converts an input length to its output length.
+pub fn encode_len(len: usize) -> usize {
+    len / LINE * encode_line_len(LINE)
+        + if len % LINE == 0 { 0 } else { encode_line_len(len % LINE) }
+}
+
+/// Encodes with allocation.
+///
+/// The output contains one line per 52 bytes of input.
+pub fn encode(input: &[u8]) -> String {
+    let radix = ::base85rfc1924::radix();
+    let mut output = Vec::with_capacity(encode_len(input.len()));
+    for line in input.chunks(LINE) {
+        let n = line.len() as u8;
+        output.push(if n <= 26 { b'A' + n - 1 } else { b'a' + n - 27 });
+        for group in line.chunks(4) {
+            let mut block = [0u8; 4];
+            block[.. group.len()].copy_from_slice(group);
+            let mut symbols = [0u8; 5];
+            ::radix::encode_chunked_mut(radix, 4, &block, &mut symbols);
+            output.extend_from_slice(&symbols);
+        }
+        output.push(b'\n');
+    }
+    unsafe {
+        // This is valid because symbols are ascii.
+        String::from_utf8_unchecked(output)
+    }
+}
+
+/// Decodes with allocation.
+///
+/// Each line must be terminated by `\n`.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadCharacter` if a line does not start with a length, if
+/// it contains a non-symbol, or if a group overflows 4 bytes. The
+/// position of a group overflow is the one of its first symbol.
+/// - `Error::BadLength` if a line does not match its length or is not
+/// terminated.
+/// - `Error::BadPadding` if the padding bytes of a line are non-zero.
+pub fn decode(input: &[u8]) -> Result<Vec<u8>, Error> {
+    let radix = ::base85rfc1924::radix();
+    let mut output = Vec::with_capacity(input.len() * 4 / 5);
+    let mut pos = 0;
+    while pos < input.len() {
+        let n = match input[pos] {
+            x @ b'A' ..= b'Z' => (x - b'A' + 1) as usize,
+            x @ b'a' ..= b'z' => (x - b'a' + 27) as usize,
+            _ => return Err(BadCharacter(pos)),
+        };
+        let len = encode_line_len(n);
+        check!(BadLength, pos + len <= input.len());
+        let symbols = &input[pos + 1 .. pos + len - 1];
+        check!(BadCharacter(pos + len - 1), input[pos + len - 1] == b'\n');
+        let mut block = vec![0u8; symbols.len() / 5 * 4];
+        try!(::radix::decode_chunked_mut(radix, 4, symbols, &mut block)
+             .map_err(|e| e.shift(pos + 1)));
+        check!(BadPadding, block[n ..].iter().all(|&x| x == 0));
+        output.extend_from_slice(&block[.. n]);
+        pos += len;
+    }
+    Ok(output)
+}
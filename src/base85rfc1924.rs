@@ -0,0 +1,79 @@
+//! Base 85 Encoding of IPv6 Addresses.
+//!
+//! Symbols are ``0-9A-Za-z!#$%&()*+-;<=>?@^_`{|}~``. A 128-bit value
+//! is encoded as a big-endian number with exactly 20 symbols.
+//!
+//! # Conformance
+//!
+//! [RFC 1924](https://tools.ietf.org/html/rfc1924) compliant.
+
+use decode::Error;
+use decode::Error::*;
+use radix::Opt;
+
+const X_: u8 = 128;
+
+/// Force static dispatch.
+pub enum Static {}
+
+static RADIX: Opt<Static> = Opt {
+    val: ascii!(
+        X_, 62, X_, 63, 64, 65, 66, X_, 67, 68, 69, 70, X_, 71, X_, X_,
+        0_, 1_, 2_, 3_, 4_, 5_, 6_, 7_, 8_, 9_, X_, 72, 73, 74, 75, 76,
+        77, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+        25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, X_, X_, X_, 78, 79,
+        80, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50,
+        51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 81, 82, 83, 84, X_),
+    sym: b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz!#$%&()*+-;<=>?@^_`{|}~",
+    _phantom: ::std::marker::PhantomData
+};
+
+/// The encoded length.
+pub const LEN: usize = 20;
+
+/// Gives access to the radix.
+pub fn radix() -> &'static Opt<Static> {
+    &RADIX
+}
+
+/// Encodes 16 bytes.
+pub fn encode(input: &[u8; 16]) -> String {
+    ::radix::encode_chunked(&RADIX, 16, input)
+}
+
+/// Decodes 16 bytes.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`Error`](../decode/enum.Error.html). It returns
+/// `Error::BadLength` if `input.len()` is not 20 and
+/// `Error::BadCharacter(0)` if the value overflows 128 bits.
+pub fn decode(input: &[u8]) -> Result<[u8; 16], Error> {
+    check!(BadLength, input.len() == LEN);
+    let mut output = [0u8; 16];
+    try!(::radix::decode_chunked_mut(&RADIX, 16, input, &mut output));
+    Ok(output)
+}
+
+/// Encodes a 128-bit value.
+pub fn encode_u128(input: u128) -> String {
+    encode(&input.to_be_bytes())
+}
+
+/// Decodes a 128-bit value.
+///
+/// See [`decode`](fn.decode.html) for details.
+pub fn decode_u128(input: &[u8]) -> Result<u128, Error> {
+    decode(input).map(u128::from_be_bytes)
+}
+
+#[test]
+fn check() {
+    use radix::Radix;
+    assert_eq!(RADIX.val.len(), 256);
+    assert_eq!(RADIX.radix(), 85);
+    for v in 0 .. 85 {
+        assert_eq!(RADIX.val(RADIX.sym(v)), Some(v));
+    }
+}
@@ -0,0 +1,130 @@
+//! basE91 Encoding.
+//!
+//! Symbols are ``A-Za-z0-9!#$%&()*+,./:;<=>?@[]^_`{|}~"``. The input
+//! is read as a stream of bits, least significant first. Each group
+//! of 13 or 14 bits is encoded with 2 symbols, least significant
+//! first: groups of 13 bits are used when their value is larger than
+//! 88 and groups of 14 bits otherwise. The remaining bits are encoded
+//! with 1 or 2 symbols.
+//!
+//! Since groups do not have a fixed size, the output length depends
+//! on the input value and decoding needs its own engine instead of
+//! the generic [`decode`](../decode/index.html) module. Like the
+//! generic functions, decoding rejects non-canonical inputs.
+//!
+//! # Conformance
+//!
+//! [basE91](http://base91.sourceforge.net/) compliant.
+
+use decode::Error;
+use decode::Error::*;
+use radix::{Opt, Radix};
+
+const X_: u8 = 128;
+
+/// Force static dispatch.
+pub enum Static {}
+
+static RADIX: Opt<Static> = Opt {
+    val: ascii!(
+        X_, 62, 90, 63, 64, 65, 66, X_, 67, 68, 69, 70, 71, X_, 72, 73,
+        52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 74, 75, 76, 77, 78, 79,
+        80, 0_, 1_, 2_, 3_, 4_, 5_, 6_, 7_, 8_, 9_, 10, 11, 12, 13, 14,
+        15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 81, X_, 82, 83, 84,
+        85, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40,
+        41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 86, 87, 88, 89, X_),
+    sym: b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!#$%&()*+,./:;<=>?@[]^_`{|}~\"",
+    _phantom: ::std::marker::PhantomData
+};
+
+/// Gives access to the symbols.
+pub fn radix() -> &'static Opt<Static> {
+    &RADIX
+}
+
+/// Converts an input length to its maximum output length.
+///
+/// The actual output length depends on the input value.
+pub fn encode_len(len: usize) -> usize {
+    // Each pair of symbols holds at least 13 bits.
+    16 * len / 13 + 2
+}
+
+/// Encodes with allocation.
+pub fn encode(input: &[u8]) -> String {
+    let mut output = Vec::with_capacity(encode_len(input.len()));
+    let mut b = 0u32;
+    let mut n = 0;
+    for &x in input {
+        b |= (x as u32) << n;
+        n += 8;
+        if n > 13 {
+            let mut v = b & 8191;
+            if v > 88 {
+                b >>= 13;
+                n -= 13;
+            } else {
+                v = b & 16383;
+                b >>= 14;
+                n -= 14;
+            }
+            output.push(RADIX.sym((v % 91) as u8));
+            output.push(RADIX.sym((v / 91) as u8));
+        }
+    }
+    if n > 0 {
+        output.push(RADIX.sym((b % 91) as u8));
+        if n > 7 || b > 90 {
+            output.push(RADIX.sym((b / 91) as u8));
+        }
+    }
+    unsafe {
+        // This is valid because symbols are ascii.
+        String::from_utf8_unchecked(output)
+    }
+}
+
+/// Decodes with allocation.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadCharacter` if the input contains a non-symbol.
+/// - `Error::BadPadding` if the last symbols are not canonical.
+pub fn decode(input: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut output = Vec::with_capacity(input.len() * 14 / 16 + 1);
+    let mut b = 0u32;
+    let mut n = 0;
+    let mut v = None;
+    for (i, &x) in input.iter().enumerate() {
+        let p = try!(RADIX.val(x).ok_or(BadCharacter(i))) as u32;
+        match v.take() {
+            None => v = Some(p),
+            Some(q) => {
+                let w = q + 91 * p;
+                b |= w << n;
+                n += if w & 8191 > 88 { 13 } else { 14 };
+                while n > 7 {
+                    output.push(b as u8);
+                    b >>= 8;
+                    n -= 8;
+                }
+            }
+        }
+    }
+    if let Some(q) = v {
+        output.push((b | q << n) as u8);
+    }
+    check!(BadPadding, encode(&output).as_bytes() == input);
+    Ok(output)
+}
+
+#[test]
+fn check() {
+    assert_eq!(RADIX.val.len(), 256);
+    assert_eq!(RADIX.radix(), 91);
+    for v in 0 .. 91 {
+        assert_eq!(RADIX.val(RADIX.sym(v)), Some(v));
+    }
+}
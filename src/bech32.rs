@@ -0,0 +1,239 @@
+//! Bech32 and Bech32m Encodings.
+//!
+//! An encoded string is made of a human-readable part, the separator
+//! `1`, a data part, and a checksum of 6 symbols. Symbols are
+//! `qpzry9x8gf2tvdw0s3jn54khce6mua7l`, each holding a 5-bit value.
+//! The checksum is a BCH code over the human-readable part and the
+//! data part. The [`Variant`](enum.Variant.html) selects the constant
+//! of the checksum.
+//!
+//! The [`encode`](fn.encode.html) and [`decode`](fn.decode.html)
+//! functions regroup bytes in 5-bit values like the generic
+//! [`encode_nopad`](../encode/fn.encode_nopad.html) and
+//! [`decode_nopad`](../decode/fn.decode_nopad.html) functions. The
+//! [`encode_values`](fn.encode_values.html) and
+//! [`decode_values`](fn.decode_values.html) functions work on 5-bit
+//! values directly, for example to prepend a witness version.
+//!
+//! Encoding always produces lowercase strings. Decoding accepts
+//! lowercase and uppercase strings, but not mixed case.
+//!
+//! # Conformance
+//!
+//! [BIP 173](https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki)
+//! and
+//! [BIP 350](https://github.com/bitcoin/bips/blob/master/bip-0350.mediawiki)
+//! compliant.
+
+use base::{Base, BitOrder, Opt};
+use decode::Error;
+use decode::Error::*;
+
+const X_: u8 = 128;
+
+/// Force static dispatch.
+pub enum Static {}
+
+static BASE: Opt<Static> = Opt {
+    val: ascii!(
+        X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+        15, X_, 10, 17, 21, 20, 26, 30, 7_, 5_, X_, X_, X_, X_, X_, X_,
+        X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+        X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+        X_, 29, X_, 24, 13, 25, 9_, 8_, 23, X_, 18, 22, 31, 27, 19, X_,
+        1_, 0_, 3_, 16, 11, 28, 12, 14, 6_, 4_, 2_, X_, X_, X_, X_, X_),
+    sym: b"qpzry9x8gf2tvdw0s3jn54khce6mua7l", bit: 5, pad: b'=',
+    order: BitOrder::MostSignificantFirst, _phantom: ::std::marker::PhantomData
+};
+
+/// The maximum length of an encoded string.
+pub const MAX_LEN: usize = 90;
+
+/// Checksum variants.
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+pub enum Variant {
+    /// Bech32 as defined by BIP 173.
+    Bech32,
+
+    /// Bech32m as defined by BIP 350.
+    Bech32m,
+}
+use self::Variant::*;
+
+impl Variant {
+    fn constant(self) -> u32 {
+        match self {
+            Bech32 => 1,
+            Bech32m => 0x2bc830a3,
+        }
+    }
+}
+
+const GEN: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+fn polymod(chk: u32, v: u8) -> u32 {
+    let b = chk >> 25;
+    let mut chk = (chk & 0x1ffffff) << 5 ^ v as u32;
+    for i in 0 .. 5 {
+        if b >> i & 1 == 1 {
+            chk ^= GEN[i];
+        }
+    }
+    chk
+}
+
+fn checksum(hrp: &[u8], values: &[u8]) -> u32 {
+    let mut chk = 1;
+    for &x in hrp {
+        chk = polymod(chk, x >> 5);
+    }
+    chk = polymod(chk, 0);
+    for &x in hrp {
+        chk = polymod(chk, x & 31);
+    }
+    for &v in values {
+        chk = polymod(chk, v);
+    }
+    chk
+}
+
+/// Gives access to the base.
+///
+/// This base maps symbols to their 5-bit values.
+pub fn base() -> &'static Opt<Static> {
+    &BASE
+}
+
+/// Encodes 5-bit values.
+///
+/// The human-readable part is converted to lowercase.
+///
+/// # Panics
+///
+/// Panics if `hrp` is empty or contains characters outside the range
+/// 33 to 126, if a value is not smaller than 32, or if the encoded
+/// string would be longer than [`MAX_LEN`](constant.MAX_LEN.html).
+pub fn encode_values(hrp: &str, values: &[u8], variant: Variant) -> String {
+    let hrp = hrp.to_ascii_lowercase();
+    assert!(!hrp.is_empty());
+    assert!(hrp.bytes().all(|x| 33 <= x && x <= 126));
+    assert!(values.iter().all(|&v| v < 32));
+    assert!(hrp.len() + 1 + values.len() + 6 <= MAX_LEN);
+    let mut chk = checksum(hrp.as_bytes(), values);
+    for _ in 0 .. 6 {
+        chk = polymod(chk, 0);
+    }
+    chk ^= variant.constant();
+    let mut output = Vec::with_capacity(hrp.len() + 1 + values.len() + 6);
+    output.extend_from_slice(hrp.as_bytes());
+    output.push(b'1');
+    output.extend(values.iter().map(|&v| BASE.sym(v)));
+    output.extend((0 .. 6).map(|i| BASE.sym((chk >> 5 * (5 - i) & 31) as u8)));
+    unsafe {
+        // This is valid because the human-readable part and symbols
+        // are ascii.
+        String::from_utf8_unchecked(output)
+    }
+}
+
+/// Encodes bytes.
+///
+/// The bytes are regrouped in 5-bit values, most significant first.
+/// The last value is padded with zero bits.
+///
+/// # Panics
+///
+/// Panics in the circumstances defined by
+/// [`encode_values`](fn.encode_values.html).
+pub fn encode(hrp: &str, data: &[u8], variant: Variant) -> String {
+    let symbols = ::encode::encode_nopad(&BASE, data);
+    let values: Vec<u8> = symbols.bytes().map(|x| BASE.val(x).unwrap()).collect();
+    encode_values(hrp, &values, variant)
+}
+
+// Returns the lowercase input, the position of the separator, and the
+// variant.
+fn parse(input: &[u8]) -> Result<(Vec<u8>, usize, Variant), Error> {
+    check!(BadLength, input.len() <= MAX_LEN);
+    let mut case = None;
+    for (i, &x) in input.iter().enumerate() {
+        check!(BadCharacter(i), 33 <= x && x <= 126);
+        if x.is_ascii_alphabetic() {
+            let upper = x.is_ascii_uppercase();
+            check!(BadCharacter(i), *case.get_or_insert(upper) == upper);
+        }
+    }
+    let input = input.to_ascii_lowercase();
+    let sep = try!(input.iter().rposition(|&x| x == b'1').ok_or(BadLength));
+    check!(BadCharacter(0), sep > 0);
+    check!(BadLength, input.len() - sep - 1 >= 6);
+    let mut values = Vec::with_capacity(input.len() - sep - 1);
+    for i in sep + 1 .. input.len() {
+        values.push(try!(BASE.val(input[i]).ok_or(BadCharacter(i))));
+    }
+    let variant = match checksum(&input[.. sep], &values) {
+        x if x == Bech32.constant() => Bech32,
+        x if x == Bech32m.constant() => Bech32m,
+        _ => return Err(BadChecksum),
+    };
+    Ok((input, sep, variant))
+}
+
+/// Decodes 5-bit values.
+///
+/// This function returns the lowercase human-readable part, the
+/// values, and the variant of the checksum.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadLength` if the input is longer than 90 characters,
+/// has no separator, or has fewer than 6 symbols after the
+/// separator.
+/// - `Error::BadCharacter` if the input contains characters outside
+/// the range 33 to 126, mixes cases, has an empty human-readable
+/// part, or contains a non-symbol after the separator.
+/// - `Error::BadChecksum` if the checksum does not match either
+/// variant.
+pub fn decode_values(input: &[u8]) -> Result<(String, Vec<u8>, Variant), Error> {
+    let (input, sep, variant) = try!(parse(input));
+    let symbols = &input[sep + 1 .. input.len() - 6];
+    let values = symbols.iter().map(|&x| BASE.val(x).unwrap()).collect();
+    let hrp = unsafe {
+        // This is valid because the human-readable part is ascii.
+        String::from_utf8_unchecked(input[.. sep].to_vec())
+    };
+    Ok((hrp, values, variant))
+}
+
+/// Decodes bytes.
+///
+/// This function behaves like
+/// [`decode_values`](fn.decode_values.html) and regroups the values
+/// in bytes.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`decode_values`](fn.decode_values.html). Regrouping may also fail
+/// in the circumstances defined by
+/// [`decode_nopad`](../decode/fn.decode_nopad.html).
+pub fn decode(input: &[u8]) -> Result<(String, Vec<u8>, Variant), Error> {
+    let (input, sep, variant) = try!(parse(input));
+    let data = try!(::decode::decode_nopad(&BASE, &input[sep + 1 .. input.len() - 6])
+                    .map_err(|e| e.shift(sep + 1)));
+    let hrp = unsafe {
+        // This is valid because the human-readable part is ascii.
+        String::from_utf8_unchecked(input[.. sep].to_vec())
+    };
+    Ok((hrp, data, variant))
+}
+
+#[test]
+fn check() {
+    use base::valid;
+    assert_eq!(BASE.val.len(), 256);
+    assert_eq!(BASE.sym.len(), 1 << BASE.bit);
+    valid(&BASE).unwrap();
+}
@@ -0,0 +1,177 @@
+//! BinHex 4.0 Decoding.
+//!
+//! A BinHex file holds a Macintosh file: its name, its type and
+//! creator codes, its Finder flags, its data fork, and its resource
+//! fork. The header and each fork are followed by their CRC-16. This
+//! stream is compressed with run-length encoding: the byte `0x90`
+//! followed by a count `n` repeats the previous byte until it appears
+//! `n` times, and followed by `0` stands for the byte `0x90` itself.
+//! The compressed stream is read as a stream of bits, most
+//! significant first, and each group of 6 bits is encoded with a
+//! symbol of ``!"#$%&'()*+,-012345689@ABCDEFGHIJKLMNPQRSTUVXYZ[`abcdefhijklmpqr``.
+//! The symbols are delimited by `:` and split in lines.
+//!
+//! Only decoding is supported. Text before the first `:`, usually
+//! `(This file must be converted with BinHex 4.0)`, and after the last
+//! `:` is ignored, as well as line terminators and spaces between
+//! symbols.
+//!
+//! # Conformance
+//!
+//! [RFC 1741](https://tools.ietf.org/html/rfc1741) compliant.
+
+use checksum::crc16;
+use decode::Error;
+use decode::Error::*;
+use radix::{Opt, Radix};
+
+const X_: u8 = 128;
+
+/// Force static dispatch.
+pub enum Static {}
+
+static RADIX: Opt<Static> = Opt {
+    val: ascii!(
+        X_, 0_, 1_, 2_, 3_, 4_, 5_, 6_, 7_, 8_, 9_, 10, 11, 12, X_, X_,
+        13, 14, 15, 16, 17, 18, 19, X_, 20, 21, X_, X_, X_, X_, X_, X_,
+        22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, X_,
+        37, 38, 39, 40, 41, 42, 43, X_, 44, 45, 46, 47, X_, X_, X_, X_,
+        48, 49, 50, 51, 52, 53, 54, X_, 55, 56, 57, 58, 59, 60, X_, X_,
+        61, 62, 63, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_),
+    sym: b"!\"#$%&'()*+,-012345689@ABCDEFGHIJKLMNPQRSTUVXYZ[`abcdefhijklmpqr",
+    _phantom: ::std::marker::PhantomData
+};
+
+/// Gives access to the symbols.
+pub fn radix() -> &'static Opt<Static> {
+    &RADIX
+}
+
+/// A decoded file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct File {
+    /// The name, in the Mac OS Roman character set.
+    pub name: Vec<u8>,
+
+    /// The type code.
+    pub kind: [u8; 4],
+
+    /// The creator code.
+    pub creator: [u8; 4],
+
+    /// The Finder flags.
+    pub flags: u16,
+
+    /// The data fork.
+    pub data: Vec<u8>,
+
+    /// The resource fork.
+    pub resource: Vec<u8>,
+}
+
+// Decodes the symbols between the delimiters. Trailing bits are
+// ignored.
+fn unframe(input: &[u8]) -> Result<Vec<u8>, Error> {
+    let start = try!(input.iter().position(|&x| x == b':').ok_or(BadLength)) + 1;
+    let mut output = Vec::with_capacity((input.len() - start) * 3 / 4);
+    let mut b = 0u32;
+    let mut n = 0;
+    for i in start .. input.len() {
+        let v = match input[i] {
+            b':' => return Ok(output),
+            b'\r' | b'\n' | b'\t' | b' ' => continue,
+            x => try!(RADIX.val(x).ok_or(BadCharacter(i))),
+        };
+        b = b << 6 | v as u32;
+        n += 6;
+        if n >= 8 {
+            n -= 8;
+            output.push((b >> n) as u8);
+        }
+    }
+    Err(BadLength)
+}
+
+// Expands the runs.
+fn expand(input: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut output = Vec::with_capacity(input.len());
+    let mut i = 0;
+    while i < input.len() {
+        if input[i] != 0x90 {
+            output.push(input[i]);
+            i += 1;
+            continue;
+        }
+        check!(BadLength, i + 1 < input.len());
+        match input[i + 1] {
+            0 => output.push(0x90),
+            n => {
+                let x = *try!(output.last().ok_or(BadLength));
+                for _ in 1 .. n {
+                    output.push(x);
+                }
+            }
+        }
+        i += 2;
+    }
+    Ok(output)
+}
+
+// Reads a part of the stream.
+fn take<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], Error> {
+    check!(BadLength, input.len() >= len);
+    let (x, y) = input.split_at(len);
+    *input = y;
+    Ok(x)
+}
+
+fn be(x: &[u8]) -> u32 {
+    x.iter().fold(0, |a, &b| a << 8 | b as u32)
+}
+
+// Reads a part of the stream followed by its CRC-16.
+fn take_crc<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], Error> {
+    let x = try!(take(input, len));
+    check!(BadChecksum, be(try!(take(input, 2))) == crc16(x) as u32);
+    Ok(x)
+}
+
+/// Decodes a file.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadLength` if the delimiters are missing, if a run has no
+/// byte to repeat, or if the stream is shorter than announced by its
+/// header.
+/// - `Error::BadCharacter` if there is a non-symbol between the
+/// delimiters.
+/// - `Error::BadChecksum` if a CRC-16 does not match.
+pub fn decode(input: &[u8]) -> Result<File, Error> {
+    let stream = try!(expand(&try!(unframe(input))));
+    let mut input = &stream[..];
+    let n = *try!(input.first().ok_or(BadLength)) as usize;
+    let header = try!(take_crc(&mut input, 1 + n + 1 + 4 + 4 + 2 + 4 + 4));
+    let (name, header) = header[1 ..].split_at(n);
+    let mut kind = [0; 4];
+    let mut creator = [0; 4];
+    kind.copy_from_slice(&header[1 .. 5]);
+    creator.copy_from_slice(&header[5 .. 9]);
+    let flags = be(&header[9 .. 11]) as u16;
+    let data = try!(take_crc(&mut input, be(&header[11 .. 15]) as usize));
+    let resource = try!(take_crc(&mut input, be(&header[15 .. 19]) as usize));
+    Ok(File {
+        name: name.to_vec(), kind: kind, creator: creator, flags: flags,
+        data: data.to_vec(), resource: resource.to_vec(),
+    })
+}
+
+#[test]
+fn check() {
+    assert_eq!(RADIX.val.len(), 256);
+    assert_eq!(RADIX.radix(), 64);
+    for v in 0 .. 64 {
+        assert_eq!(RADIX.val(RADIX.sym(v)), Some(v));
+    }
+}
@@ -0,0 +1,90 @@
+//! BIP39 Mnemonic Encoding.
+//!
+//! The entropy, of 16 to 32 bytes by steps of 4, is followed by the
+//! first bits of its SHA-256 digest, one bit per 4 bytes of entropy.
+//! The result is split in groups of 11 bits, most significant first,
+//! each encoded as a word of a 2048-word list. Words are separated by
+//! spaces.
+//!
+//! This crate does not embed the BIP39 wordlists. The caller provides
+//! the wordlist of its language as a [`Wordlist`](../wordlist/trait.Wordlist.html),
+//! for example a slice of 2048 words in value order.
+//!
+//! # Conformance
+//!
+//! [BIP 39](https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki)
+//! compliant when given a BIP39 wordlist and single space separators.
+
+use checksum::sha256;
+use decode::Error;
+use decode::Error::*;
+use tool::words;
+use wordlist::Wordlist;
+
+fn get(data: &[u8], pos: usize) -> usize {
+    let mut x = 0;
+    for p in pos .. pos + 11 {
+        x = x << 1 | (data[p / 8] >> (7 - p % 8) & 1) as usize;
+    }
+    x
+}
+
+fn set(data: &mut [u8], pos: usize, x: usize) {
+    for k in 0 .. 11 {
+        let p = pos + k;
+        data[p / 8] |= ((x >> (10 - k) & 1) as u8) << (7 - p % 8);
+    }
+}
+
+/// Encodes entropy.
+///
+/// # Panics
+///
+/// Panics if `wordlist` does not have 2048 words or if
+/// `entropy.len()` is not a multiple of 4 between 16 and 32.
+pub fn encode<W: Wordlist + ?Sized>(wordlist: &W, entropy: &[u8]) -> String {
+    assert_eq!(wordlist.len(), 2048);
+    assert!(16 <= entropy.len() && entropy.len() <= 32 && entropy.len() % 4 == 0);
+    let mut data = entropy.to_vec();
+    data.push(sha256(entropy)[0]);
+    let n = (8 * entropy.len() + entropy.len() / 4) / 11;
+    let mut output = String::new();
+    for i in 0 .. n {
+        if i > 0 {
+            output.push(' ');
+        }
+        output.push_str(wordlist.word(get(&data, 11 * i)));
+    }
+    output
+}
+
+/// Decodes entropy.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadLength` if the number of words is not a multiple of 3
+/// between 12 and 24.
+/// - `Error::BadCharacter` if a word is not in the wordlist, in which
+/// case the position is the one of the word.
+/// - `Error::BadChecksum` if the checksum bits do not match.
+///
+/// # Panics
+///
+/// Panics if `wordlist` does not have 2048 words.
+pub fn decode<W: Wordlist + ?Sized>(wordlist: &W, input: &str) -> Result<Vec<u8>, Error> {
+    assert_eq!(wordlist.len(), 2048);
+    let words = words(input);
+    let n = words.len();
+    check!(BadLength, 12 <= n && n <= 24 && n % 3 == 0);
+    let mut data = vec![0u8; 4 * n / 3 + 1];
+    for (i, &(pos, w)) in words.iter().enumerate() {
+        let x = try!(wordlist.val(w).ok_or(BadCharacter(pos)));
+        set(&mut data, 11 * i, x);
+    }
+    let check = data.pop().unwrap();
+    let bits = n / 3;
+    check!(BadChecksum, check >> (8 - bits) == sha256(&data)[0] >> (8 - bits));
+    Ok(data)
+}
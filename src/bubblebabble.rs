@@ -0,0 +1,117 @@
+//! Bubble Babble Encoding.
+//!
+//! Vowels are `aeiouy` and consonants are `bcdfghklmnprstvzx`. The
+//! output starts and ends with `x`. Each group of 2 bytes is encoded
+//! as a tuple of 5 letters followed by a hyphen, the first byte as a
+//! vowel, a consonant, and a vowel, and the second byte as 2
+//! consonants separated by the hyphen. The vowels depend on a running
+//! checksum of the previous bytes. The last tuple has 3 letters: it
+//! encodes the last byte if the input length is odd and only the
+//! checksum otherwise.
+//!
+//! # Conformance
+//!
+//! [Bubble Babble](https://web.mit.edu/kenta/www/one/bubblebabble/spec/jrtrjwzi/draft-huima-01.txt)
+//! compliant.
+
+use decode::Error;
+use decode::Error::*;
+
+const VOW: &'static [u8; 6] = b"aeiouy";
+const CON: &'static [u8; 17] = b"bcdfghklmnprstvzx";
+
+fn val(alphabet: &[u8], input: &[u8], pos: usize) -> Result<usize, Error> {
+    alphabet.iter().position(|&y| y == input[pos]).ok_or(BadCharacter(pos))
+}
+
+fn encode_byte(output: &mut Vec<u8>, x: u8, seed: usize) {
+    let x = x as usize;
+    output.push(VOW[((x >> 6) + seed) % 6]);
+    output.push(CON[x >> 2 & 15]);
+    output.push(VOW[((x & 3) + seed / 6) % 6]);
+}
+
+fn decode_byte(input: &[u8], pos: usize, seed: usize) -> Result<u8, Error> {
+    let hi = try!(val(VOW, input, pos));
+    let mid = try!(val(CON, input, pos + 1));
+    let lo = try!(val(VOW, input, pos + 2));
+    check!(BadCharacter(pos + 1), mid < 16);
+    let hi = (hi + 6 - seed % 6) % 6;
+    let lo = (lo + 6 - seed / 6) % 6;
+    check!(BadChecksum, hi < 4 && lo < 4);
+    Ok((hi << 6 | mid << 2 | lo) as u8)
+}
+
+/// Converts an input length to its output length.
+pub fn encode_len(len: usize) -> usize {
+    6 * (len / 2) + 5
+}
+
+/// Encodes with allocation.
+pub fn encode(input: &[u8]) -> String {
+    let mut output = Vec::with_capacity(encode_len(input.len()));
+    let mut seed = 1;
+    output.push(b'x');
+    for pair in input.chunks(2) {
+        encode_byte(&mut output, pair[0], seed);
+        if pair.len() == 2 {
+            output.push(CON[(pair[1] >> 4) as usize]);
+            output.push(b'-');
+            output.push(CON[(pair[1] & 15) as usize]);
+            seed = (seed * 5 + pair[0] as usize * 7 + pair[1] as usize) % 36;
+        }
+    }
+    if input.len() % 2 == 0 {
+        output.push(VOW[seed % 6]);
+        output.push(CON[16]);
+        output.push(VOW[seed / 6]);
+    }
+    output.push(b'x');
+    unsafe {
+        // This is valid because letters are ascii.
+        String::from_utf8_unchecked(output)
+    }
+}
+
+/// Decodes with allocation.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadLength` if the input length is not 5 plus a multiple
+/// of 6.
+/// - `Error::BadCharacter` if the input contains a letter at an
+/// inappropriate position.
+/// - `Error::BadChecksum` if a vowel does not match the running
+/// checksum.
+pub fn decode(input: &[u8]) -> Result<Vec<u8>, Error> {
+    let len = input.len();
+    check!(BadLength, len >= 5 && (len - 5) % 6 == 0);
+    check!(BadCharacter(0), input[0] == b'x');
+    check!(BadCharacter(len - 1), input[len - 1] == b'x');
+    let n = (len - 5) / 6;
+    let mut output = Vec::with_capacity(2 * n + 1);
+    let mut seed = 1;
+    for i in 0 .. n {
+        let pos = 1 + 6 * i;
+        let x = try!(decode_byte(input, pos, seed));
+        let hi = try!(val(CON, input, pos + 3));
+        check!(BadCharacter(pos + 3), hi < 16);
+        check!(BadCharacter(pos + 4), input[pos + 4] == b'-');
+        let lo = try!(val(CON, input, pos + 5));
+        check!(BadCharacter(pos + 5), lo < 16);
+        let y = (hi << 4 | lo) as u8;
+        output.push(x);
+        output.push(y);
+        seed = (seed * 5 + x as usize * 7 + y as usize) % 36;
+    }
+    let pos = 1 + 6 * n;
+    if input[pos + 1] == CON[16] {
+        check!(BadChecksum, try!(val(VOW, input, pos)) == seed % 6);
+        check!(BadChecksum, try!(val(VOW, input, pos + 2)) == seed / 6);
+    } else {
+        output.push(try!(decode_byte(input, pos, seed)));
+    }
+    Ok(output)
+}
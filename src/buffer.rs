@@ -0,0 +1,91 @@
+//! Stack buffer module.
+//!
+//! This module defines the [`Buffer`](struct.Buffer.html) type, which
+//! encodes small inputs into an internal array and returns the output
+//! as a borrowed string. This avoids allocation for the common case of
+//! encoding a short value, like a hash or a key, for display or
+//! logging.
+//!
+//! # Examples
+//!
+//! ```
+//! use data_encoding::buffer::Buffer;
+//! use data_encoding::specification::BASE16;
+//! let mut buffer = Buffer::new();
+//! assert_eq!(buffer.format(&BASE16, &[0xde, 0xad, 0xbe, 0xef]), "DEADBEEF");
+//! ```
+
+use std::fmt;
+
+use base::Base;
+use encode::{encode_len, encode_nopad_len, encode_mut_str, encode_nopad_mut_str};
+
+/// The capacity of a buffer.
+///
+/// This is enough to encode 64 bytes with a base of 4 bits, for
+/// instance a SHA-512 digest in hexadecimal.
+pub const CAPACITY: usize = 128;
+
+/// Stack buffer.
+///
+/// A buffer can be reused for several encodings, each one overwriting
+/// the previous one.
+#[derive(Copy,Clone)]
+pub struct Buffer {
+    bytes: [u8; CAPACITY],
+}
+
+impl Buffer {
+    /// Creates a buffer.
+    pub fn new() -> Buffer {
+        Buffer { bytes: [0; CAPACITY] }
+    }
+
+    /// Encodes an input (with padding) and returns the output.
+    ///
+    /// # Correctness
+    ///
+    /// The base must satisfy the `Base` invariants.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the output is longer than
+    /// [`CAPACITY`](constant.CAPACITY.html), or if the base has
+    /// non-ascii symbols or padding. May also panic if `base` does not
+    /// satisfy the `Base` invariants.
+    pub fn format<B: Base + ?Sized>(&mut self, base: &B, input: &[u8]) -> &str {
+        let len = encode_len(base, input.len());
+        assert!(len <= CAPACITY, "output too long for the buffer");
+        encode_mut_str(base, input, &mut self.bytes[.. len])
+    }
+
+    /// Encodes an input (without padding) and returns the output.
+    ///
+    /// # Correctness
+    ///
+    /// The base must satisfy the `Base` invariants.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the output is longer than
+    /// [`CAPACITY`](constant.CAPACITY.html), or if the base has
+    /// non-ascii symbols. May also panic if `base` does not satisfy the
+    /// `Base` invariants.
+    pub fn format_nopad<B: Base + ?Sized>(&mut self, base: &B, input: &[u8]) -> &str {
+        let len = encode_nopad_len(base, input.len());
+        assert!(len <= CAPACITY, "output too long for the buffer");
+        encode_nopad_mut_str(base, input, &mut self.bytes[.. len])
+    }
+}
+
+impl Default for Buffer {
+    fn default() -> Buffer {
+        Buffer::new()
+    }
+}
+
+impl fmt::Debug for Buffer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Buffer")
+    }
+}
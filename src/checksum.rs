@@ -0,0 +1,228 @@
+// Checksums and digests used by some encodings.
+
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+fn sha256_block(h: &mut [u32; 8], block: &[u8]) {
+    let mut w = [0u32; 64];
+    for i in 0 .. 16 {
+        w[i] = (block[4 * i] as u32) << 24 | (block[4 * i + 1] as u32) << 16
+            | (block[4 * i + 2] as u32) << 8 | block[4 * i + 3] as u32;
+    }
+    for i in 16 .. 64 {
+        let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ w[i - 15] >> 3;
+        let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ w[i - 2] >> 10;
+        w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+    }
+    let mut v = *h;
+    for i in 0 .. 64 {
+        let s1 = v[4].rotate_right(6) ^ v[4].rotate_right(11) ^ v[4].rotate_right(25);
+        let ch = v[4] & v[5] ^ !v[4] & v[6];
+        let t1 = v[7].wrapping_add(s1).wrapping_add(ch).wrapping_add(K[i]).wrapping_add(w[i]);
+        let s0 = v[0].rotate_right(2) ^ v[0].rotate_right(13) ^ v[0].rotate_right(22);
+        let maj = v[0] & v[1] ^ v[0] & v[2] ^ v[1] & v[2];
+        let t2 = s0.wrapping_add(maj);
+        v = [t1.wrapping_add(t2), v[0], v[1], v[2], v[3].wrapping_add(t1), v[4], v[5], v[6]];
+    }
+    for i in 0 .. 8 {
+        h[i] = h[i].wrapping_add(v[i]);
+    }
+}
+
+/// Returns the SHA-256 digest of the input.
+pub fn sha256(input: &[u8]) -> [u8; 32] {
+    let mut h: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a,
+        0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+    ];
+    let n = input.len() / 64;
+    for i in 0 .. n {
+        sha256_block(&mut h, &input[64 * i .. 64 * (i + 1)]);
+    }
+    let rest = &input[64 * n ..];
+    let mut last = [0u8; 128];
+    last[.. rest.len()].copy_from_slice(rest);
+    last[rest.len()] = 0x80;
+    let blocks = if rest.len() < 56 { 1 } else { 2 };
+    let bits = (input.len() as u64).wrapping_mul(8);
+    for i in 0 .. 8 {
+        last[64 * blocks - 1 - i] = (bits >> 8 * i) as u8;
+    }
+    for i in 0 .. blocks {
+        sha256_block(&mut h, &last[64 * i .. 64 * (i + 1)]);
+    }
+    let mut output = [0u8; 32];
+    for i in 0 .. 32 {
+        output[i] = (h[i / 4] >> 8 * (3 - i % 4)) as u8;
+    }
+    output
+}
+
+const RC: [u64; 24] = [
+    0x0000000000000001, 0x0000000000008082, 0x800000000000808a, 0x8000000080008000,
+    0x000000000000808b, 0x0000000080000001, 0x8000000080008081, 0x8000000000008009,
+    0x000000000000008a, 0x0000000000000088, 0x0000000080008009, 0x000000008000000a,
+    0x000000008000808b, 0x800000000000008b, 0x8000000000008089, 0x8000000000008003,
+    0x8000000000008002, 0x8000000000000080, 0x000000000000800a, 0x800000008000000a,
+    0x8000000080008081, 0x8000000000008080, 0x0000000080000001, 0x8000000080008008,
+];
+
+// Rotation offsets indexed by `x + 5 * y`.
+const ROT: [u32; 25] = [
+    0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
+];
+
+fn keccak_f(a: &mut [u64; 25]) {
+    for round in 0 .. 24 {
+        let mut c = [0u64; 5];
+        for x in 0 .. 5 {
+            c[x] = a[x] ^ a[x + 5] ^ a[x + 10] ^ a[x + 15] ^ a[x + 20];
+        }
+        for x in 0 .. 5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in 0 .. 5 {
+                a[x + 5 * y] ^= d;
+            }
+        }
+        let mut b = [0u64; 25];
+        for x in 0 .. 5 {
+            for y in 0 .. 5 {
+                b[y + 5 * ((2 * x + 3 * y) % 5)] = a[x + 5 * y].rotate_left(ROT[x + 5 * y]);
+            }
+        }
+        for x in 0 .. 5 {
+            for y in 0 .. 5 {
+                a[x + 5 * y] = b[x + 5 * y] ^ !b[(x + 1) % 5 + 5 * y] & b[(x + 2) % 5 + 5 * y];
+            }
+        }
+        a[0] ^= RC[round];
+    }
+}
+
+fn keccak_absorb(a: &mut [u64; 25], block: &[u8]) {
+    for (i, lane) in block.chunks(8).enumerate() {
+        for (j, &x) in lane.iter().enumerate() {
+            a[i] ^= (x as u64) << 8 * j;
+        }
+    }
+    keccak_f(a);
+}
+
+/// Returns the SHA3-256 digest of the input.
+pub fn sha3_256(input: &[u8]) -> [u8; 32] {
+    const RATE: usize = 136;
+    let mut a = [0u64; 25];
+    let n = input.len() / RATE;
+    for i in 0 .. n {
+        keccak_absorb(&mut a, &input[RATE * i .. RATE * (i + 1)]);
+    }
+    let rest = &input[RATE * n ..];
+    let mut last = [0u8; RATE];
+    last[.. rest.len()].copy_from_slice(rest);
+    last[rest.len()] ^= 0x06;
+    last[RATE - 1] ^= 0x80;
+    keccak_absorb(&mut a, &last);
+    let mut output = [0u8; 32];
+    for i in 0 .. 32 {
+        output[i] = (a[i / 8] >> 8 * (i % 8)) as u8;
+    }
+    output
+}
+
+/// Returns the CRC-32 (IEEE 802.3) of the input.
+pub fn crc32(input: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &x in input {
+        crc ^= x as u32;
+        for _ in 0 .. 8 {
+            crc = if crc & 1 == 1 { crc >> 1 ^ 0xedb88320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+/// Returns the CRC-16 (XMODEM) of the input.
+pub fn crc16(input: &[u8]) -> u16 {
+    let mut crc = 0u16;
+    for &x in input {
+        crc ^= (x as u16) << 8;
+        for _ in 0 .. 8 {
+            crc = if crc & 0x8000 != 0 { crc << 1 ^ 0x1021 } else { crc << 1 };
+        }
+    }
+    crc
+}
+
+/// Returns the CRC-24 (OpenPGP) of the input.
+pub fn crc24(input: &[u8]) -> u32 {
+    let mut crc = 0xb704ce;
+    for &x in input {
+        crc ^= (x as u32) << 16;
+        for _ in 0 .. 8 {
+            crc <<= 1;
+            if crc & 0x1000000 != 0 {
+                crc ^= 0x1864cfb;
+            }
+        }
+    }
+    crc & 0xffffff
+}
+
+#[test]
+fn sha256_vectors() {
+    fn hex(x: &[u8]) -> String {
+        ::hexlower::encode(x)
+    }
+    assert_eq!(hex(&sha256(b"")),
+               "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+    assert_eq!(hex(&sha256(b"abc")),
+               "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+    assert_eq!(hex(&sha256(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq")),
+               "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1");
+    assert_eq!(hex(&sha256(&[b'a'; 1000])),
+               "41edece42d63e8d9bf515a9ba6932e1c20cbc9f5a5d134645adb5db1b9737ea3");
+}
+
+#[test]
+fn sha3_256_vectors() {
+    fn hex(x: &[u8]) -> String {
+        ::hexlower::encode(x)
+    }
+    assert_eq!(hex(&sha3_256(b"")),
+               "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a");
+    assert_eq!(hex(&sha3_256(b"abc")),
+               "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532");
+    assert_eq!(hex(&sha3_256(&[b'a'; 135])),
+               "8094bb53c44cfb1e67b7c30447f9a1c33696d2463ecc1d9c92538913392843c9");
+    assert_eq!(hex(&sha3_256(&[b'a'; 136])),
+               "3fc5559f14db8e453a0a3091edbd2bc25e11528d81c66fa570a4efdcc2695ee1");
+    assert_eq!(hex(&sha3_256(&[b'a'; 1000])),
+               "8f3934e6f7a15698fe0f396b95d8c4440929a8fa6eae140171c068b4549fbf81");
+}
+
+#[test]
+fn crc32_vectors() {
+    assert_eq!(crc32(b""), 0);
+    assert_eq!(crc32(b"123456789"), 0xcbf43926);
+    assert_eq!(crc32(b"The quick brown fox jumps over the lazy dog"), 0x414fa339);
+}
+
+#[test]
+fn crc16_vectors() {
+    assert_eq!(crc16(b""), 0);
+    assert_eq!(crc16(b"123456789"), 0x31c3);
+}
+
+#[test]
+fn crc24_vectors() {
+    assert_eq!(crc24(b""), 0xb704ce);
+    assert_eq!(crc24(b"123456789"), 0x21cf02);
+}
@@ -0,0 +1,83 @@
+//! Data URI Scheme.
+//!
+//! A data URI is `data:` followed by a media type, optionally
+//! followed by `;base64`, followed by `,` and the data. The data is
+//! encoded with [`base64`](../base64/index.html) if `;base64` is
+//! present, and with [`percent`](../percent/index.html) encoding
+//! otherwise.
+//!
+//! # Conformance
+//!
+//! [RFC 2397](https://tools.ietf.org/html/rfc2397) compliant. The
+//! percent-encoded form uses the
+//! [`QUERY`](../percent/constant.QUERY.html) set.
+
+use decode::Error;
+use decode::Error::*;
+use percent::QUERY;
+
+/// The media type of data URIs without one.
+pub const DEFAULT: &'static str = "text/plain;charset=US-ASCII";
+
+fn prefix(mime: &str) -> String {
+    assert!(!mime.contains(','));
+    format!("data:{}", mime)
+}
+
+/// Encodes with base64.
+///
+/// # Panics
+///
+/// Panics if `mime` contains `,`.
+pub fn encode(mime: &str, input: &[u8]) -> String {
+    format!("{};base64,{}", prefix(mime), ::base64::encode(input))
+}
+
+/// Encodes with percent-encoding.
+///
+/// # Panics
+///
+/// Panics if `mime` contains `,`.
+pub fn encode_percent(mime: &str, input: &[u8]) -> String {
+    format!("{},{}", prefix(mime), ::percent::encode(input, &QUERY))
+}
+
+/// Decodes with allocation.
+///
+/// This function returns the media type and the data. The `data:`
+/// prefix and the `;base64` suffix are case-insensitive. An empty
+/// media type is replaced by [`DEFAULT`](constant.DEFAULT.html) and a
+/// media type starting with `;` is prefixed with `text/plain`.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadCharacter` if the input does not start with `data:`
+/// or if the media type is not ascii.
+/// - `Error::BadLength` if the input does not contain `,`.
+///
+/// Decoding may also fail in the circumstances defined by
+/// [`base64::decode`](../base64/fn.decode.html) or
+/// [`percent::decode`](../percent/fn.decode.html).
+pub fn decode(input: &[u8]) -> Result<(String, Vec<u8>), Error> {
+    check!(BadCharacter(0), input.len() >= 5 && input[.. 5].eq_ignore_ascii_case(b"data:"));
+    let n = try!(input.iter().position(|&x| x == b',').ok_or(BadLength));
+    let mut mime = &input[5 .. n];
+    if let Some(i) = mime.iter().position(|&x| x >= 128) {
+        return Err(BadCharacter(5 + i));
+    }
+    let base64 = mime.len() >= 7 && mime[mime.len() - 7 ..].eq_ignore_ascii_case(b";base64");
+    if base64 {
+        mime = &mime[.. mime.len() - 7];
+    }
+    let mut mime: String = mime.iter().map(|&x| x as char).collect();
+    if mime.is_empty() {
+        mime = DEFAULT.to_string();
+    } else if mime.starts_with(';') {
+        mime.insert_str(0, "text/plain");
+    }
+    let data = &input[n + 1 ..];
+    let output = if base64 { ::base64::decode(data) } else { ::percent::decode(data) };
+    Ok((mime, try!(output.map_err(|e| e.shift(n + 1)))))
+}
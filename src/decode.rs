@@ -1,34 +1,61 @@
 //! Generic decoding module.
 
 use std::{error, fmt};
+use std::mem::MaybeUninit;
 
-use base::{Base, enc, dec};
-use tool::{div_ceil, chunk, chunk_mut, chunk_unchecked, chunk_mut_unchecked};
+use base::{Base, BitOrder, enc, dec};
+use tool::{chunk, chunk_mut, chunk_unchecked, chunk_mut_unchecked};
+use tool::{write_uninit, assume_init};
+use encode::checked_encode_nopad_len;
 
 use self::Error::*;
 
-fn decode_block<B: Base>
+// Position of the value of index `j` in a block.
+fn shift_val<B: Base + ?Sized>(base: &B, j: usize) -> usize {
+    match base.bit_order() {
+        BitOrder::MostSignificantFirst => base.bit() * (dec(base) - 1 - j),
+        BitOrder::LeastSignificantFirst => base.bit() * j,
+    }
+}
+
+// Position of the byte of index `j` in a block.
+fn shift_byte<B: Base + ?Sized>(base: &B, j: usize) -> usize {
+    match base.bit_order() {
+        BitOrder::MostSignificantFirst => 8 * (enc(base) - 1 - j),
+        BitOrder::LeastSignificantFirst => 8 * j,
+    }
+}
+
+// Returns whether the bits of a block after its first `r` bytes are
+// zero.
+fn trailing_zero<B: Base + ?Sized>(base: &B, x: u64, r: usize) -> bool {
+    match base.bit_order() {
+        BitOrder::MostSignificantFirst => x & ((1 << 8 * (enc(base) - r)) - 1) == 0,
+        BitOrder::LeastSignificantFirst => x >> 8 * r == 0,
+    }
+}
+
+fn decode_block<B: Base + ?Sized>
     (base: &B, input: &[u8], output: &mut [u8]) -> Result<u64, Error>
 {
-    let mut x = 0u64; // This is enough because `base.len() <= 40`.
+    let mut x = 0u64; // This is enough because `base.len() <= 56`.
     for j in 0 .. input.len() {
         let y = try!(base.val(input[j]).ok_or(BadCharacter(j)));
-        x |= (y as u64) << base.bit() * (dec(base) - 1 - j);
+        x |= (y as u64) << shift_val(base, j);
     }
     for j in 0 .. output.len() {
-        output[j] = (x >> 8 * (enc(base) - 1 - j)) as u8;
+        output[j] = (x >> shift_byte(base, j)) as u8;
     }
     Ok(x)
 }
 
-fn decode_last<B: Base>
+fn decode_last<B: Base + ?Sized>
     (base: &B, input: &[u8], output: &mut [u8]) -> Result<usize, Error>
 {
     let bit = base.bit();
-    let enc = enc(base);
     let dec = dec(base);
     let mut r = 0;
-    let mut x = 0u64; // This is enough because `base.len() <= 40`.
+    let mut x = 0u64; // This is enough because `base.len() <= 56`.
     for j in 0 .. dec {
         if bit * j / 8 > r {
             r += 1;
@@ -36,18 +63,16 @@ fn decode_last<B: Base>
                 for k in j .. dec {
                     check!(BadCharacter(k), input[k] == base.pad());
                 }
-                let s = bit * j - 8 * r;
-                let p = (x >> 8 * (enc - 1 - r)) as u8 >> 8 - s;
-                check!(BadPadding, p == 0);
+                check!(BadPadding, !base.check_trailing_bits() || trailing_zero(base, x, r));
                 break;
             }
         }
         let y = try!(base.val(input[j]).ok_or(BadCharacter(j)));
-        x |= (y as u64) << bit * (dec - 1 - j);
+        x |= (y as u64) << shift_val(base, j);
         if j == dec - 1 { r += 1; }
     }
     for j in 0 .. r {
-        output[j] = (x >> 8 * (enc - 1 - j)) as u8;
+        output[j] = (x >> shift_byte(base, j)) as u8;
     }
     Ok(r)
 }
@@ -59,9 +84,25 @@ fn decode_last<B: Base>
 ///
 /// # Panics
 ///
+/// Panics if the output length overflows, see
+/// [`checked_decode_len`](fn.checked_decode_len.html). May also
+/// panic if `base` does not satisfy the `Base` invariants.
+pub fn decode_len<B: Base + ?Sized>(base: &B, len: usize) -> usize {
+    checked_decode_len(base, len).expect("decoding length overflow")
+}
+
+/// Converts an input length to its output length (with padding) and
+/// checks for overflow.
+///
+/// This function returns `None` if the output length does not fit in
+/// a `usize`. This may only happen for invalid input lengths.
+///
+/// # Panics
+///
 /// May panic if `base` does not satisfy the `Base` invariants.
-pub fn decode_len<B: Base>(base: &B, len: usize) -> usize {
-    div_ceil(len, dec(base)) * enc(base)
+pub fn checked_decode_len<B: Base + ?Sized>(base: &B, len: usize) -> Option<usize> {
+    let dec = dec(base);
+    (len / dec + (len % dec != 0) as usize).checked_mul(enc(base))
 }
 
 /// Converts an input length to its output length (without padding).
@@ -72,13 +113,54 @@ pub fn decode_len<B: Base>(base: &B, len: usize) -> usize {
 /// # Failures
 ///
 /// Invalid input length returns `Error::BadLength`.
-pub fn decode_nopad_len<B: Base>(base: &B, len: usize) -> Result<usize, Error> {
-    let olen = base.bit() * len / 8;
-    let ilen = div_ceil(8 * olen, base.bit());
-    if len != ilen { return Err(BadLength); }
+pub fn decode_nopad_len<B: Base + ?Sized>(base: &B, len: usize) -> Result<usize, Error> {
+    let bit = base.bit();
+    let olen = len / 8 * bit + len % 8 * bit / 8;
+    check!(BadLength, checked_encode_nopad_len(base, olen) == Some(len));
     Ok(olen)
 }
 
+/// Computes the exact output length of an input (with padding).
+///
+/// Contrary to [`decode_len`](fn.decode_len.html) which returns the
+/// worst case for an input length, this function inspects the padding
+/// of the last block to return the exact length of the decoded data.
+/// Only the padding is checked, symbols are not.
+///
+/// # Examples
+///
+/// ```
+/// use data_encoding::{base64, decode};
+/// assert_eq!(decode::decode_len(base64::base(), 8), 6);
+/// assert_eq!(decode::decode_exact_len(base64::base(), b"Zm9vYg=="), Ok(4));
+/// ```
+///
+/// # Failures
+///
+/// Returns `Error::BadLength` if the input length is not a multiple of
+/// the decoding length, and `Error::BadCharacter` if the padding of
+/// the last block does not start at a valid position or is
+/// interrupted.
+///
+/// # Panics
+///
+/// May panic if `base` does not satisfy the `Base` invariants.
+pub fn decode_exact_len<B: Base + ?Sized>(base: &B, input: &[u8]) -> Result<usize, Error> {
+    let enc = enc(base);
+    let dec = dec(base);
+    let ilen = input.len();
+    if ilen == 0 { return Ok(0); }
+    check!(BadLength, ilen % dec == 0);
+    let last = &input[ilen - dec ..];
+    let n = last.iter().position(|&x| x == base.pad()).unwrap_or(dec);
+    for k in n .. dec {
+        check!(BadCharacter(ilen - dec + k), last[k] == base.pad());
+    }
+    let r = base.bit() * n / 8;
+    check!(BadCharacter(ilen - dec + n), r > 0 && checked_encode_nopad_len(base, r) == Some(n));
+    Ok((ilen / dec - 1) * enc + r)
+}
+
 /// Generic decoding function without allocation (with padding).
 ///
 /// This function takes a base implementation, a shared input slice, a
@@ -99,7 +181,35 @@ pub fn decode_nopad_len<B: Base>(base: &B, len: usize) -> Result<usize, Error> {
 ///
 /// Panics if `output.len() != decode_len(input.len())`. May also
 /// panic if `base` does not satisfy the `Base` invariants.
-pub fn decode_mut<B: Base>
+pub fn decode_mut<B: Base + ?Sized>
+    (base: &B, input: &[u8], output: &mut [u8]) -> Result<usize, Error>
+{
+    match try_decode_mut(base, input, output) {
+        Err(BadOutput) => panic!("unexpected output length"),
+        result => result,
+    }
+}
+
+/// Generic decoding function without allocation (with padding) and
+/// without output length panic.
+///
+/// This function behaves like [`decode_mut`](fn.decode_mut.html) but
+/// returns `Error::BadOutput` instead of panicking if `output.len() !=
+/// decode_len(input.len())`, in which case the output is untouched.
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`Error`](enum.Error.html).
+///
+/// # Panics
+///
+/// May panic if `base` does not satisfy the `Base` invariants.
+pub fn try_decode_mut<B: Base + ?Sized>
     (base: &B, input: &[u8], output: &mut [u8]) -> Result<usize, Error>
 {
     let enc = enc(base);
@@ -107,7 +217,7 @@ pub fn decode_mut<B: Base>
     let ilen = input.len();
     if ilen == 0 { return Ok(0); }
     if ilen % dec != 0 { return Err(BadLength); }
-    assert_eq!(output.len(), decode_len(base, ilen));
+    check!(BadOutput, output.len() == decode_len(base, ilen));
     let n = ilen / dec - 1;
     for i in 0 .. n {
         let input = unsafe { chunk_unchecked(input, dec, i) };
@@ -140,14 +250,44 @@ pub fn decode_mut<B: Base>
 /// Panics if `output.len() !=
 /// decode_nopad_len(input.len()).unwrap()`. May also panic if `base`
 /// does not satisfy the `Base` invariants.
-pub fn decode_nopad_mut<B: Base>
+pub fn decode_nopad_mut<B: Base + ?Sized>
+    (base: &B, input: &[u8], output: &mut [u8]) -> Result<(), Error>
+{
+    match try_decode_nopad_mut(base, input, output) {
+        Err(BadOutput) => panic!("unexpected output length"),
+        result => result,
+    }
+}
+
+/// Generic decoding function without allocation (without padding)
+/// and without output length panic.
+///
+/// This function behaves like
+/// [`decode_nopad_mut`](fn.decode_nopad_mut.html) but returns
+/// `Error::BadOutput` instead of panicking if `output.len() !=
+/// decode_nopad_len(input.len()).unwrap()`, in which case the output
+/// is untouched.
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`Error`](enum.Error.html). Padding are unexpected characters.
+///
+/// # Panics
+///
+/// May panic if `base` does not satisfy the `Base` invariants.
+pub fn try_decode_nopad_mut<B: Base + ?Sized>
     (base: &B, input: &[u8], output: &mut [u8]) -> Result<(), Error>
 {
     let enc = enc(base);
     let dec = dec(base);
     let ilen = input.len();
     let olen = try!(decode_nopad_len(base, ilen));
-    assert_eq!(output.len(), olen);
+    check!(BadOutput, output.len() == olen);
     let n = ilen / dec;
     for i in 0 .. n {
         let input = unsafe { chunk_unchecked(input, dec, i) };
@@ -157,9 +297,7 @@ pub fn decode_nopad_mut<B: Base>
     }
     let x = try!(decode_block(base, &input[dec * n ..], &mut output[enc * n ..])
                  .map_err(|e| e.shift(dec * n)));
-    if (x >> 8 * (enc * (n + 1) - (olen + 1))) as u8 != 0 {
-        return Err(BadPadding);
-    }
+    check!(BadPadding, !base.check_trailing_bits() || trailing_zero(base, x, olen - enc * n));
     Ok(())
 }
 
@@ -182,8 +320,9 @@ pub fn decode_nopad_mut<B: Base>
 /// # Panics
 ///
 /// May panic if `base` does not satisfy the `Base` invariants.
-pub fn decode<B: Base>(base: &B, input: &[u8]) -> Result<Vec<u8>, Error> {
-    let mut output = vec![0u8; decode_len(base, input.len())];
+pub fn decode<B: Base + ?Sized>(base: &B, input: &[u8]) -> Result<Vec<u8>, Error> {
+    let len = try!(checked_decode_len(base, input.len()).ok_or(BadLength));
+    let mut output = vec![0u8; len];
     let len = try!(decode_mut(base, input, &mut output));
     output.truncate(len);
     Ok(output)
@@ -209,12 +348,589 @@ pub fn decode<B: Base>(base: &B, input: &[u8]) -> Result<Vec<u8>, Error> {
 /// # Panics
 ///
 /// May panic if `base` does not satisfy the `Base` invariants.
-pub fn decode_nopad<B: Base>(base: &B, input: &[u8]) -> Result<Vec<u8>, Error> {
+pub fn decode_nopad<B: Base + ?Sized>(base: &B, input: &[u8]) -> Result<Vec<u8>, Error> {
     let mut output = vec![0u8; try!(decode_nopad_len(base, input.len()))];
     try!(decode_nopad_mut(base, input, &mut output));
     Ok(output)
 }
 
+/// Generic decoding function appending to a vector (with padding).
+///
+/// This function behaves like [`decode`](fn.decode.html) but appends
+/// the output to `output`, using its spare capacity if sufficient,
+/// instead of allocating a new vector. It returns the length of the
+/// appended data.
+///
+/// # Examples
+///
+/// ```
+/// use data_encoding::{base64, decode};
+/// let mut output = b"foo".to_vec();
+/// assert_eq!(decode::decode_append(base64::base(), b"YmFy", &mut output), Ok(3));
+/// assert_eq!(output, b"foobar");
+/// ```
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`Error`](enum.Error.html), in which case `output` is left
+/// unchanged.
+///
+/// # Panics
+///
+/// May panic if `base` does not satisfy the `Base` invariants.
+pub fn decode_append<B: Base + ?Sized>
+    (base: &B, input: &[u8], output: &mut Vec<u8>) -> Result<usize, Error>
+{
+    let start = output.len();
+    output.resize(start + decode_len(base, input.len()), 0);
+    match decode_mut(base, input, &mut output[start ..]) {
+        Ok(len) => {
+            output.truncate(start + len);
+            Ok(len)
+        }
+        Err(e) => {
+            output.truncate(start);
+            Err(e)
+        }
+    }
+}
+
+/// Generic decoding function to uninitialized memory (with padding).
+///
+/// This function behaves like [`decode_mut`](fn.decode_mut.html) but
+/// the output does not need to be initialized beforehand. It returns
+/// the initialized prefix of the output, which holds the decoded data.
+///
+/// # Examples
+///
+/// ```
+/// use std::mem::MaybeUninit;
+/// use data_encoding::{base64, decode};
+/// let mut buffer = [MaybeUninit::uninit(); 6];
+/// let output = decode::decode_uninit_mut(base64::base(), b"Zm9vYg==", &mut buffer);
+/// assert_eq!(output.unwrap(), b"foob");
+/// ```
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`Error`](enum.Error.html).
+///
+/// # Panics
+///
+/// Panics if `output.len() != decode_len(input.len())`. May also
+/// panic if `base` does not satisfy the `Base` invariants.
+pub fn decode_uninit_mut<'a, B: Base + ?Sized>
+    (base: &B, input: &[u8], output: &'a mut [MaybeUninit<u8>]) -> Result<&'a mut [u8], Error>
+{
+    let enc = enc(base);
+    let dec = dec(base);
+    let ilen = input.len();
+    assert_eq!(output.len(), decode_len(base, ilen));
+    if ilen == 0 { return Ok(&mut []); }
+    if ilen % dec != 0 { return Err(BadLength); }
+    let mut block = [0u8; 7];
+    let n = ilen / dec - 1;
+    for i in 0 .. n {
+        let _ = try!(decode_block(base, chunk(input, dec, i), &mut block[.. enc])
+                     .map_err(|e| e.shift(dec * i)));
+        write_uninit(&mut output[enc * i .. enc * (i + 1)], &block[.. enc]);
+    }
+    let r = try!(decode_last(base, chunk(input, dec, n), &mut block[.. enc])
+                 .map_err(|e| e.shift(dec * n)));
+    write_uninit(&mut output[enc * n .. enc * n + r], &block[.. r]);
+    unsafe {
+        // This is valid because the first `enc * n + r` bytes have
+        // been initialized.
+        Ok(assume_init(&mut output[.. enc * n + r]))
+    }
+}
+
+/// Generic decoding function from an iterator (with padding).
+///
+/// This function behaves like [`decode`](fn.decode.html) but reads
+/// the input from an iterator of bytes, for instance when the input is
+/// not contiguous in memory. The input is read block by block and is
+/// never copied as a whole.
+///
+/// # Examples
+///
+/// ```
+/// use data_encoding::{base64, decode};
+/// let chunks: Vec<&[u8]> = vec![b"Zm", b"9vY", b"g=="];
+/// let input = chunks.iter().flat_map(|c| c.iter().cloned());
+/// assert_eq!(decode::decode_iter(base64::base(), input).unwrap(), b"foob");
+/// ```
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`Error`](enum.Error.html). Since the input length is only known at
+/// the end, a bad character may be reported for an input with a bad
+/// length.
+///
+/// # Panics
+///
+/// May panic if `base` does not satisfy the `Base` invariants.
+pub fn decode_iter<B: Base + ?Sized, I: IntoIterator<Item = u8>>
+    (base: &B, input: I) -> Result<Vec<u8>, Error>
+{
+    let enc = enc(base);
+    let dec = dec(base);
+    let mut input = input.into_iter().peekable();
+    let mut output = Vec::with_capacity(decode_len(base, input.size_hint().0));
+    let mut block = [0u8; 8];
+    let mut buffer = [0u8; 7];
+    let mut pos = 0;
+    loop {
+        let mut k = 0;
+        while k < dec {
+            match input.next() {
+                Some(x) => block[k] = x,
+                None => break,
+            }
+            k += 1;
+        }
+        if k == 0 { return Ok(output); }
+        check!(BadLength, k == dec);
+        if input.peek().is_none() {
+            let r = try!(decode_last(base, &block[.. dec], &mut buffer[.. enc])
+                         .map_err(|e| e.shift(pos)));
+            output.extend_from_slice(&buffer[.. r]);
+            return Ok(output);
+        }
+        let _ = try!(decode_block(base, &block[.. dec], &mut buffer[.. enc])
+                     .map_err(|e| e.shift(pos)));
+        output.extend_from_slice(&buffer[.. enc]);
+        pos += dec;
+    }
+}
+
+/// Generic decoding function in place (with padding).
+///
+/// This function behaves like [`decode_mut`](fn.decode_mut.html) but
+/// decodes `buffer` onto itself, which is possible since the output is
+/// never longer than the input. It returns the length of the decoded
+/// data, which is at the start of the buffer.
+///
+/// # Examples
+///
+/// ```
+/// use data_encoding::{base64, decode};
+/// let mut buffer = b"Zm9vYmFy".to_vec();
+/// let len = decode::decode_in_place(base64::base(), &mut buffer).unwrap();
+/// buffer.truncate(len);
+/// assert_eq!(buffer, b"foobar");
+/// ```
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`Error`](enum.Error.html), in which case the content of the buffer
+/// is unspecified.
+///
+/// # Panics
+///
+/// May panic if `base` does not satisfy the `Base` invariants.
+pub fn decode_in_place<B: Base + ?Sized>(base: &B, buffer: &mut [u8]) -> Result<usize, Error> {
+    let enc = enc(base);
+    let dec = dec(base);
+    let ilen = buffer.len();
+    if ilen == 0 { return Ok(0); }
+    if ilen % dec != 0 { return Err(BadLength); }
+    // Blocks are copied since they may overlap with their output.
+    let mut input = [0u8; 8];
+    let mut output = [0u8; 7];
+    let n = ilen / dec - 1;
+    for i in 0 .. n {
+        input[.. dec].copy_from_slice(chunk(buffer, dec, i));
+        let _ = try!(decode_block(base, &input[.. dec], &mut output[.. enc])
+                     .map_err(|e| e.shift(dec * i)));
+        chunk_mut(buffer, enc, i).copy_from_slice(&output[.. enc]);
+    }
+    input[.. dec].copy_from_slice(chunk(buffer, dec, n));
+    let r = try!(decode_last(base, &input[.. dec], &mut output[.. enc])
+                 .map_err(|e| e.shift(dec * n)));
+    buffer[enc * n .. enc * n + r].copy_from_slice(&output[.. r]);
+    Ok(enc * n + r)
+}
+
+/// Generic partial decoding function (with padding).
+///
+/// This function decodes as many complete blocks of the input as fit
+/// in the output, and returns the number of bytes consumed from the
+/// input and the number of bytes produced in the output. It never
+/// panics on length mismatch, which permits to decode chunk by chunk
+/// into fixed-size buffers.
+///
+/// A block with padding is the last block of an encoding, after which
+/// decoding stops. The remaining input, if any, is not consumed.
+/// Input shorter than a block is not consumed either, it should be
+/// completed with the next chunk or rejected once the input is
+/// complete.
+///
+/// # Examples
+///
+/// ```
+/// use data_encoding::{base64, decode};
+/// let mut output = [0u8; 4];
+/// assert_eq!(decode::decode_partial(base64::base(), b"Zm9vYmFy", &mut output), Ok((4, 3)));
+/// assert_eq!(&output[.. 3], b"foo");
+/// ```
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`Error`](enum.Error.html), except `Error::BadLength`.
+///
+/// # Panics
+///
+/// May panic if `base` does not satisfy the `Base` invariants.
+pub fn decode_partial<B: Base + ?Sized>
+    (base: &B, input: &[u8], output: &mut [u8]) -> Result<(usize, usize), Error>
+{
+    let enc = enc(base);
+    let dec = dec(base);
+    let mut block = [0u8; 7];
+    let mut olen = 0;
+    for (i, input) in input.chunks(dec).enumerate() {
+        if input.len() < dec { break; }
+        let padded = input[dec - 1] == base.pad();
+        let r = if padded {
+            try!(decode_last(base, input, &mut block[.. enc]).map_err(|e| e.shift(dec * i)))
+        } else {
+            let _ = try!(decode_block(base, input, &mut block[.. enc])
+                         .map_err(|e| e.shift(dec * i)));
+            enc
+        };
+        if olen + r > output.len() { return Ok((dec * i, olen)); }
+        output[olen .. olen + r].copy_from_slice(&block[.. r]);
+        olen += r;
+        if padded { return Ok((dec * (i + 1), olen)); }
+    }
+    Ok((olen / enc * dec, olen))
+}
+
+/// Converts an input length to its output length (with optional
+/// padding).
+///
+/// This function is meant to be used in conjunction with
+/// [`decode_opt_pad_mut`](fn.decode_opt_pad_mut.html). It returns
+/// [`decode_len`](fn.decode_len.html) if the input length is a
+/// multiple of the decoding length and
+/// [`decode_nopad_len`](fn.decode_nopad_len.html) otherwise.
+///
+/// # Failures
+///
+/// Invalid input length returns `Error::BadLength`.
+pub fn decode_opt_pad_len<B: Base + ?Sized>(base: &B, len: usize) -> Result<usize, Error> {
+    if len % dec(base) == 0 { return Ok(decode_len(base, len)); }
+    decode_nopad_len(base, len)
+}
+
+/// Generic decoding function without allocation (with optional
+/// padding).
+///
+/// This function accepts both padded and unpadded input. An input
+/// whose length is a multiple of the decoding length is decoded
+/// with [`decode_mut`](fn.decode_mut.html), otherwise it is decoded
+/// with [`decode_nopad_mut`](fn.decode_nopad_mut.html). It returns
+/// the length of the decoded data.
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`Error`](enum.Error.html).
+///
+/// # Panics
+///
+/// Panics if `output.len() !=
+/// decode_opt_pad_len(input.len()).unwrap()`. May also panic if
+/// `base` does not satisfy the `Base` invariants.
+pub fn decode_opt_pad_mut<B: Base + ?Sized>
+    (base: &B, input: &[u8], output: &mut [u8]) -> Result<usize, Error>
+{
+    if input.len() % dec(base) == 0 { return decode_mut(base, input, output); }
+    try!(decode_nopad_mut(base, input, output));
+    Ok(output.len())
+}
+
+/// Generic decoding function with allocation (with optional padding).
+///
+/// This function is a wrapper for
+/// [`decode_opt_pad_mut`](fn.decode_opt_pad_mut.html) that allocates
+/// an output of sufficient size using
+/// [`decode_opt_pad_len`](fn.decode_opt_pad_len.html). The input may
+/// or may not be padded.
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`Error`](enum.Error.html).
+///
+/// # Panics
+///
+/// May panic if `base` does not satisfy the `Base` invariants.
+pub fn decode_opt_pad<B: Base + ?Sized>(base: &B, input: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut output = vec![0u8; try!(decode_opt_pad_len(base, input.len()))];
+    let len = try!(decode_opt_pad_mut(base, input, &mut output));
+    output.truncate(len);
+    Ok(output)
+}
+
+/// Generic decoding function with allocation (ignoring characters).
+///
+/// This function behaves like [`decode`](fn.decode.html) but skips
+/// the characters of `ignore` wherever they occur in the input, for
+/// instance line breaks and spaces. The input is not copied: blocks
+/// between ignored characters are decoded directly, and only blocks
+/// split by ignored characters are gathered first.
+///
+/// The ignored characters should not be symbols nor padding.
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`Error`](enum.Error.html). Reported positions refer to the input
+/// as given, and the length is the length without ignored characters.
+///
+/// # Panics
+///
+/// May panic if `base` does not satisfy the `Base` invariants.
+pub fn decode_ignore<B: Base + ?Sized>
+    (base: &B, ignore: &[u8], input: &[u8]) -> Result<Vec<u8>, Error>
+{
+    let mut skip = [false; 256];
+    for &x in ignore {
+        skip[x as usize] = true;
+    }
+    if !input.iter().any(|&x| skip[x as usize]) { return decode(base, input); }
+    let enc = enc(base);
+    let dec = dec(base);
+    let len = input.iter().filter(|&&x| !skip[x as usize]).count();
+    if len % dec != 0 { return Err(BadLength); }
+    // Returns the position in the input of the character at position
+    // `pos` once the ignored characters are removed.
+    let position = |pos: usize| {
+        (0 .. input.len()).filter(|&i| !skip[input[i] as usize]).nth(pos).unwrap()
+    };
+    let mut output = Vec::with_capacity(decode_len(base, len));
+    let mut block = [0u8; 8];
+    let mut buffer = [0u8; 7];
+    let mut j = 0;
+    let n = len / dec;
+    for i in 0 .. n {
+        while skip[input[j] as usize] { j += 1; }
+        let direct = j + dec <= input.len() && !input[j .. j + dec].iter().any(|&x| skip[x as usize]);
+        let chunk = if direct {
+            j += dec;
+            &input[j - dec .. j]
+        } else {
+            let mut k = 0;
+            while k < dec {
+                if !skip[input[j] as usize] {
+                    block[k] = input[j];
+                    k += 1;
+                }
+                j += 1;
+            }
+            &block[.. dec]
+        };
+        let r = if i + 1 < n {
+            decode_block(base, chunk, &mut buffer[.. enc]).map(|_| enc)
+        } else {
+            decode_last(base, chunk, &mut buffer[.. enc])
+        };
+        let r = try!(r.map_err(|e| e.shift(dec * i).map(&position)));
+        output.extend_from_slice(&buffer[.. r]);
+    }
+    Ok(output)
+}
+
+/// Generic validation function (with padding).
+///
+/// This function behaves like [`decode_mut`](fn.decode_mut.html) but
+/// does not write any output. It returns the length of the decoded
+/// data, which is exact.
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Failures
+///
+/// Validation fails in the circumstances defined by
+/// [`Error`](enum.Error.html).
+///
+/// # Panics
+///
+/// May panic if `base` does not satisfy the `Base` invariants.
+pub fn validate<B: Base + ?Sized>(base: &B, input: &[u8]) -> Result<usize, Error> {
+    let enc = enc(base);
+    let dec = dec(base);
+    let ilen = input.len();
+    if ilen == 0 { return Ok(0); }
+    if ilen % dec != 0 { return Err(BadLength); }
+    let mut output = [0u8; 7];
+    let n = ilen / dec - 1;
+    for i in 0 .. n {
+        let _ = try!(decode_block(base, chunk(input, dec, i), &mut output[.. enc])
+                     .map_err(|e| e.shift(dec * i)));
+    }
+    decode_last(base, chunk(input, dec, n), &mut output[.. enc])
+        .map_err(|e| e.shift(dec * n))
+        .map(|r| enc * n + r)
+}
+
+/// Returns whether an input would decode successfully (with padding).
+///
+/// See [`validate`](fn.validate.html) for details.
+pub fn is_valid<B: Base + ?Sized>(base: &B, input: &[u8]) -> bool {
+    validate(base, input).is_ok()
+}
+
+/// Returns whether an input is in canonical form (with padding).
+///
+/// An input is canonical if it is valid and is the encoding of its
+/// decoding, symbol by symbol. For instance, an input with non-zero
+/// trailing bits may be valid for a base that does not check trailing
+/// bits, but it is not canonical. Like [`validate`](fn.validate.html)
+/// this function does not write any output.
+///
+/// # Examples
+///
+/// ```
+/// use data_encoding::{decode, specification};
+/// let mut spec = specification::BASE64.specification();
+/// spec.check_trailing_bits = false;
+/// let base = spec.encoding().unwrap();
+/// assert!(decode::is_canonical(&base, b"Zg=="));
+/// assert!(decode::is_valid(&base, b"Zh=="));
+/// assert!(!decode::is_canonical(&base, b"Zh=="));
+/// ```
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Panics
+///
+/// May panic if `base` does not satisfy the `Base` invariants.
+pub fn is_canonical<B: Base + ?Sized>(base: &B, input: &[u8]) -> bool {
+    let enc = enc(base);
+    let dec = dec(base);
+    let ilen = input.len();
+    if ilen % dec != 0 { return false; }
+    let mut output = [0u8; 7];
+    let mut block = [0u8; 8];
+    for (i, input) in input.chunks(dec).enumerate() {
+        let r = if dec * (i + 1) < ilen {
+            decode_block(base, input, &mut output[.. enc]).map(|_| enc)
+        } else {
+            decode_last(base, input, &mut output[.. enc])
+        };
+        let r = match r {
+            Ok(r) => r,
+            Err(_) => return false,
+        };
+        ::encode::encode_mut(base, &output[.. r], &mut block[.. dec]);
+        if &block[.. dec] != input { return false; }
+    }
+    true
+}
+
+/// Generic normalization function (with optional padding).
+///
+/// This function decodes the input and encodes it back block by block,
+/// in a single pass. The output is the canonical form of the input, as
+/// defined by [`is_canonical`](fn.is_canonical.html): symbols accepted
+/// by decoding are replaced by the symbols used by encoding, non-zero
+/// trailing bits are cleared if the base does not check them, and
+/// padding is added if missing. The input may or may not be padded,
+/// like for [`decode_opt_pad`](fn.decode_opt_pad.html).
+///
+/// # Examples
+///
+/// ```
+/// use data_encoding::{base64, hexlower};
+/// assert_eq!(hexlower::normalize(b"DeadBeef").unwrap(), "deadbeef");
+/// assert_eq!(base64::normalize(b"Zm9vYg").unwrap(), "Zm9vYg==");
+/// ```
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Failures
+///
+/// Normalization fails in the circumstances defined by
+/// [`Error`](enum.Error.html).
+///
+/// # Panics
+///
+/// Panics if the base has non-ascii symbols or padding, see
+/// [`encode_bytes`](../encode/fn.encode_bytes.html). May also panic if
+/// `base` does not satisfy the `Base` invariants.
+pub fn normalize<B: Base + ?Sized>(base: &B, input: &[u8]) -> Result<String, Error> {
+    ::tool::assert_ascii(base, true);
+    let enc = enc(base);
+    let dec = dec(base);
+    let ilen = input.len();
+    let olen = try!(decode_opt_pad_len(base, ilen));
+    let mut output = String::with_capacity(::encode::encode_len(base, olen));
+    let mut block = [0u8; 7];
+    for (i, input) in input.chunks(dec).enumerate() {
+        let r = if input.len() < dec {
+            // The last block is not padded.
+            let r = olen - enc * i;
+            decode_block(base, input, &mut block[.. enc]).and_then(|x| {
+                check!(BadPadding, !base.check_trailing_bits() || trailing_zero(base, x, r));
+                Ok(r)
+            })
+        } else if dec * (i + 1) < ilen {
+            decode_block(base, input, &mut block[.. enc]).map(|_| enc)
+        } else {
+            decode_last(base, input, &mut block[.. enc])
+        };
+        let r = try!(r.map_err(|e| e.shift(dec * i)));
+        ::encode::encode_append(base, &block[.. r], &mut output);
+    }
+    Ok(output)
+}
+
 /// Decoding errors.
 #[derive(Copy,Clone,Debug,PartialEq,Eq)]
 pub enum Error {
@@ -236,8 +952,23 @@ pub enum Error {
     /// Bad padding.
     ///
     /// The non-significant bits preceding padding and left out by
-    /// decoding are non-zero.
+    /// decoding are non-zero. This error is not returned if the base
+    /// does not check trailing bits.
     BadPadding,
+
+    /// Bad checksum.
+    ///
+    /// The input is well-formed but its check symbols do not match
+    /// the decoded data. This error is only returned by encodings
+    /// with a checksum.
+    BadChecksum,
+
+    /// Bad output length.
+    ///
+    /// The output slice does not have the length expected for the
+    /// input. This error is only returned by the `try_` encoding and
+    /// decoding functions, the other functions panic instead.
+    BadOutput,
 }
 
 impl Error {
@@ -264,6 +995,8 @@ impl fmt::Display for Error {
             &BadCharacter(p) => write!(f, "Unexpected character at offset {}", p),
             &BadLength => write!(f, "Unexpected length"),
             &BadPadding => write!(f, "Non-zero padding"),
+            &BadChecksum => write!(f, "Invalid checksum"),
+            &BadOutput => write!(f, "Unexpected output length"),
         }
     }
 }
@@ -274,6 +1007,8 @@ impl error::Error for Error {
             &BadCharacter(_) => "unexpected character",
             &BadLength => "unexpected length",
             &BadPadding => "non-zero padding",
+            &BadChecksum => "invalid checksum",
+            &BadOutput => "unexpected output length",
         }
     }
 }
@@ -1,20 +1,55 @@
 //! Generic encoding module.
 
-use base::{Base, mask, enc, dec};
-use tool::{div_ceil, chunk_unchecked, chunk_mut_unchecked};
+use std::{fmt, str};
+use std::mem::MaybeUninit;
 
-fn encode_block<B: Base>(base: &B, input: &[u8], output: &mut [u8]) {
-    let mut x = 0u64; // This is enough because `base.len() <= 40`.
-    for j in 0 .. input.len() {
-        x |= (input[j] as u64) << 8 * (enc(base) - 1 - j);
+use base::{Base, BitOrder, mask, enc, dec};
+use tool::{div_ceil, chunk_unchecked, chunk_mut_unchecked, write_uninit, assume_init};
+use tool::assert_ascii;
+use decode::Error;
+use decode::Error::{BadLength, BadOutput};
+
+fn encode_block<B: Base + ?Sized>(base: &B, input: &[u8], output: &mut [u8]) {
+    let mut x = 0u64; // This is enough because `base.len() <= 56`.
+    match base.bit_order() {
+        BitOrder::MostSignificantFirst => {
+            for j in 0 .. input.len() {
+                x |= (input[j] as u64) << 8 * (enc(base) - 1 - j);
+            }
+            for j in 0 .. output.len() {
+                let y = (x >> base.bit() * (dec(base) - 1 - j)) as u8;
+                output[j] = base.sym(y & mask(base));
+            }
+        }
+        BitOrder::LeastSignificantFirst => {
+            for j in 0 .. input.len() {
+                x |= (input[j] as u64) << 8 * j;
+            }
+            for j in 0 .. output.len() {
+                let y = (x >> base.bit() * j) as u8;
+                output[j] = base.sym(y & mask(base));
+            }
+        }
     }
-    for j in 0 .. output.len() {
-        let y = (x >> base.bit() * (dec(base) - 1 - j)) as u8;
-        output[j] = base.sym(y & mask(base));
+}
+
+fn into_string(output: Vec<u8>) -> String {
+    unsafe {
+        // This is valid because symbols are checked to be ascii and
+        // separators are strings.
+        String::from_utf8_unchecked(output)
     }
 }
 
-fn encode_last<B: Base>(base: &B, input: &[u8], output: &mut [u8]) {
+fn try_alloc(len: Option<usize>) -> Result<Vec<u8>, Error> {
+    let len = try!(len.ok_or(BadLength));
+    let mut output = Vec::new();
+    try!(output.try_reserve_exact(len).map_err(|_| BadLength));
+    output.resize(len, 0);
+    Ok(output)
+}
+
+fn encode_last<B: Base + ?Sized>(base: &B, input: &[u8], output: &mut [u8]) {
     let ilen = input.len();
     let olen = div_ceil(8 * ilen, base.bit());
     encode_block(base, input, &mut output[0 .. olen]);
@@ -30,17 +65,48 @@ fn encode_last<B: Base>(base: &B, input: &[u8], output: &mut [u8]) {
 ///
 /// # Panics
 ///
-/// May panic if `base` does not satisfy the `Base` invariants.
-pub fn encode_len<B: Base>(base: &B, len: usize) -> usize {
-    div_ceil(len, enc(base)) * dec(base)
+/// Panics if the output length overflows, see
+/// [`checked_encode_len`](fn.checked_encode_len.html). May also
+/// panic if `base` does not satisfy the `Base` invariants.
+pub fn encode_len<B: Base + ?Sized>(base: &B, len: usize) -> usize {
+    checked_encode_len(base, len).expect("encoding length overflow")
 }
 
 /// Converts an input length to its output length (without padding).
 ///
 /// This function is meant to be used in conjunction with
 /// [`encode_nopad_mut`](fn.encode_nopad_mut.html).
-pub fn encode_nopad_len<B: Base>(base: &B, len: usize) -> usize {
-    div_ceil(8 * len, base.bit())
+///
+/// # Panics
+///
+/// Panics if the output length overflows, see
+/// [`checked_encode_nopad_len`](fn.checked_encode_nopad_len.html).
+pub fn encode_nopad_len<B: Base + ?Sized>(base: &B, len: usize) -> usize {
+    checked_encode_nopad_len(base, len).expect("encoding length overflow")
+}
+
+/// Converts an input length to its output length (with padding) and
+/// checks for overflow.
+///
+/// This function returns `None` if the output length does not fit in
+/// a `usize`, which may happen for large inputs on 32-bit targets.
+///
+/// # Panics
+///
+/// May panic if `base` does not satisfy the `Base` invariants.
+pub fn checked_encode_len<B: Base + ?Sized>(base: &B, len: usize) -> Option<usize> {
+    let enc = enc(base);
+    (len / enc + (len % enc != 0) as usize).checked_mul(dec(base))
+}
+
+/// Converts an input length to its output length (without padding)
+/// and checks for overflow.
+///
+/// This function returns `None` if the output length does not fit in
+/// a `usize`, which may happen for large inputs on 32-bit targets.
+pub fn checked_encode_nopad_len<B: Base + ?Sized>(base: &B, len: usize) -> Option<usize> {
+    let bit = base.bit();
+    (len / bit).checked_mul(8).and_then(|x| x.checked_add(div_ceil(8 * (len % bit), bit)))
 }
 
 /// Generic encoding function without allocation (with padding).
@@ -57,12 +123,33 @@ pub fn encode_nopad_len<B: Base>(base: &B, len: usize) -> usize {
 ///
 /// Panics if `output.len() != encode_len(input.len())`. May also
 /// panic if `base` does not satisfy the `Base` invariants.
-pub fn encode_mut<B: Base>(base: &B, input: &[u8], output: &mut [u8]) {
+pub fn encode_mut<B: Base + ?Sized>(base: &B, input: &[u8], output: &mut [u8]) {
+    try_encode_mut(base, input, output).expect("unexpected output length")
+}
+
+/// Generic encoding function without allocation (with padding) and
+/// without output length panic.
+///
+/// This function behaves like [`encode_mut`](fn.encode_mut.html) but
+/// returns `Error::BadOutput` instead of panicking if `output.len() !=
+/// encode_len(input.len())` or if this length overflows, in which case
+/// the output is untouched.
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Panics
+///
+/// May panic if `base` does not satisfy the `Base` invariants.
+pub fn try_encode_mut<B: Base + ?Sized>
+    (base: &B, input: &[u8], output: &mut [u8]) -> Result<(), Error>
+{
     let enc = enc(base);
     let dec = dec(base);
     let ilen = input.len();
-    let olen = encode_len(base, ilen);
-    assert_eq!(output.len(), olen);
+    let olen = try!(checked_encode_len(base, ilen).ok_or(BadOutput));
+    check!(BadOutput, output.len() == olen);
     let n = ilen / enc;
     for i in 0 .. n {
         let input = unsafe { chunk_unchecked(input, enc, i) };
@@ -70,6 +157,7 @@ pub fn encode_mut<B: Base>(base: &B, input: &[u8], output: &mut [u8]) {
         encode_block(base, input, output);
     }
     encode_last(base, &input[enc * n ..], &mut output[dec * n ..]);
+    Ok(())
 }
 
 /// Generic encoding function without allocation (without padding).
@@ -86,12 +174,34 @@ pub fn encode_mut<B: Base>(base: &B, input: &[u8], output: &mut [u8]) {
 ///
 /// Panics if `output.len() != encode_nopad_len(input.len())`. May
 /// also panic if `base` does not satisfy the `Base` invariants.
-pub fn encode_nopad_mut<B: Base>(base: &B, input: &[u8], output: &mut [u8]) {
+pub fn encode_nopad_mut<B: Base + ?Sized>(base: &B, input: &[u8], output: &mut [u8]) {
+    try_encode_nopad_mut(base, input, output).expect("unexpected output length")
+}
+
+/// Generic encoding function without allocation (without padding)
+/// and without output length panic.
+///
+/// This function behaves like
+/// [`encode_nopad_mut`](fn.encode_nopad_mut.html) but returns
+/// `Error::BadOutput` instead of panicking if `output.len() !=
+/// encode_nopad_len(input.len())` or if this length overflows, in
+/// which case the output is untouched.
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Panics
+///
+/// May panic if `base` does not satisfy the `Base` invariants.
+pub fn try_encode_nopad_mut<B: Base + ?Sized>
+    (base: &B, input: &[u8], output: &mut [u8]) -> Result<(), Error>
+{
     let enc = enc(base);
     let dec = dec(base);
     let ilen = input.len();
-    let olen = encode_nopad_len(base, ilen);
-    assert_eq!(output.len(), olen);
+    let olen = try!(checked_encode_nopad_len(base, ilen).ok_or(BadOutput));
+    check!(BadOutput, output.len() == olen);
     let n = ilen / enc;
     for i in 0 .. n {
         let input = unsafe { chunk_unchecked(input, enc, i) };
@@ -99,6 +209,73 @@ pub fn encode_nopad_mut<B: Base>(base: &B, input: &[u8], output: &mut [u8]) {
         encode_block(base, input, output);
     }
     encode_block(base, &input[enc * n ..], &mut output[dec * n ..]);
+    Ok(())
+}
+
+fn into_str(output: &mut [u8]) -> &mut str {
+    unsafe {
+        // This is valid because symbols are checked to be ascii.
+        str::from_utf8_unchecked_mut(output)
+    }
+}
+
+/// Generic encoding function without allocation returning a string
+/// (with padding).
+///
+/// This function is a wrapper for [`encode_mut`](fn.encode_mut.html)
+/// that returns the output as a string slice, without validating it
+/// again when the symbols are ascii.
+///
+/// # Examples
+///
+/// ```
+/// use data_encoding::{base64, encode};
+/// let mut buffer = [0u8; 8];
+/// assert_eq!(encode::encode_mut_str(base64::base(), b"foob", &mut buffer), "Zm9vYg==");
+/// ```
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Panics
+///
+/// Panics if `output.len() != encode_len(input.len())`, or if the base
+/// has non-ascii symbols or padding, see
+/// [`encode_bytes`](fn.encode_bytes.html). May also panic if `base`
+/// does not satisfy the `Base` invariants.
+pub fn encode_mut_str<'a, B: Base + ?Sized>
+    (base: &B, input: &[u8], output: &'a mut [u8]) -> &'a mut str
+{
+    assert_ascii(base, true);
+    encode_mut(base, input, output);
+    into_str(output)
+}
+
+/// Generic encoding function without allocation returning a string
+/// (without padding).
+///
+/// This function is a wrapper for
+/// [`encode_nopad_mut`](fn.encode_nopad_mut.html) that returns the
+/// output as a string slice, without validating it again when the
+/// symbols are ascii. The output is not padded.
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Panics
+///
+/// Panics if `output.len() != encode_nopad_len(input.len())`, or if
+/// the base has non-ascii symbols, see
+/// [`encode_nopad_bytes`](fn.encode_nopad_bytes.html). May also panic
+/// if `base` does not satisfy the `Base` invariants.
+pub fn encode_nopad_mut_str<'a, B: Base + ?Sized>
+    (base: &B, input: &[u8], output: &'a mut [u8]) -> &'a mut str
+{
+    assert_ascii(base, false);
+    encode_nopad_mut(base, input, output);
+    into_str(output)
 }
 
 /// Generic encoding function with allocation (with padding).
@@ -113,14 +290,13 @@ pub fn encode_nopad_mut<B: Base>(base: &B, input: &[u8], output: &mut [u8]) {
 ///
 /// # Panics
 ///
-/// May panic if `base` does not satisfy the `Base` invariants.
-pub fn encode<B: Base>(base: &B, input: &[u8]) -> String {
-    let mut output = vec![0u8; encode_len(base, input.len())];
-    encode_mut(base, input, &mut output);
-    unsafe {
-        // This is valid because values are ascii.
-        String::from_utf8_unchecked(output)
-    }
+/// Panics if the output length overflows, instead of allocating an
+/// undersized output. Also panics if the base has non-ascii symbols
+/// or padding, see [`encode_bytes`](fn.encode_bytes.html). May also
+/// panic if `base` does not satisfy the `Base` invariants.
+pub fn encode<B: Base + ?Sized>(base: &B, input: &[u8]) -> String {
+    assert_ascii(base, true);
+    into_string(encode_bytes(base, input))
 }
 
 /// Generic encoding function with allocation (without padding).
@@ -137,12 +313,588 @@ pub fn encode<B: Base>(base: &B, input: &[u8]) -> String {
 ///
 /// # Panics
 ///
-/// May panic if `base` does not satisfy the `Base` invariants.
-pub fn encode_nopad<B: Base>(base: &B, input: &[u8]) -> String {
+/// Panics if the output length overflows, instead of allocating an
+/// undersized output. Also panics if the base has non-ascii symbols,
+/// see [`encode_nopad_bytes`](fn.encode_nopad_bytes.html). May also
+/// panic if `base` does not satisfy the `Base` invariants.
+pub fn encode_nopad<B: Base + ?Sized>(base: &B, input: &[u8]) -> String {
+    assert_ascii(base, false);
+    into_string(encode_nopad_bytes(base, input))
+}
+
+/// Generic encoding function with allocation (with padding) and
+/// without length panic.
+///
+/// This function behaves like [`encode`](fn.encode.html) but returns
+/// `Error::BadLength` instead of panicking or aborting if the output
+/// length overflows or the output cannot be allocated.
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Panics
+///
+/// Panics if the base has non-ascii symbols or padding, see
+/// [`encode_bytes`](fn.encode_bytes.html). May also panic if `base`
+/// does not satisfy the `Base` invariants.
+pub fn try_encode<B: Base + ?Sized>(base: &B, input: &[u8]) -> Result<String, Error> {
+    assert_ascii(base, true);
+    let mut output = try!(try_alloc(checked_encode_len(base, input.len())));
+    encode_mut(base, input, &mut output);
+    Ok(into_string(output))
+}
+
+/// Generic encoding function with allocation (without padding) and
+/// without length panic.
+///
+/// This function behaves like [`encode_nopad`](fn.encode_nopad.html)
+/// but returns `Error::BadLength` instead of panicking or aborting if
+/// the output length overflows or the output cannot be allocated.
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Panics
+///
+/// Panics if the base has non-ascii symbols, see
+/// [`encode_nopad_bytes`](fn.encode_nopad_bytes.html). May also panic
+/// if `base` does not satisfy the `Base` invariants.
+pub fn try_encode_nopad<B: Base + ?Sized>(base: &B, input: &[u8]) -> Result<String, Error> {
+    assert_ascii(base, false);
+    let mut output = try!(try_alloc(checked_encode_nopad_len(base, input.len())));
+    encode_nopad_mut(base, input, &mut output);
+    Ok(into_string(output))
+}
+
+/// Generic encoding function with allocation to bytes (with padding).
+///
+/// This function behaves like [`encode`](fn.encode.html) but returns
+/// the output as bytes. The functions returning strings only accept
+/// bases with ascii symbols and padding, while this function accepts
+/// any base, for instance a base with 128 symbols.
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Panics
+///
+/// Panics if the output length overflows, instead of allocating an
+/// undersized output. May also panic if `base` does not satisfy the
+/// `Base` invariants.
+pub fn encode_bytes<B: Base + ?Sized>(base: &B, input: &[u8]) -> Vec<u8> {
+    let mut output = vec![0u8; encode_len(base, input.len())];
+    encode_mut(base, input, &mut output);
+    output
+}
+
+/// Generic encoding function with allocation to bytes (without
+/// padding).
+///
+/// This function behaves like [`encode_nopad`](fn.encode_nopad.html)
+/// but returns the output as bytes, for any base.
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Panics
+///
+/// Panics if the output length overflows, instead of allocating an
+/// undersized output. May also panic if `base` does not satisfy the
+/// `Base` invariants.
+pub fn encode_nopad_bytes<B: Base + ?Sized>(base: &B, input: &[u8]) -> Vec<u8> {
     let mut output = vec![0u8; encode_nopad_len(base, input.len())];
     encode_nopad_mut(base, input, &mut output);
+    output
+}
+
+/// Converts an input length to its output length (with padding) for
+/// a base of `bit` bits.
+///
+/// This function is the constant version of
+/// [`encode_len`](fn.encode_len.html) and is meant to compute the
+/// output length of [`encode_array`](fn.encode_array.html).
+///
+/// # Examples
+///
+/// ```
+/// use data_encoding::{base64, encode};
+/// const LEN: usize = encode::encode_array_len(6, 32);
+/// let output: [u8; LEN] = base64::encode_array(&[0; 32]);
+/// assert_eq!(output.len(), 44);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `bit` is not between 1 and 7 inclusive.
+pub const fn encode_array_len(bit: usize, len: usize) -> usize {
+    assert!(1 <= bit && bit <= 7);
+    let lcm = 8 * bit >> bit.trailing_zeros();
+    let enc = lcm / 8;
+    (len / enc + (len % enc != 0) as usize) * (lcm / bit)
+}
+
+/// Converts an input length to its output length (without padding)
+/// for a base of `bit` bits.
+///
+/// This function is the constant version of
+/// [`encode_nopad_len`](fn.encode_nopad_len.html) and is meant to
+/// compute the output length of
+/// [`encode_nopad_array`](fn.encode_nopad_array.html).
+///
+/// # Panics
+///
+/// Panics if `bit` is not between 1 and 7 inclusive.
+pub const fn encode_nopad_array_len(bit: usize, len: usize) -> usize {
+    assert!(1 <= bit && bit <= 7);
+    len / bit * 8 + (8 * (len % bit) + bit - 1) / bit
+}
+
+/// Generic encoding function to an array (with padding).
+///
+/// This function behaves like [`encode_mut`](fn.encode_mut.html) but
+/// encodes a fixed-size input to a fixed-size output on the stack. The
+/// length of the output is usually computed with
+/// [`encode_array_len`](fn.encode_array_len.html). The functions of
+/// the base modules check this length at compile time.
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Panics
+///
+/// Panics if `M != encode_len(N)`. May also panic if `base` does not
+/// satisfy the `Base` invariants.
+pub fn encode_array<B: Base + ?Sized, const N: usize, const M: usize>
+    (base: &B, input: &[u8; N]) -> [u8; M]
+{
+    let mut output = [0u8; M];
+    encode_mut(base, input, &mut output);
+    output
+}
+
+/// Generic encoding function to an array (without padding).
+///
+/// This function behaves like
+/// [`encode_nopad_mut`](fn.encode_nopad_mut.html) but encodes a
+/// fixed-size input to a fixed-size output on the stack. The length
+/// of the output is usually computed with
+/// [`encode_nopad_array_len`](fn.encode_nopad_array_len.html). The
+/// functions of the base modules check this length at compile time.
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Panics
+///
+/// Panics if `M != encode_nopad_len(N)`. May also panic if `base`
+/// does not satisfy the `Base` invariants.
+pub fn encode_nopad_array<B: Base + ?Sized, const N: usize, const M: usize>
+    (base: &B, input: &[u8; N]) -> [u8; M]
+{
+    let mut output = [0u8; M];
+    encode_nopad_mut(base, input, &mut output);
+    output
+}
+
+/// Generic encoding function appending to a string (with padding).
+///
+/// This function behaves like [`encode`](fn.encode.html) but appends
+/// the output to `output`, reserving its length once, instead of
+/// allocating a new string.
+///
+/// # Examples
+///
+/// ```
+/// use data_encoding::{base64, encode};
+/// let mut output = "sha256-".to_string();
+/// encode::encode_append(base64::base(), b"foo", &mut output);
+/// assert_eq!(output, "sha256-Zm9v");
+/// ```
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Panics
+///
+/// Panics if the base has non-ascii symbols or padding, see
+/// [`encode_bytes`](fn.encode_bytes.html), in which case `output` is
+/// left unchanged. May also panic if `base` does not satisfy the
+/// `Base` invariants.
+pub fn encode_append<B: Base + ?Sized>(base: &B, input: &[u8], output: &mut String) {
+    assert_ascii(base, true);
+    let len = encode_len(base, input.len());
+    let output = unsafe {
+        // This is valid because symbols are checked to be ascii.
+        output.as_mut_vec()
+    };
+    let start = output.len();
+    output.resize(start + len, 0);
+    encode_mut(base, input, &mut output[start ..]);
+}
+
+/// Generic encoding function writing to a formatter (with padding).
+///
+/// This function behaves like [`encode`](fn.encode.html) but writes
+/// the output to `output`, for instance a `fmt::Formatter` in a
+/// `Display` implementation. The input is encoded by chunks in a
+/// stack buffer and does not allocate.
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt;
+/// use data_encoding::{encode, hexlower};
+/// struct Digest([u8; 4]);
+/// impl fmt::Display for Digest {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         encode::encode_write(hexlower::base(), &self.0, f)
+///     }
+/// }
+/// assert_eq!(Digest([0xde, 0xad, 0xbe, 0xef]).to_string(), "deadbeef");
+/// ```
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Failures
+///
+/// Returns an error if writing fails.
+///
+/// # Panics
+///
+/// Panics if the base has non-ascii symbols or padding, see
+/// [`encode_bytes`](fn.encode_bytes.html). May also panic if `base`
+/// does not satisfy the `Base` invariants.
+pub fn encode_write<B: Base + ?Sized, W: fmt::Write>
+    (base: &B, input: &[u8], output: &mut W) -> fmt::Result
+{
+    assert_ascii(base, true);
+    let mut buffer = [0u8; 1024];
+    let ilen = buffer.len() / dec(base) * enc(base);
+    for chunk in input.chunks(ilen) {
+        let olen = encode_len(base, chunk.len());
+        encode_mut(base, chunk, &mut buffer[.. olen]);
+        try!(output.write_str(unsafe {
+            // This is valid because symbols are checked to be ascii.
+            str::from_utf8_unchecked(&buffer[.. olen])
+        }));
+    }
+    Ok(())
+}
+
+/// Generic encoding function extending a collection (with padding).
+///
+/// This function behaves like [`encode`](fn.encode.html) but extends
+/// `output` with the encoded bytes, for any collection of bytes like
+/// `Vec<u8>` or `VecDeque<u8>`. The input is encoded by chunks in a
+/// stack buffer and does not allocate. Strings may be extended with
+/// [`encode_append`](fn.encode_append.html).
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use data_encoding::{base64, encode};
+/// let mut output: VecDeque<u8> = VecDeque::new();
+/// encode::encode_extend(base64::base(), b"foo", &mut output);
+/// assert_eq!(output, b"Zm9v");
+/// ```
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Panics
+///
+/// May panic if `base` does not satisfy the `Base` invariants.
+pub fn encode_extend<B: Base + ?Sized, E: Extend<u8>>(base: &B, input: &[u8], output: &mut E) {
+    let mut buffer = [0u8; 1024];
+    let ilen = buffer.len() / dec(base) * enc(base);
+    for chunk in input.chunks(ilen) {
+        let olen = encode_len(base, chunk.len());
+        encode_mut(base, chunk, &mut buffer[.. olen]);
+        output.extend(buffer[.. olen].iter().cloned());
+    }
+}
+
+/// Generic encoding function to uninitialized memory (with padding).
+///
+/// This function behaves like [`encode_mut`](fn.encode_mut.html) but
+/// the output does not need to be initialized beforehand. It returns
+/// the output, which is then entirely initialized.
+///
+/// # Examples
+///
+/// ```
+/// use std::mem::MaybeUninit;
+/// use data_encoding::{base64, encode};
+/// let mut buffer = [MaybeUninit::uninit(); 8];
+/// let output = encode::encode_uninit_mut(base64::base(), b"foob", &mut buffer);
+/// assert_eq!(output, b"Zm9vYg==");
+/// ```
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Panics
+///
+/// Panics if `output.len() != encode_len(input.len())`. May also
+/// panic if `base` does not satisfy the `Base` invariants.
+pub fn encode_uninit_mut<'a, B: Base + ?Sized>
+    (base: &B, input: &[u8], output: &'a mut [MaybeUninit<u8>]) -> &'a mut [u8]
+{
+    let enc = enc(base);
+    let dec = dec(base);
+    let ilen = input.len();
+    assert_eq!(output.len(), encode_len(base, ilen));
+    let mut block = [0u8; 8];
+    let n = ilen / enc;
+    for i in 0 .. n {
+        encode_block(base, &input[enc * i .. enc * (i + 1)], &mut block[.. dec]);
+        write_uninit(&mut output[dec * i .. dec * (i + 1)], &block[.. dec]);
+    }
+    if enc * n < ilen {
+        encode_last(base, &input[enc * n ..], &mut block[.. dec]);
+        write_uninit(&mut output[dec * n ..], &block[.. dec]);
+    }
     unsafe {
-        // This is valid because values are ascii.
-        String::from_utf8_unchecked(output)
+        // This is valid because all bytes have been initialized.
+        assume_init(output)
     }
 }
+
+/// Generic encoding function in place (with padding).
+///
+/// This function behaves like [`encode_mut`](fn.encode_mut.html) but
+/// encodes the first `len` bytes of `buffer` onto the whole buffer.
+/// Blocks are encoded from the end backwards, such that the input is
+/// not overwritten before being read.
+///
+/// # Examples
+///
+/// ```
+/// use data_encoding::{base64, encode};
+/// let mut buffer = b"foob".to_vec();
+/// buffer.resize(base64::encode_len(4), 0);
+/// encode::encode_in_place(base64::base(), &mut buffer, 4);
+/// assert_eq!(buffer, b"Zm9vYg==");
+/// ```
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Panics
+///
+/// Panics if `buffer.len() != encode_len(len)`. May also panic if
+/// `base` does not satisfy the `Base` invariants.
+pub fn encode_in_place<B: Base + ?Sized>(base: &B, buffer: &mut [u8], len: usize) {
+    let enc = enc(base);
+    let dec = dec(base);
+    assert_eq!(buffer.len(), encode_len(base, len));
+    // Blocks are copied since they may overlap with their output.
+    let mut input = [0u8; 7];
+    let mut output = [0u8; 8];
+    let n = len / enc;
+    let r = len - enc * n;
+    if r > 0 {
+        input[.. r].copy_from_slice(&buffer[enc * n .. len]);
+        encode_last(base, &input[.. r], &mut output[.. dec]);
+        buffer[dec * n ..].copy_from_slice(&output[.. dec]);
+    }
+    for i in (0 .. n).rev() {
+        input[.. enc].copy_from_slice(&buffer[enc * i .. enc * (i + 1)]);
+        encode_block(base, &input[.. enc], &mut output[.. dec]);
+        buffer[dec * i .. dec * (i + 1)].copy_from_slice(&output[.. dec]);
+    }
+}
+
+/// Generic partial encoding function.
+///
+/// This function encodes as many complete blocks of the input as fit
+/// in the output, and returns the number of bytes consumed from the
+/// input and the number of bytes produced in the output. It never
+/// panics on length mismatch, which permits to encode chunk by chunk
+/// into fixed-size buffers.
+///
+/// Only complete blocks are encoded, such that no padding is
+/// produced. Once the input is complete, its remaining part, which is
+/// shorter than a block, is encoded with
+/// [`encode_mut`](fn.encode_mut.html).
+///
+/// # Examples
+///
+/// ```
+/// use data_encoding::{base64, encode};
+/// let mut output = [0u8; 6];
+/// assert_eq!(encode::encode_partial(base64::base(), b"foobar", &mut output), (3, 4));
+/// assert_eq!(&output[.. 4], b"Zm9v");
+/// ```
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Panics
+///
+/// May panic if `base` does not satisfy the `Base` invariants.
+pub fn encode_partial<B: Base + ?Sized>
+    (base: &B, input: &[u8], output: &mut [u8]) -> (usize, usize)
+{
+    let enc = enc(base);
+    let dec = dec(base);
+    let n = ::std::cmp::min(input.len() / enc, output.len() / dec);
+    for i in 0 .. n {
+        let input = unsafe { chunk_unchecked(input, enc, i) };
+        let output = unsafe { chunk_mut_unchecked(output, dec, i) };
+        encode_block(base, input, output);
+    }
+    (enc * n, dec * n)
+}
+
+/// Converts an input length to its output length (with padding and
+/// wrapping).
+///
+/// This function is meant to be used in conjunction with
+/// [`encode_wrap_mut`](fn.encode_wrap_mut.html) to size the output
+/// before encoding. Each line, including the last one, is followed by
+/// the separator.
+///
+/// # Examples
+///
+/// ```
+/// use data_encoding::{base64, encode};
+/// let input = b"Hello world";
+/// let len = encode::encode_wrap_len(base64::base(), 8, b"\r\n", input.len());
+/// assert_eq!(len, 20);
+/// let mut output = [0u8; 64];
+/// encode::encode_wrap_mut(base64::base(), 8, b"\r\n", input, &mut output[.. len]);
+/// assert_eq!(&output[.. len], b"SGVsbG8g\r\nd29ybGQ=\r\n");
+/// ```
+///
+/// # Panics
+///
+/// Panics if `width` is not a positive multiple of `dec(base)`, or if
+/// the output length overflows. May also panic if `base` does not
+/// satisfy the `Base` invariants.
+pub fn encode_wrap_len<B: Base + ?Sized>
+    (base: &B, width: usize, separator: &[u8], len: usize) -> usize
+{
+    checked_encode_wrap_len(base, width, separator, len).expect("encoding length overflow")
+}
+
+fn checked_encode_wrap_len<B: Base + ?Sized>
+    (base: &B, width: usize, separator: &[u8], len: usize) -> Option<usize>
+{
+    assert!(width > 0 && width % dec(base) == 0);
+    let ilen = width / dec(base) * enc(base);
+    let full = width.checked_add(separator.len()).and_then(|x| (len / ilen).checked_mul(x));
+    match len % ilen {
+        0 => full,
+        r => full.and_then(|x| x.checked_add(encode_len(base, r) + separator.len())),
+    }
+}
+
+/// Generic encoding function without allocation (with padding and
+/// wrapping).
+///
+/// This function behaves like [`encode_mut`](fn.encode_mut.html) but
+/// writes `separator` after each `width` symbols and after the last
+/// line, in a single pass. The width must be a multiple of the
+/// decoding length, such that only the last line may be padded.
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Panics
+///
+/// Panics if `width` is not a positive multiple of `dec(base)` or if
+/// `output.len() != encode_wrap_len(width, separator, input.len())`.
+/// May also panic if `base` does not satisfy the `Base` invariants.
+pub fn encode_wrap_mut<B: Base + ?Sized>
+    (base: &B, width: usize, separator: &[u8], input: &[u8], output: &mut [u8])
+{
+    assert_eq!(output.len(), encode_wrap_len(base, width, separator, input.len()));
+    let ilen = width / dec(base) * enc(base);
+    let mut k = 0;
+    for line in input.chunks(ilen) {
+        let n = encode_len(base, line.len());
+        encode_mut(base, line, &mut output[k .. k + n]);
+        output[k + n .. k + n + separator.len()].copy_from_slice(separator);
+        k += n + separator.len();
+    }
+}
+
+/// Generic encoding function with allocation (with padding and
+/// wrapping).
+///
+/// This function is a wrapper for
+/// [`encode_wrap_mut`](fn.encode_wrap_mut.html) that allocates an
+/// output of the correct size using
+/// [`encode_wrap_len`](fn.encode_wrap_len.html).
+///
+/// # Examples
+///
+/// ```
+/// use data_encoding::{base64, encode};
+/// let output = encode::encode_wrap(base64::base(), 8, "\r\n", b"foobarbaz!");
+/// assert_eq!(output, "Zm9vYmFy\r\nYmF6IQ==\r\n");
+/// ```
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Panics
+///
+/// Panics if `width` is not a positive multiple of `dec(base)`, or if
+/// the base has non-ascii symbols or padding, see
+/// [`encode_bytes`](fn.encode_bytes.html). May also panic if `base`
+/// does not satisfy the `Base` invariants.
+pub fn encode_wrap<B: Base + ?Sized>
+    (base: &B, width: usize, separator: &str, input: &[u8]) -> String
+{
+    assert_ascii(base, true);
+    let separator = separator.as_bytes();
+    let mut output = vec![0u8; encode_wrap_len(base, width, separator, input.len())];
+    encode_wrap_mut(base, width, separator, input, &mut output);
+    into_string(output)
+}
+
+/// Generic encoding function with allocation (with padding and
+/// wrapping) and without length panic.
+///
+/// This function behaves like [`encode_wrap`](fn.encode_wrap.html)
+/// but returns `Error::BadLength` instead of panicking or aborting if
+/// the output length overflows or the output cannot be allocated.
+///
+/// # Correctness
+///
+/// The base must satisfy the `Base` invariants.
+///
+/// # Panics
+///
+/// Panics if `width` is not a positive multiple of `dec(base)`, or if
+/// the base has non-ascii symbols or padding, see
+/// [`encode_bytes`](fn.encode_bytes.html). May also panic if `base`
+/// does not satisfy the `Base` invariants.
+pub fn try_encode_wrap<B: Base + ?Sized>
+    (base: &B, width: usize, separator: &str, input: &[u8]) -> Result<String, Error>
+{
+    assert_ascii(base, true);
+    let separator = separator.as_bytes();
+    let len = checked_encode_wrap_len(base, width, separator, input.len());
+    let mut output = try!(try_alloc(len));
+    encode_wrap_mut(base, width, separator, input, &mut output);
+    Ok(into_string(output))
+}
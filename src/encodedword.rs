@@ -0,0 +1,239 @@
+//! MIME Encoded-Words.
+//!
+//! An encoded-word represents text of a given charset in a header
+//! field: `=?charset?B?text?=` where the text is encoded with
+//! [`base64`](../base64/index.html), or `=?charset?Q?text?=` where
+//! the text is encoded like
+//! [`quotedprintable`](../quotedprintable/index.html) with restricted
+//! literals and `_` for the space. Encoded-words are at most 75
+//! characters long: longer texts are folded in multiple encoded-words
+//! separated by `\r\n `.
+//!
+//! This module does not convert between charsets: encoding takes and
+//! decoding returns bytes in the named charset. If the charset is
+//! UTF-8, encoding does not split characters across encoded-words,
+//! except malformed ones which do not fit in an encoded-word.
+//!
+//! # Conformance
+//!
+//! [RFC 2047](https://tools.ietf.org/html/rfc2047) compliant.
+
+use decode::Error;
+use decode::Error::*;
+use tool::{hex_val, push_escape};
+
+/// The maximum length of an encoded-word.
+pub const MAX_LEN: usize = 75;
+
+/// Text encodings.
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+pub enum Variant {
+    /// The `B` encoding, based on base64.
+    B,
+
+    /// The `Q` encoding, based on quoted-printable.
+    Q,
+}
+use self::Variant::*;
+
+// Returns whether a byte is a literal in the Q encoding.
+fn literal(x: u8) -> bool {
+    x.is_ascii_alphanumeric() || b"!*+-/".contains(&x)
+}
+
+fn encoded_len(variant: Variant, input: &[u8]) -> usize {
+    match variant {
+        B => ::base64::encode_len(input.len()),
+        Q => input.iter().map(|&x| if literal(x) || x == b' ' { 1 } else { 3 }).sum(),
+    }
+}
+
+fn encode_word(charset: &str, variant: Variant, input: &[u8], output: &mut Vec<u8>) {
+    output.extend_from_slice(b"=?");
+    output.extend_from_slice(charset.as_bytes());
+    output.extend_from_slice(if variant == B { b"?B?" } else { b"?Q?" });
+    match variant {
+        B => output.extend_from_slice(::base64::encode(input).as_bytes()),
+        Q => for &x in input {
+            match x {
+                b' ' => output.push(b'_'),
+                x if literal(x) => output.push(x),
+                x => push_escape(output, b'=', x),
+            }
+        },
+    }
+    output.extend_from_slice(b"?=");
+}
+
+/// Encodes with allocation.
+///
+/// # Panics
+///
+/// Panics if `charset` is empty, is longer than 56 characters, or
+/// contains characters outside the range 33 to 126 or `?`.
+pub fn encode(charset: &str, variant: Variant, input: &[u8]) -> String {
+    assert!(!charset.is_empty() && charset.len() <= 56);
+    assert!(charset.bytes().all(|x| 33 <= x && x <= 126 && x != b'?'));
+    let utf8 = charset.eq_ignore_ascii_case("utf-8");
+    let room = MAX_LEN - charset.len() - 7;
+    let mut output = Vec::with_capacity(MAX_LEN);
+    let mut start = 0;
+    loop {
+        // Extends the encoded-word one character at a time.
+        let mut end = start;
+        let mut next = start;
+        while next < input.len() {
+            next += 1;
+            while utf8 && next < input.len() && input[next] & 0xc0 == 0x80 {
+                next += 1;
+            }
+            if encoded_len(variant, &input[start .. next]) > room {
+                break;
+            }
+            end = next;
+        }
+        if end == start && start < input.len() {
+            // A malformed character longer than a word is split.
+            end = start + 1;
+            while end < input.len() && encoded_len(variant, &input[start .. end + 1]) <= room {
+                end += 1;
+            }
+        }
+        encode_word(charset, variant, &input[start .. end], &mut output);
+        if end == input.len() {
+            break;
+        }
+        output.extend_from_slice(b"\r\n ");
+        start = end;
+    }
+    unsafe {
+        // This is valid because the charset and symbols are ascii.
+        String::from_utf8_unchecked(output)
+    }
+}
+
+/// Decodes an encoded-word with allocation.
+///
+/// This function returns the charset and the text. Decoding accepts
+/// lowercase encodings and lowercase hexadecimal digits.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadLength` if the input is not delimited by `=?` and
+/// `?=` or has no encoding.
+/// - `Error::BadCharacter` if the encoding is neither `B` nor `Q`, or
+/// if the text is invalid.
+/// - Any error of [`base64::decode`](../base64/fn.decode.html) if the
+/// text is invalid base64.
+pub fn decode_word(input: &[u8]) -> Result<(String, Vec<u8>), Error> {
+    let n = input.len();
+    check!(BadLength, n >= 4 && input.starts_with(b"=?") && input.ends_with(b"?="));
+    let i = try!(input[2 .. n - 2].iter().position(|&x| x == b'?').ok_or(BadLength)) + 2;
+    check!(BadLength, i + 3 <= n - 2 && input[i + 2] == b'?');
+    let text = &input[i + 3 .. n - 2];
+    let output = match input[i + 1] {
+        b'B' | b'b' => try!(::base64::decode(text).map_err(|e| e.shift(i + 3))),
+        b'Q' | b'q' => {
+            let mut output = Vec::with_capacity(text.len());
+            let mut j = 0;
+            while j < text.len() {
+                let x = match text[j] {
+                    b'_' => b' ',
+                    b'=' => {
+                        let h = text.get(j + 1).and_then(|&x| hex_val(x, true));
+                        let l = text.get(j + 2).and_then(|&x| hex_val(x, true));
+                        let (h, l) = match (h, l) {
+                            (Some(h), Some(l)) => (h, l),
+                            _ => return Err(BadCharacter(i + 3 + j)),
+                        };
+                        j += 2;
+                        h << 4 | l
+                    }
+                    x => {
+                        check!(BadCharacter(i + 3 + j), 33 <= x && x <= 126 && x != b'?');
+                        x
+                    }
+                };
+                output.push(x);
+                j += 1;
+            }
+            output
+        }
+        _ => return Err(BadCharacter(i + 1)),
+    };
+    let charset = try!(String::from_utf8(input[2 .. i].to_vec())
+                       .map_err(|e| BadCharacter(2 + e.utf8_error().valid_up_to())));
+    Ok((charset, output))
+}
+
+// Returns whether a byte is whitespace in a header field.
+fn is_space(x: u8) -> bool {
+    x == b' ' || x == b'\t' || x == b'\r' || x == b'\n'
+}
+
+// Appends unfolded whitespace to the plain text parts.
+fn push_space(output: &mut Vec<(Option<String>, Vec<u8>)>, space: &[u8]) {
+    let space = space.iter().cloned().filter(|&x| x != b'\r' && x != b'\n');
+    match output.last_mut() {
+        Some(&mut (None, ref mut last)) => return last.extend(space),
+        _ => (),
+    }
+    output.push((None, space.collect()));
+}
+
+/// Decodes a header field with allocation.
+///
+/// This function returns the parts of the field. Each part is either
+/// text of a charset, from consecutive encoded-words, or plain text
+/// without charset. The field is unfolded and whitespace between
+/// encoded-words is removed.
+///
+/// Words delimited by `=?` and `?=` are decoded as encoded-words.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`decode_word`](fn.decode_word.html).
+pub fn decode(input: &[u8]) -> Result<Vec<(Option<String>, Vec<u8>)>, Error> {
+    let mut output: Vec<(Option<String>, Vec<u8>)> = Vec::new();
+    let mut space: &[u8] = &[];
+    let mut i = 0;
+    while i < input.len() {
+        let start = i;
+        let white = is_space(input[i]);
+        while i < input.len() && is_space(input[i]) == white {
+            i += 1;
+        }
+        let token = &input[start .. i];
+        if white {
+            space = token;
+            continue;
+        }
+        let word = token.len() >= 4 && token.starts_with(b"=?") && token.ends_with(b"?=");
+        let (charset, text) = if word {
+            let (charset, text) = try!(decode_word(token).map_err(|e| e.shift(start)));
+            (Some(charset), text)
+        } else {
+            (None, token.to_vec())
+        };
+        let joined = word && output.last().map_or(false, |x| x.0.is_some());
+        if !joined && !space.is_empty() {
+            push_space(&mut output, space);
+        }
+        space = &[];
+        match output.last_mut() {
+            Some(&mut (ref last_charset, ref mut last)) if *last_charset == charset => {
+                last.extend_from_slice(&text);
+                continue;
+            }
+            _ => (),
+        }
+        output.push((charset, text));
+    }
+    if !space.is_empty() {
+        push_space(&mut output, space);
+    }
+    Ok(output)
+}
@@ -0,0 +1,169 @@
+//! Extension traits module.
+//!
+//! This module defines the [`EncodeExt`](trait.EncodeExt.html) trait,
+//! providing the common encodings as methods on byte slices, and the
+//! [`DecodeExt`](trait.DecodeExt.html) trait, providing the
+//! corresponding decodings as methods on strings. The traits need to
+//! be imported explicitly: they are opt-in.
+//!
+//! # Examples
+//!
+//! ```
+//! use data_encoding::ext::{DecodeExt, EncodeExt};
+//! assert_eq!(b"\x01\xab".to_hex(), "01ab");
+//! assert_eq!(vec![0x66, 0x6f].to_base64(), "Zm8=");
+//! assert_eq!(b"fo".to_base32_nopad(), "MZXQ");
+//! assert_eq!("01ab".from_hex().unwrap(), b"\x01\xab");
+//! assert_eq!("Zm8=".from_base64().unwrap(), b"fo");
+//! ```
+
+use decode::Error;
+
+/// Encoding methods on byte slices.
+///
+/// Each method is a shortcut for the `encode` or `encode_nopad`
+/// function of the module of the same name.
+pub trait EncodeExt {
+    /// Encodes with [`hexlower`](../hexlower/index.html).
+    fn to_hex(&self) -> String;
+
+    /// Encodes with [`base16`](../base16/index.html).
+    fn to_base16(&self) -> String;
+
+    /// Encodes with [`base32`](../base32/index.html).
+    fn to_base32(&self) -> String;
+
+    /// Encodes with [`base32`](../base32/index.html) without padding.
+    fn to_base32_nopad(&self) -> String;
+
+    /// Encodes with [`base32hex`](../base32hex/index.html).
+    fn to_base32hex(&self) -> String;
+
+    /// Encodes with [`base64`](../base64/index.html).
+    fn to_base64(&self) -> String;
+
+    /// Encodes with [`base64`](../base64/index.html) without padding.
+    fn to_base64_nopad(&self) -> String;
+
+    /// Encodes with [`base64url`](../base64url/index.html).
+    fn to_base64url(&self) -> String;
+
+    /// Encodes with [`base64url`](../base64url/index.html) without
+    /// padding.
+    fn to_base64url_nopad(&self) -> String;
+}
+
+impl EncodeExt for [u8] {
+    fn to_hex(&self) -> String {
+        ::hexlower::encode(self)
+    }
+
+    fn to_base16(&self) -> String {
+        ::base16::encode(self)
+    }
+
+    fn to_base32(&self) -> String {
+        ::base32::encode(self)
+    }
+
+    fn to_base32_nopad(&self) -> String {
+        ::base32::encode_nopad(self)
+    }
+
+    fn to_base32hex(&self) -> String {
+        ::base32hex::encode(self)
+    }
+
+    fn to_base64(&self) -> String {
+        ::base64::encode(self)
+    }
+
+    fn to_base64_nopad(&self) -> String {
+        ::base64::encode_nopad(self)
+    }
+
+    fn to_base64url(&self) -> String {
+        ::base64url::encode(self)
+    }
+
+    fn to_base64url_nopad(&self) -> String {
+        ::base64url::encode_nopad(self)
+    }
+}
+
+/// Decoding methods on strings.
+///
+/// Each method is a shortcut for the `decode` or `decode_nopad`
+/// function of the module of the same name.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`Error`](../decode/enum.Error.html).
+pub trait DecodeExt {
+    /// Decodes with [`hexlower`](../hexlower/index.html).
+    fn from_hex(&self) -> Result<Vec<u8>, Error>;
+
+    /// Decodes with [`base16`](../base16/index.html).
+    fn from_base16(&self) -> Result<Vec<u8>, Error>;
+
+    /// Decodes with [`base32`](../base32/index.html).
+    fn from_base32(&self) -> Result<Vec<u8>, Error>;
+
+    /// Decodes with [`base32`](../base32/index.html) without padding.
+    fn from_base32_nopad(&self) -> Result<Vec<u8>, Error>;
+
+    /// Decodes with [`base32hex`](../base32hex/index.html).
+    fn from_base32hex(&self) -> Result<Vec<u8>, Error>;
+
+    /// Decodes with [`base64`](../base64/index.html).
+    fn from_base64(&self) -> Result<Vec<u8>, Error>;
+
+    /// Decodes with [`base64`](../base64/index.html) without padding.
+    fn from_base64_nopad(&self) -> Result<Vec<u8>, Error>;
+
+    /// Decodes with [`base64url`](../base64url/index.html).
+    fn from_base64url(&self) -> Result<Vec<u8>, Error>;
+
+    /// Decodes with [`base64url`](../base64url/index.html) without
+    /// padding.
+    fn from_base64url_nopad(&self) -> Result<Vec<u8>, Error>;
+}
+
+impl DecodeExt for str {
+    fn from_hex(&self) -> Result<Vec<u8>, Error> {
+        ::hexlower::decode(self.as_bytes())
+    }
+
+    fn from_base16(&self) -> Result<Vec<u8>, Error> {
+        ::base16::decode(self.as_bytes())
+    }
+
+    fn from_base32(&self) -> Result<Vec<u8>, Error> {
+        ::base32::decode(self.as_bytes())
+    }
+
+    fn from_base32_nopad(&self) -> Result<Vec<u8>, Error> {
+        ::base32::decode_nopad(self.as_bytes())
+    }
+
+    fn from_base32hex(&self) -> Result<Vec<u8>, Error> {
+        ::base32hex::decode(self.as_bytes())
+    }
+
+    fn from_base64(&self) -> Result<Vec<u8>, Error> {
+        ::base64::decode(self.as_bytes())
+    }
+
+    fn from_base64_nopad(&self) -> Result<Vec<u8>, Error> {
+        ::base64::decode_nopad(self.as_bytes())
+    }
+
+    fn from_base64url(&self) -> Result<Vec<u8>, Error> {
+        ::base64url::decode(self.as_bytes())
+    }
+
+    fn from_base64url_nopad(&self) -> Result<Vec<u8>, Error> {
+        ::base64url::decode_nopad(self.as_bytes())
+    }
+}
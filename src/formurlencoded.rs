@@ -0,0 +1,94 @@
+//! application/x-www-form-urlencoded Encoding.
+//!
+//! This is [`percent`](../percent/index.html) encoding with the
+//! [`FORM`](../percent/constant.FORM.html) set, except that spaces
+//! are encoded as `+`. Forms are lists of name-value pairs: names are
+//! separated from values by `=` and pairs are separated by `&`.
+//!
+//! Decoding never fails: a `%` not followed by 2 hexadecimal digits
+//! represents itself.
+//!
+//! # Conformance
+//!
+//! [URL Standard](https://url.spec.whatwg.org/#application/x-www-form-urlencoded)
+//! compliant, without charset conversion: names and values are bytes.
+
+use percent::FORM;
+use tool::{hex_val, push_escape};
+
+fn encode_into(input: &[u8], output: &mut Vec<u8>) {
+    for &x in input {
+        match x {
+            b' ' => output.push(b'+'),
+            x if FORM.contains(x) => push_escape(output, b'%', x),
+            x => output.push(x),
+        }
+    }
+}
+
+/// Encodes with allocation.
+pub fn encode(input: &[u8]) -> String {
+    let mut output = Vec::with_capacity(input.len());
+    encode_into(input, &mut output);
+    unsafe {
+        // This is valid because non-ascii bytes are encoded.
+        String::from_utf8_unchecked(output)
+    }
+}
+
+/// Decodes with allocation.
+pub fn decode(input: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(input.len());
+    let mut i = 0;
+    while i < input.len() {
+        let x = match input[i] {
+            b'+' => b' ',
+            b'%' => {
+                let h = input.get(i + 1).and_then(|&x| hex_val(x, true));
+                let l = input.get(i + 2).and_then(|&x| hex_val(x, true));
+                match (h, l) {
+                    (Some(h), Some(l)) => { i += 2; h << 4 | l }
+                    _ => b'%',
+                }
+            }
+            x => x,
+        };
+        output.push(x);
+        i += 1;
+    }
+    output
+}
+
+/// Encodes name-value pairs with allocation.
+pub fn encode_pairs<K: AsRef<[u8]>, V: AsRef<[u8]>>(pairs: &[(K, V)]) -> String {
+    let mut output = Vec::new();
+    for (i, &(ref name, ref value)) in pairs.iter().enumerate() {
+        if i > 0 {
+            output.push(b'&');
+        }
+        encode_into(name.as_ref(), &mut output);
+        output.push(b'=');
+        encode_into(value.as_ref(), &mut output);
+    }
+    unsafe {
+        // This is valid because non-ascii bytes are encoded.
+        String::from_utf8_unchecked(output)
+    }
+}
+
+/// Decodes name-value pairs with allocation.
+///
+/// Empty pairs are ignored. A pair without `=` has an empty value.
+pub fn decode_pairs(input: &[u8]) -> Vec<(Vec<u8>, Vec<u8>)> {
+    let mut output = Vec::new();
+    for pair in input.split(|&x| x == b'&') {
+        if pair.is_empty() {
+            continue;
+        }
+        let n = pair.iter().position(|&x| x == b'=').unwrap_or(pair.len());
+        let name = decode(&pair[.. n]);
+        let value = if n < pair.len() { decode(&pair[n + 1 ..]) } else { Vec::new() };
+        output.push((name, value));
+    }
+    output
+}
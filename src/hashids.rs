@@ -0,0 +1,255 @@
+//! Hashids Encoding.
+//!
+//! A sequence of integers is encoded as a short string over an
+//! alphabet shuffled with a salt. The first symbol is a lottery symbol
+//! derived from the integers, which reshuffles the alphabet for each
+//! integer. Integers are separated by separator symbols and the output
+//! may be padded to a minimum length with guard symbols and alphabet
+//! symbols.
+//!
+//! This is meant to hide consecutive identifiers, like database
+//! keys, and is not a form of encryption. Decoding only accepts the
+//! output of encoding with the same parameters.
+//!
+//! # Conformance
+//!
+//! [Hashids](https://hashids.org/) compliant for ascii salts.
+
+use std::{error, fmt};
+
+use decode::Error;
+use decode::Error::*;
+
+/// The default alphabet.
+pub const ALPHABET: &'static str =
+    "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ1234567890";
+
+/// The separators, when they are part of the alphabet.
+pub const SEPS: &'static str = "cfhistuCFHISTU";
+
+/// The minimum number of symbols of an alphabet.
+pub const MIN_ALPHABET: usize = 16;
+
+/// Alphabet errors.
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+pub enum BuildError {
+    /// The alphabet has less than 16 symbols.
+    Short,
+
+    /// The alphabet contains a non-ascii symbol.
+    NotAscii(u8),
+
+    /// The alphabet contains a control character.
+    NotPrintable(u8),
+
+    /// The alphabet contains a space.
+    Space,
+
+    /// The alphabet contains a symbol twice.
+    Duplicate(u8),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::BuildError::*;
+        match self {
+            &Short => write!(f, "Alphabet has less than 16 symbols."),
+            &NotAscii(s) => write!(f, "Symbol {:?} is not ascii.", s as char),
+            &NotPrintable(s) => write!(f, "Symbol {:?} is not printable.", s as char),
+            &Space => write!(f, "Alphabet contains a space."),
+            &Duplicate(s) => write!(f, "Symbol {:?} is duplicated.", s as char),
+        }
+    }
+}
+
+impl error::Error for BuildError {
+    fn description(&self) -> &str {
+        use self::BuildError::*;
+        match self {
+            &Short => "alphabet must have at least 16 symbols",
+            &NotAscii(_) => "symbols must be ascii",
+            &NotPrintable(_) => "symbols must be printable",
+            &Space => "alphabet must not contain spaces",
+            &Duplicate(_) => "symbols must be unique",
+        }
+    }
+}
+
+// Shuffles the alphabet deterministically from the salt.
+fn shuffle(alphabet: &mut [u8], salt: &[u8]) {
+    if salt.is_empty() {
+        return;
+    }
+    let mut p = 0;
+    for (v, i) in (1 .. alphabet.len()).rev().enumerate() {
+        let v = v % salt.len();
+        let x = salt[v] as usize;
+        p += x;
+        alphabet.swap(i, (x + v + p) % i);
+    }
+}
+
+// Shuffles the alphabet for the next integer.
+fn reshuffle(alphabet: &mut Vec<u8>, lottery: u8, salt: &[u8]) {
+    let mut buffer = vec![lottery];
+    buffer.extend_from_slice(salt);
+    buffer.extend_from_slice(alphabet);
+    buffer.truncate(alphabet.len());
+    shuffle(alphabet, &buffer);
+}
+
+/// Encoding parameters.
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub struct Hashids {
+    salt: Vec<u8>,
+    min_len: usize,
+    alphabet: Vec<u8>,
+    seps: Vec<u8>,
+    guards: Vec<u8>,
+}
+
+impl Hashids {
+    /// Creates encoding parameters.
+    ///
+    /// The alphabet is usually [`ALPHABET`](constant.ALPHABET.html).
+    /// The output of encoding is padded to `min_len` symbols.
+    ///
+    /// # Failures
+    ///
+    /// Returns an error if the alphabet is not valid, as described by
+    /// [`BuildError`](enum.BuildError.html).
+    pub fn new(salt: &[u8], min_len: usize, alphabet: &[u8]) -> Result<Hashids, BuildError> {
+        let mut alphabet = alphabet.to_vec();
+        for (i, &x) in alphabet.iter().enumerate() {
+            check!(BuildError::NotAscii(x), x < 128);
+            check!(BuildError::NotPrintable(x), x >= b' ' && x != 127);
+            check!(BuildError::Space, x != b' ');
+            check!(BuildError::Duplicate(x), !alphabet[.. i].contains(&x));
+        }
+        check!(BuildError::Short, alphabet.len() >= MIN_ALPHABET);
+        let mut seps: Vec<u8> = SEPS.bytes().filter(|x| alphabet.contains(x)).collect();
+        alphabet.retain(|x| !seps.contains(x));
+        shuffle(&mut seps, salt);
+        // The ratio of the alphabet length to the separators length is
+        // at most 3.5.
+        if seps.is_empty() || 2 * alphabet.len() > 7 * seps.len() {
+            let n = ::std::cmp::max(2, (2 * alphabet.len() + 6) / 7);
+            if n > seps.len() {
+                let d = n - seps.len();
+                seps.extend(alphabet.drain(.. d));
+            } else {
+                seps.truncate(n);
+            }
+        }
+        shuffle(&mut alphabet, salt);
+        let n = (alphabet.len() + 11) / 12;
+        let guards = if alphabet.len() < 3 {
+            seps.drain(.. n).collect()
+        } else {
+            alphabet.drain(.. n).collect()
+        };
+        Ok(Hashids {
+            salt: salt.to_vec(), min_len: min_len,
+            alphabet: alphabet, seps: seps, guards: guards,
+        })
+    }
+
+    /// Encodes with allocation.
+    ///
+    /// The empty sequence is encoded as the empty string.
+    pub fn encode(&self, input: &[u64]) -> String {
+        if input.is_empty() {
+            return String::new();
+        }
+        let mut alphabet = self.alphabet.clone();
+        let n = alphabet.len() as u64;
+        let id = input.iter().enumerate().fold(0, |s, (i, &x)| s + x % (i as u64 + 100));
+        let lottery = alphabet[(id % n) as usize];
+        let mut output = vec![lottery];
+        for (i, &x) in input.iter().enumerate() {
+            reshuffle(&mut alphabet, lottery, &self.salt);
+            let start = output.len();
+            let mut y = x;
+            loop {
+                output.insert(start, alphabet[(y % n) as usize]);
+                y /= n;
+                if y == 0 {
+                    break;
+                }
+            }
+            if i + 1 < input.len() {
+                let y = x % (output[start] as u64 + i as u64);
+                output.push(self.seps[(y % self.seps.len() as u64) as usize]);
+            }
+        }
+        let g = self.guards.len() as u64;
+        if output.len() < self.min_len {
+            let guard = self.guards[((id + output[0] as u64) % g) as usize];
+            output.insert(0, guard);
+        }
+        if output.len() < self.min_len {
+            let guard = self.guards[((id + output[2] as u64) % g) as usize];
+            output.push(guard);
+        }
+        let half = alphabet.len() / 2;
+        while output.len() < self.min_len {
+            let salt = alphabet.clone();
+            shuffle(&mut alphabet, &salt);
+            let mut padded = alphabet[half ..].to_vec();
+            padded.extend_from_slice(&output);
+            padded.extend_from_slice(&alphabet[.. half]);
+            output = padded;
+            if output.len() > self.min_len {
+                let excess = output.len() - self.min_len;
+                output = output[excess / 2 .. excess / 2 + self.min_len].to_vec();
+            }
+        }
+        unsafe {
+            // This is valid because the alphabet is ascii.
+            String::from_utf8_unchecked(output)
+        }
+    }
+
+    /// Decodes with allocation.
+    ///
+    /// # Failures
+    ///
+    /// Decoding may fail in the following circumstances:
+    ///
+    /// - `Error::BadCharacter` if an integer contains a non-symbol or
+    /// overflows.
+    /// - `Error::BadLength` if the lottery symbol is missing.
+    /// - `Error::BadChecksum` if the input is not the encoding of the
+    /// decoded integers.
+    pub fn decode(&self, input: &[u8]) -> Result<Vec<u64>, Error> {
+        if input.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut parts = Vec::new();
+        let mut pos = 0;
+        for part in input.split(|x| self.guards.contains(x)) {
+            parts.push((pos, part));
+            pos += part.len() + 1;
+        }
+        let (start, part) = parts[if parts.len() == 2 || parts.len() == 3 { 1 } else { 0 }];
+        check!(BadLength, !part.is_empty());
+        let lottery = part[0];
+        let mut alphabet = self.alphabet.clone();
+        let n = alphabet.len() as u64;
+        let mut output = Vec::new();
+        let mut pos = start + 1;
+        for sub in part[1 ..].split(|x| self.seps.contains(x)) {
+            reshuffle(&mut alphabet, lottery, &self.salt);
+            let mut x = 0u64;
+            for (i, &y) in sub.iter().enumerate() {
+                let v = try!(alphabet.iter().position(|&a| a == y).ok_or(BadCharacter(pos + i)));
+                x = try!(x.checked_mul(n).and_then(|x| x.checked_add(v as u64))
+                         .ok_or(BadCharacter(pos + i)));
+            }
+            output.push(x);
+            pos += sub.len() + 1;
+        }
+        check!(BadChecksum, self.encode(&output).as_bytes() == input);
+        Ok(output)
+    }
+}
@@ -0,0 +1,258 @@
+//! Intel HEX Encoding.
+//!
+//! A file is a sequence of records, one per line. A record is `:`
+//! followed by uppercase hexadecimal digits encoding its data length,
+//! a 16-bit offset, its type, its data, and a checksum. The checksum
+//! is the two's complement of the sum of the other bytes. The
+//! supported record types are described in the
+//! [`Record`](enum.Record.html) enum.
+//!
+//! The [`records`](fn.records.html) function iterates over the records
+//! of a file. The [`encode`](fn.encode.html) and
+//! [`decode`](fn.decode.html) functions convert between a memory image
+//! and a file, handling the extended address records.
+//!
+//! Decoding accepts lowercase hexadecimal digits, `\n` and `\r\n`
+//! line terminators, and empty lines.
+//!
+//! # Conformance
+//!
+//! [Intel HEX](https://en.wikipedia.org/wiki/Intel_HEX) compliant for
+//! the record types 00 to 05. Encoding uses `\n` line terminators.
+
+use decode::Error;
+use decode::Error::*;
+use lines::lines;
+use tool::hex_val;
+
+/// The length of data records produced by [`encode`](fn.encode.html).
+pub const LINE: usize = 16;
+
+/// Records.
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub enum Record {
+    /// Data at an offset from the base address (type 00).
+    Data {
+        /// The offset.
+        offset: u16,
+
+        /// The data, at most 255 bytes.
+        data: Vec<u8>,
+    },
+
+    /// End of file (type 01).
+    EndOfFile,
+
+    /// Sets the base address to the segment times 16 (type 02).
+    ExtendedSegmentAddress(u16),
+
+    /// Start address as a code segment and an instruction pointer
+    /// (type 03).
+    StartSegmentAddress {
+        /// The code segment.
+        cs: u16,
+
+        /// The instruction pointer.
+        ip: u16,
+    },
+
+    /// Sets the upper 16 bits of the base address (type 04).
+    ExtendedLinearAddress(u16),
+
+    /// Start address as a 32-bit linear address (type 05).
+    StartLinearAddress(u32),
+}
+
+fn be16(x: &[u8]) -> u16 {
+    (x[0] as u16) << 8 | x[1] as u16
+}
+
+/// Encodes a record without line terminator.
+///
+/// # Panics
+///
+/// Panics if a data record has more than 255 bytes.
+pub fn encode_record(record: &Record) -> String {
+    let (kind, offset, data) = match *record {
+        Record::Data { offset, ref data } => (0, offset, data.clone()),
+        Record::EndOfFile => (1, 0, vec![]),
+        Record::ExtendedSegmentAddress(x) => (2, 0, vec![(x >> 8) as u8, x as u8]),
+        Record::StartSegmentAddress { cs, ip } =>
+            (3, 0, vec![(cs >> 8) as u8, cs as u8, (ip >> 8) as u8, ip as u8]),
+        Record::ExtendedLinearAddress(x) => (4, 0, vec![(x >> 8) as u8, x as u8]),
+        Record::StartLinearAddress(x) =>
+            (5, 0, vec![(x >> 24) as u8, (x >> 16) as u8, (x >> 8) as u8, x as u8]),
+    };
+    assert!(data.len() <= 255);
+    let mut bytes = vec![data.len() as u8, (offset >> 8) as u8, offset as u8, kind];
+    bytes.extend_from_slice(&data);
+    let sum = bytes.iter().fold(0u8, |s, &x| s.wrapping_add(x));
+    bytes.push(sum.wrapping_neg());
+    format!(":{}", ::base16::encode(&bytes))
+}
+
+/// Decodes a record without line terminator.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadCharacter` if the record does not start with `:`,
+/// contains a non-hexadecimal digit, or has an unknown type. The
+/// position of an unknown type is the one of its first digit.
+/// - `Error::BadLength` if the record is too short, does not match its
+/// data length, or has a data length invalid for its type.
+/// - `Error::BadChecksum` if the checksum does not match.
+pub fn decode_record(input: &[u8]) -> Result<Record, Error> {
+    check!(BadCharacter(0), input.first() == Some(&b':'));
+    check!(BadLength, input.len() % 2 == 1 && input.len() >= 11);
+    let mut bytes = Vec::with_capacity(input.len() / 2);
+    for i in 0 .. input.len() / 2 {
+        let j = 1 + 2 * i;
+        let h = try!(hex_val(input[j], true).ok_or(BadCharacter(j)));
+        let l = try!(hex_val(input[j + 1], true).ok_or(BadCharacter(j + 1)));
+        bytes.push(h << 4 | l);
+    }
+    let n = bytes.len();
+    check!(BadLength, bytes[0] as usize + 5 == n);
+    check!(BadChecksum, bytes.iter().fold(0u8, |s, &x| s.wrapping_add(x)) == 0);
+    let offset = be16(&bytes[1 ..]);
+    let data = &bytes[4 .. n - 1];
+    let len = match bytes[3] {
+        0 => return Ok(Record::Data { offset: offset, data: data.to_vec() }),
+        1 => 0,
+        2 | 4 => 2,
+        3 | 5 => 4,
+        _ => return Err(BadCharacter(7)),
+    };
+    check!(BadLength, data.len() == len);
+    Ok(match bytes[3] {
+        1 => Record::EndOfFile,
+        2 => Record::ExtendedSegmentAddress(be16(data)),
+        3 => Record::StartSegmentAddress { cs: be16(data), ip: be16(&data[2 ..]) },
+        4 => Record::ExtendedLinearAddress(be16(data)),
+        _ => Record::StartLinearAddress((be16(data) as u32) << 16 | be16(&data[2 ..]) as u32),
+    })
+}
+
+/// Iterator over the records of a file.
+///
+/// This iterator is returned by the [`records`](fn.records.html)
+/// function.
+pub struct Records<'a> {
+    lines: ::std::vec::IntoIter<(usize, &'a [u8])>,
+}
+
+impl<'a> Iterator for Records<'a> {
+    type Item = Result<Record, Error>;
+
+    fn next(&mut self) -> Option<Result<Record, Error>> {
+        for (pos, line) in self.lines.by_ref() {
+            if !line.is_empty() {
+                return Some(decode_record(line).map_err(|e| e.shift(pos)));
+            }
+        }
+        None
+    }
+}
+
+/// Iterates over the records of a file.
+///
+/// Empty lines are skipped. Errors are those of
+/// [`decode_record`](fn.decode_record.html), with positions in the
+/// file.
+pub fn records(input: &[u8]) -> Records<'_> {
+    Records { lines: lines(input).into_iter() }
+}
+
+/// Converts a memory image to records.
+///
+/// The data is placed at the given address, in data records of 16
+/// bytes which do not cross a 64KiB boundary. Extended linear address
+/// records are inserted when needed. The last record is an end of
+/// file record.
+///
+/// # Panics
+///
+/// Panics if the data does not fit below 4GiB.
+pub fn to_records(address: u32, data: &[u8]) -> Vec<Record> {
+    assert!(address as u64 + data.len() as u64 <= 1 << 32);
+    let mut output = Vec::new();
+    let mut upper = 0;
+    let mut i = 0;
+    while i < data.len() {
+        let a = address as u64 + i as u64;
+        if (a >> 16) as u16 != upper {
+            upper = (a >> 16) as u16;
+            output.push(Record::ExtendedLinearAddress(upper));
+        }
+        let n = ::std::cmp::min(LINE as u64, 0x10000 - (a & 0xffff)) as usize;
+        let n = ::std::cmp::min(n, data.len() - i);
+        output.push(Record::Data { offset: a as u16, data: data[i .. i + n].to_vec() });
+        i += n;
+    }
+    output.push(Record::EndOfFile);
+    output
+}
+
+/// Encodes a memory image with allocation.
+///
+/// The records are those of [`to_records`](fn.to_records.html), each
+/// followed by `\n`.
+///
+/// # Panics
+///
+/// Panics if the data does not fit below 4GiB.
+pub fn encode(address: u32, data: &[u8]) -> String {
+    let mut output = String::new();
+    for record in to_records(address, data) {
+        output.push_str(&encode_record(&record));
+        output.push('\n');
+    }
+    output
+}
+
+/// Decodes a memory image with allocation.
+///
+/// This function returns the address and data of each contiguous
+/// segment, in file order. Start address records are ignored.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`records`](fn.records.html). It also returns
+/// `Error::BadLength` if there is no end of file record and
+/// `Error::BadCharacter` if a record follows it.
+pub fn decode(input: &[u8]) -> Result<Vec<(u32, Vec<u8>)>, Error> {
+    let mut output: Vec<(u32, Vec<u8>)> = Vec::new();
+    let mut base = 0u32;
+    let mut lines = lines(input).into_iter();
+    loop {
+        let (pos, line) = try!(lines.next().ok_or(BadLength));
+        if line.is_empty() {
+            continue;
+        }
+        match try!(decode_record(line).map_err(|e| e.shift(pos))) {
+            Record::Data { offset, data } => {
+                let address = base.wrapping_add(offset as u32);
+                match output.last_mut() {
+                    Some(&mut (start, ref mut last))
+                        if start.wrapping_add(last.len() as u32) == address => {
+                        last.extend_from_slice(&data);
+                        continue;
+                    }
+                    _ => (),
+                }
+                output.push((address, data));
+            }
+            Record::EndOfFile => break,
+            Record::ExtendedSegmentAddress(x) => base = (x as u32) << 4,
+            Record::ExtendedLinearAddress(x) => base = (x as u32) << 16,
+            _ => (),
+        }
+    }
+    for (pos, line) in lines {
+        check!(BadCharacter(pos), line.is_empty());
+    }
+    Ok(output)
+}
@@ -0,0 +1,141 @@
+//! IMAP Modified UTF-7 Encoding.
+//!
+//! Printable ascii characters, except `&`, represent themselves and
+//! `&` is encoded as `&-`. Other characters are encoded in UTF-16 and
+//! the resulting bytes are encoded with base64 without padding, using
+//! `,` instead of `/`, between `&` and `-`.
+//!
+//! Decoding rejects non-canonical inputs: printable ascii characters
+//! must represent themselves, consecutive encoded characters must be
+//! encoded together, and the last symbol of an encoded sequence must
+//! have zero padding bits.
+//!
+//! # Conformance
+//!
+//! [RFC 3501](https://tools.ietf.org/html/rfc3501#section-5.1.3)
+//! compliant.
+
+use base::{BitOrder, Opt};
+use decode::Error;
+use decode::Error::*;
+
+const X_: u8 = 128;
+
+/// Force static dispatch.
+pub enum Static {}
+
+static BASE: Opt<Static> = Opt {
+    val: ascii!(
+        X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, 62, 63, X_, X_, X_,
+        52, 53, 54, 55, 56, 57, 58, 59, 60, 61, X_, X_, X_, X_, X_, X_,
+        X_, 0_, 1_, 2_, 3_, 4_, 5_, 6_, 7_, 8_, 9_, 10, 11, 12, 13, 14,
+        15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, X_, X_, X_, X_, X_,
+        X_, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40,
+        41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, X_, X_, X_, X_, X_),
+    sym: b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+,",
+    bit: 6, pad: b'=', order: BitOrder::MostSignificantFirst,
+    _phantom: ::std::marker::PhantomData
+};
+
+/// Gives access to the base.
+///
+/// This base is used without padding.
+pub fn base() -> &'static Opt<Static> {
+    &BASE
+}
+
+// Returns whether a character represents itself.
+fn direct(c: char) -> bool {
+    ' ' <= c && c <= '~'
+}
+
+fn flush(units: &mut Vec<u8>, output: &mut String) {
+    if units.is_empty() {
+        return;
+    }
+    output.push('&');
+    output.push_str(&::encode::encode_nopad(&BASE, units));
+    output.push('-');
+    units.clear();
+}
+
+/// Encodes with allocation.
+pub fn encode(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut units = Vec::new();
+    for c in input.chars() {
+        if direct(c) {
+            flush(&mut units, &mut output);
+            output.push(c);
+            if c == '&' {
+                output.push('-');
+            }
+            continue;
+        }
+        let mut buf = [0u16; 2];
+        for &u in c.encode_utf16(&mut buf).iter() {
+            units.extend_from_slice(&[(u >> 8) as u8, u as u8]);
+        }
+    }
+    flush(&mut units, &mut output);
+    output
+}
+
+/// Decodes with allocation.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadCharacter` if the input contains a character which is
+/// not printable ascii, or if an encoded sequence contains a
+/// non-symbol, does not encode valid UTF-16, encodes a printable
+/// ascii character, or directly follows another encoded sequence. The
+/// position of an invalid encoded sequence is the one of its `&`.
+/// - `Error::BadLength` if an encoded sequence is not terminated or
+/// does not encode an even number of bytes.
+/// - `Error::BadPadding` if the last symbol of an encoded sequence has
+/// non-zero padding bits.
+pub fn decode(input: &[u8]) -> Result<String, Error> {
+    let mut output = String::with_capacity(input.len());
+    let mut i = 0;
+    let mut encoded = false;
+    while i < input.len() {
+        let x = input[i];
+        check!(BadCharacter(i), b' ' <= x && x <= b'~');
+        if x != b'&' {
+            output.push(x as char);
+            encoded = false;
+            i += 1;
+            continue;
+        }
+        let n = try!(input[i + 1 ..].iter().position(|&x| x == b'-').ok_or(BadLength));
+        let symbols = &input[i + 1 .. i + 1 + n];
+        if symbols.is_empty() {
+            output.push('&');
+            encoded = false;
+            i += 2;
+            continue;
+        }
+        check!(BadCharacter(i), !encoded);
+        let bytes = try!(::decode::decode_nopad(&BASE, symbols).map_err(|e| e.shift(i + 1)));
+        check!(BadLength, bytes.len() % 2 == 0);
+        let units: Vec<u16> = bytes.chunks(2).map(|x| (x[0] as u16) << 8 | x[1] as u16).collect();
+        for c in ::std::char::decode_utf16(units) {
+            let c = try!(c.map_err(|_| BadCharacter(i)));
+            check!(BadCharacter(i), !direct(c));
+            output.push(c);
+        }
+        encoded = true;
+        i += n + 2;
+    }
+    Ok(output)
+}
+
+#[test]
+fn check() {
+    use base::valid;
+    assert_eq!(BASE.val.len(), 256);
+    assert_eq!(BASE.sym.len(), 1 << BASE.bit);
+    valid(&BASE).unwrap();
+}
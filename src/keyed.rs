@@ -0,0 +1,97 @@
+//! Keyed permutation module.
+//!
+//! This module defines a base implementation, namely
+//! [`Keyed`](struct.Keyed.html), whose symbols are the symbols of
+//! another base in an order derived from a key. The resulting base
+//! works with all the generic functions of the
+//! [`encode`](../encode/index.html) and
+//! [`decode`](../decode/index.html) modules.
+//!
+//! The permutation is a Fisher-Yates shuffle driven by a SplitMix64
+//! generator, seeded with the first 8 bytes of the SHA-256 of the
+//! key. It only depends on the key and the original base.
+//!
+//! This is meant to make encoded tokens less recognizable, and is not
+//! a form of encryption: the permutation can be recovered from a few
+//! known inputs and their encoding.
+
+use base::{Base, BitOrder};
+use checksum::sha256;
+
+// SplitMix64 generator.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ z >> 30).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ z >> 27).wrapping_mul(0x94d049bb133111eb);
+        z ^ z >> 31
+    }
+}
+
+/// Keyed implementation.
+///
+/// This implementation uses arrays for constant-time lookup, like
+/// [`Opt`](../base/struct.Opt.html), but owns them.
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub struct Keyed {
+    val: Vec<u8>,
+    sym: Vec<u8>,
+    bit: u8,
+    pad: u8,
+    order: BitOrder,
+}
+
+impl Keyed {
+    /// Permutes the symbols of a base with a key.
+    ///
+    /// The padding and bit order are those of the original base.
+    ///
+    /// # Panics
+    ///
+    /// May panic if `base` does not satisfy the `Base` invariants.
+    pub fn new<B: Base + ?Sized>(base: &B, key: &[u8]) -> Keyed {
+        let bit = base.bit();
+        let mut sym: Vec<u8> = (0 .. 1 << bit).map(|v| base.sym(v as u8)).collect();
+        let hash = sha256(key);
+        let seed = hash[.. 8].iter().fold(0u64, |s, &x| s << 8 | x as u64);
+        let mut rng = Rng(seed);
+        for i in (1 .. sym.len()).rev() {
+            let j = (rng.next() % (i as u64 + 1)) as usize;
+            sym.swap(i, j);
+        }
+        let mut val = vec![128u8; 256];
+        for (v, &s) in sym.iter().enumerate() {
+            val[s as usize] = v as u8;
+        }
+        Keyed {
+            val: val, sym: sym, bit: bit as u8,
+            pad: base.pad(), order: base.bit_order(),
+        }
+    }
+}
+
+impl Base for Keyed {
+    fn bit(&self) -> usize {
+        self.bit as usize
+    }
+
+    fn pad(&self) -> u8 {
+        self.pad
+    }
+
+    fn val(&self, x: u8) -> Option<u8> {
+        let v = self.val[x as usize];
+        if v < 128 { Some(v) } else { None }
+    }
+
+    fn sym(&self, x: u8) -> u8 {
+        self.sym[x as usize]
+    }
+
+    fn bit_order(&self) -> BitOrder {
+        self.order
+    }
+}
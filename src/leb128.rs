@@ -0,0 +1,139 @@
+//! LEB128 Encoding.
+//!
+//! An integer is encoded in groups of 7 bits, least significant first.
+//! Each group is stored in a byte whose most significant bit is set if
+//! more groups follow. Unsigned integers stop when the remaining bits
+//! are zero. Signed integers stop when the remaining bits are the sign
+//! extension of the bit 6 of the last group.
+//!
+//! Decoding rejects overlong encodings, namely encodings with
+//! redundant trailing groups, and encodings of integers which do not
+//! fit in 64 bits. Since an encoded integer is usually followed by
+//! other data, decoding functions also return the number of bytes
+//! read.
+//!
+//! # Conformance
+//!
+//! [DWARF](http://dwarfstd.org/doc/DWARF5.pdf) compliant. The
+//! unsigned encoding is also the [Protocol Buffers
+//! varint](https://developers.google.com/protocol-buffers/docs/encoding#varints),
+//! negative `int64` fields being encoded as `x as u64`.
+
+use decode::Error;
+use decode::Error::*;
+
+/// The maximum length of an encoded integer.
+pub const MAX_LEN: usize = 10;
+
+/// Returns the length of an encoded unsigned integer.
+pub fn encode_len(mut x: u64) -> usize {
+    let mut n = 1;
+    while x >= 0x80 {
+        x >>= 7;
+        n += 1;
+    }
+    n
+}
+
+/// Encodes an unsigned integer without allocation.
+///
+/// # Panics
+///
+/// Panics if `output.len() != encode_len(x)`.
+pub fn encode_mut(mut x: u64, output: &mut [u8]) {
+    assert_eq!(output.len(), encode_len(x));
+    let n = output.len();
+    for (i, y) in output.iter_mut().enumerate() {
+        *y = (x & 0x7f) as u8 | if i + 1 < n { 0x80 } else { 0 };
+        x >>= 7;
+    }
+}
+
+/// Encodes an unsigned integer with allocation.
+pub fn encode(x: u64) -> Vec<u8> {
+    let mut output = vec![0u8; encode_len(x)];
+    encode_mut(x, &mut output);
+    output
+}
+
+/// Decodes an unsigned integer from the start of the input.
+///
+/// This function returns the integer and the number of bytes read.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadCharacter` if a byte is redundant or makes the integer
+/// overflow.
+/// - `Error::BadLength` if the input ends before the last byte.
+pub fn decode(input: &[u8]) -> Result<(u64, usize), Error> {
+    let mut x = 0u64;
+    for (i, &y) in input.iter().enumerate() {
+        check!(BadCharacter(i), i < MAX_LEN - 1 || y <= 1);
+        x |= ((y & 0x7f) as u64) << 7 * i;
+        if y & 0x80 == 0 {
+            check!(BadCharacter(i), i == 0 || y != 0);
+            return Ok((x, i + 1));
+        }
+    }
+    Err(BadLength)
+}
+
+/// Returns the length of an encoded signed integer.
+pub fn encode_signed_len(mut x: i64) -> usize {
+    let mut n = 1;
+    while x < -0x40 || x >= 0x40 {
+        x >>= 7;
+        n += 1;
+    }
+    n
+}
+
+/// Encodes a signed integer without allocation.
+///
+/// # Panics
+///
+/// Panics if `output.len() != encode_signed_len(x)`.
+pub fn encode_signed_mut(mut x: i64, output: &mut [u8]) {
+    assert_eq!(output.len(), encode_signed_len(x));
+    let n = output.len();
+    for (i, y) in output.iter_mut().enumerate() {
+        *y = (x & 0x7f) as u8 | if i + 1 < n { 0x80 } else { 0 };
+        x >>= 7;
+    }
+}
+
+/// Encodes a signed integer with allocation.
+pub fn encode_signed(x: i64) -> Vec<u8> {
+    let mut output = vec![0u8; encode_signed_len(x)];
+    encode_signed_mut(x, &mut output);
+    output
+}
+
+/// Decodes a signed integer from the start of the input.
+///
+/// This function returns the integer and the number of bytes read.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`decode`](fn.decode.html).
+pub fn decode_signed(input: &[u8]) -> Result<(i64, usize), Error> {
+    let mut x = 0i64;
+    for (i, &y) in input.iter().enumerate() {
+        check!(BadCharacter(i), i < MAX_LEN - 1 || y == 0 || y == 0x7f);
+        x |= ((y & 0x7f) as i64) << 7 * i;
+        if y & 0x80 == 0 {
+            if i > 0 {
+                let sign = input[i - 1] & 0x40 != 0;
+                check!(BadCharacter(i), y != if sign { 0x7f } else { 0 });
+            }
+            if y & 0x40 != 0 && i < MAX_LEN - 1 {
+                x |= !0 << 7 * (i + 1);
+            }
+            return Ok((x, i + 1));
+        }
+    }
+    Err(BadLength)
+}
@@ -6,7 +6,19 @@
 //! generic functions using a base interface described in module
 //! [`base`](base/index.html). The generic encoding and decoding
 //! functions are defined in the [`encode`](encode/index.html) and
-//! [`decode`](decode/index.html) modules respectively.
+//! [`decode`](decode/index.html) modules respectively. Bases whose
+//! radix is not a power of two, like [`base36`](base36/index.html),
+//! are instantiated from the generic functions of the
+//! [`radix`](radix/index.html) module. Bases whose symbols are not
+//! ascii, like Base65536, are described by the
+//! [`unicode`](unicode/index.html) module. Bases defined at runtime,
+//! for instance from a configuration file, are described by the
+//! [`specification`](specification/index.html) module and may be
+//! looked up by name in the [`registry`](registry/index.html) module.
+//! The [`prelude`](prelude/index.html) module re-exports the common
+//! encodings and the [`ext`](ext/index.html) extension traits. Short
+//! values may be encoded without allocation with the
+//! [`buffer`](buffer/index.html) module.
 //!
 //! # Examples
 //!
@@ -99,9 +111,18 @@
 
 #[macro_use]
 mod tool;
+mod checksum;
+mod lines;
 pub mod base;
 pub mod encode;
 pub mod decode;
+pub mod radix;
+pub mod unicode;
+pub mod wordlist;
+pub mod keyed;
+pub mod specification;
+pub mod registry;
+pub mod buffer;
 
 // Rust is missing functors: I use macros.
 
@@ -121,8 +142,8 @@ macro_rules! ascii {
         ] };
 }
 macro_rules! base {
-    (#[$d: meta] $(#[$a: meta])* mod $n: ident;
-     $b: expr, $p: expr, $r: expr, $s: expr, $($v: expr),*,) =>
+    (@imp #[$d: meta] $(#[$a: meta])* mod $n: ident, $dec: ident, { $($x: item)* };
+     $b: expr, $p: expr, $o: ident, $r: expr, $s: expr, $($v: expr),*,) =>
     {
         #[$d]
         ///
@@ -139,12 +160,35 @@ macro_rules! base {
             pub enum Static {}
             static BASE: ::base::Opt<Static> = ::base::Opt {
                 val: ascii!($($v),*), sym: $s, bit: $b, pad: $p,
-                _phantom: ::std::marker::PhantomData
+                order: ::base::BitOrder::$o, _phantom: ::std::marker::PhantomData
             };
+            $($x)*
             /// Gives access to the base.
             pub fn base() -> &'static ::base::Opt<Static> {
                 &BASE
             }
+            // Checks array lengths at compile time.
+            struct Array<const N: usize, const M: usize>;
+            impl<const N: usize, const M: usize> Array<N, M> {
+                const PAD: () = assert!(M == ::encode::encode_array_len($b, N));
+                const NOPAD: () = assert!(M == ::encode::encode_nopad_array_len($b, N));
+            }
+            /// See the generic [`encode_array`](../encode/fn.encode_array.html) function for details.
+            ///
+            /// The output length is checked at compile time.
+            pub fn encode_array<const N: usize, const M: usize>(input: &[u8; N]) -> [u8; M] {
+                let () = Array::<N, M>::PAD;
+                ::encode::encode_array(&BASE, input)
+            }
+            /// See the generic
+            /// [`encode_nopad_array`](../encode/fn.encode_nopad_array.html)
+            /// function for details.
+            ///
+            /// The output length is checked at compile time.
+            pub fn encode_nopad_array<const N: usize, const M: usize>(input: &[u8; N]) -> [u8; M] {
+                let () = Array::<N, M>::NOPAD;
+                ::encode::encode_nopad_array(&BASE, input)
+            }
             /// See the generic [`encode_len`](../encode/fn.encode_len.html) function for details.
             pub fn encode_len(len: usize) -> usize {
                 ::encode::encode_len(&BASE, len)
@@ -165,6 +209,12 @@ macro_rules! base {
             pub fn decode_nopad_len(len: usize) -> Result<usize, Error> {
                 ::decode::decode_nopad_len(&BASE, len)
             }
+            /// See the generic
+            /// [`decode_exact_len`](../decode/fn.decode_exact_len.html)
+            /// function for details.
+            pub fn decode_exact_len(input: &[u8]) -> Result<usize, Error> {
+                ::decode::decode_exact_len(&BASE, input)
+            }
             /// See the generic [`encode_mut`](../encode/fn.encode_mut.html) function for details.
             pub fn encode_mut(input: &[u8], output: &mut [u8]) {
                 ::encode::encode_mut(&BASE, input, output)
@@ -175,15 +225,65 @@ macro_rules! base {
             pub fn encode_nopad_mut(input: &[u8], output: &mut [u8]) {
                 ::encode::encode_nopad_mut(&BASE, input, output)
             }
+            /// See the generic
+            /// [`try_encode_mut`](../encode/fn.try_encode_mut.html)
+            /// function for details.
+            pub fn try_encode_mut(input: &[u8], output: &mut [u8])
+                                  -> Result<(), Error> {
+                ::encode::try_encode_mut(&BASE, input, output)
+            }
+            /// See the generic
+            /// [`try_encode_nopad_mut`](../encode/fn.try_encode_nopad_mut.html)
+            /// function for details.
+            pub fn try_encode_nopad_mut(input: &[u8], output: &mut [u8])
+                                        -> Result<(), Error> {
+                ::encode::try_encode_nopad_mut(&BASE, input, output)
+            }
             /// See the generic [`decode_mut`](../decode/fn.decode_mut.html) function for details.
             pub fn decode_mut(input: &[u8], output: &mut [u8]) -> Result<usize, Error> {
-                ::decode::decode_mut(&BASE, input, output)
+                ::decode::decode_mut(&$dec, input, output)
             }
             /// See the generic
             /// [`decode_nopad_mut`](../decode/fn.decode_nopad_mut.html)
             /// function for details.
             pub fn decode_nopad_mut(input: &[u8], output: &mut [u8]) -> Result<(), Error> {
-                ::decode::decode_nopad_mut(&BASE, input, output)
+                ::decode::decode_nopad_mut(&$dec, input, output)
+            }
+            /// See the generic [`normalize`](../decode/fn.normalize.html) function for details.
+            pub fn normalize(input: &[u8]) -> Result<String, Error> {
+                ::decode::normalize(&$dec, input)
+            }
+            /// See the generic [`is_valid`](../decode/fn.is_valid.html) function for details.
+            pub fn is_valid(input: &[u8]) -> bool {
+                ::decode::is_valid(&$dec, input)
+            }
+            /// See the generic [`is_canonical`](../decode/fn.is_canonical.html) function for details.
+            pub fn is_canonical(input: &[u8]) -> bool {
+                ::decode::is_canonical(&$dec, input)
+            }
+            /// See the generic
+            /// [`try_decode_mut`](../decode/fn.try_decode_mut.html)
+            /// function for details.
+            pub fn try_decode_mut(input: &[u8], output: &mut [u8]) -> Result<usize, Error> {
+                ::decode::try_decode_mut(&$dec, input, output)
+            }
+            /// See the generic
+            /// [`try_decode_nopad_mut`](../decode/fn.try_decode_nopad_mut.html)
+            /// function for details.
+            pub fn try_decode_nopad_mut(input: &[u8], output: &mut [u8]) -> Result<(), Error> {
+                ::decode::try_decode_nopad_mut(&$dec, input, output)
+            }
+            /// See the generic
+            /// [`encode_mut_str`](../encode/fn.encode_mut_str.html)
+            /// function for details.
+            pub fn encode_mut_str<'a>(input: &[u8], output: &'a mut [u8]) -> &'a mut str {
+                ::encode::encode_mut_str(&BASE, input, output)
+            }
+            /// See the generic
+            /// [`encode_nopad_mut_str`](../encode/fn.encode_nopad_mut_str.html)
+            /// function for details.
+            pub fn encode_nopad_mut_str<'a>(input: &[u8], output: &'a mut [u8]) -> &'a mut str {
+                ::encode::encode_nopad_mut_str(&BASE, input, output)
             }
             /// See the generic [`encode`](../encode/fn.encode.html) function for details.
             pub fn encode(input: &[u8]) -> String {
@@ -195,28 +295,309 @@ macro_rules! base {
             pub fn encode_nopad(input: &[u8]) -> String {
                 ::encode::encode_nopad(&BASE, input)
             }
+            /// See the generic [`try_encode`](../encode/fn.try_encode.html) function for details.
+            pub fn try_encode(input: &[u8]) -> Result<String, Error> {
+                ::encode::try_encode(&BASE, input)
+            }
+            /// See the generic
+            /// [`try_encode_nopad`](../encode/fn.try_encode_nopad.html)
+            /// function for details.
+            pub fn try_encode_nopad(input: &[u8]) -> Result<String, Error> {
+                ::encode::try_encode_nopad(&BASE, input)
+            }
+            /// See the generic
+            /// [`encode_append`](../encode/fn.encode_append.html)
+            /// function for details.
+            pub fn encode_append(input: &[u8], output: &mut String) {
+                ::encode::encode_append(&BASE, input, output)
+            }
+            /// See the generic
+            /// [`encode_write`](../encode/fn.encode_write.html)
+            /// function for details.
+            pub fn encode_write<W: ::std::fmt::Write>(input: &[u8], output: &mut W)
+                                                     -> ::std::fmt::Result {
+                ::encode::encode_write(&BASE, input, output)
+            }
+            /// See the generic
+            /// [`encode_extend`](../encode/fn.encode_extend.html)
+            /// function for details.
+            pub fn encode_extend<E: Extend<u8>>(input: &[u8], output: &mut E) {
+                ::encode::encode_extend(&BASE, input, output)
+            }
+            /// See the generic
+            /// [`encode_uninit_mut`](../encode/fn.encode_uninit_mut.html)
+            /// function for details.
+            pub fn encode_uninit_mut<'a>(input: &[u8], output: &'a mut [::std::mem::MaybeUninit<u8>])
+                                         -> &'a mut [u8] {
+                ::encode::encode_uninit_mut(&BASE, input, output)
+            }
+            /// See the generic
+            /// [`encode_partial`](../encode/fn.encode_partial.html)
+            /// function for details.
+            pub fn encode_partial(input: &[u8], output: &mut [u8]) -> (usize, usize) {
+                ::encode::encode_partial(&BASE, input, output)
+            }
+            /// See the generic
+            /// [`encode_in_place`](../encode/fn.encode_in_place.html)
+            /// function for details.
+            pub fn encode_in_place(buffer: &mut [u8], len: usize) {
+                ::encode::encode_in_place(&BASE, buffer, len)
+            }
+            /// See the generic
+            /// [`encode_wrap_len`](../encode/fn.encode_wrap_len.html)
+            /// function for details.
+            pub fn encode_wrap_len(width: usize, separator: &[u8], len: usize) -> usize {
+                ::encode::encode_wrap_len(&BASE, width, separator, len)
+            }
+            /// See the generic
+            /// [`encode_wrap_mut`](../encode/fn.encode_wrap_mut.html)
+            /// function for details.
+            pub fn encode_wrap_mut(width: usize, separator: &[u8], input: &[u8], output: &mut [u8]) {
+                ::encode::encode_wrap_mut(&BASE, width, separator, input, output)
+            }
+            /// See the generic
+            /// [`encode_wrap`](../encode/fn.encode_wrap.html)
+            /// function for details.
+            pub fn encode_wrap(width: usize, separator: &str, input: &[u8]) -> String {
+                ::encode::encode_wrap(&BASE, width, separator, input)
+            }
             /// See the generic [`decode`](../decode/fn.decode.html) function for details.
             pub fn decode(input: &[u8]) -> Result<Vec<u8>, Error> {
-                ::decode::decode(&BASE, input)
+                ::decode::decode(&$dec, input)
             }
             /// See the generic
             /// [`decode_nopad`](../decode/fn.decode_nopad.html)
             /// function for details.
             pub fn decode_nopad(input: &[u8]) -> Result<Vec<u8>, Error> {
-                ::decode::decode_nopad(&BASE, input)
+                ::decode::decode_nopad(&$dec, input)
+            }
+            /// See the generic
+            /// [`decode_append`](../decode/fn.decode_append.html)
+            /// function for details.
+            pub fn decode_append(input: &[u8], output: &mut Vec<u8>) -> Result<usize, Error> {
+                ::decode::decode_append(&$dec, input, output)
+            }
+            /// See the generic
+            /// [`decode_uninit_mut`](../decode/fn.decode_uninit_mut.html)
+            /// function for details.
+            pub fn decode_uninit_mut<'a>(input: &[u8], output: &'a mut [::std::mem::MaybeUninit<u8>])
+                                         -> Result<&'a mut [u8], Error> {
+                ::decode::decode_uninit_mut(&$dec, input, output)
+            }
+            /// See the generic [`decode_iter`](../decode/fn.decode_iter.html) function for details.
+            pub fn decode_iter<I: IntoIterator<Item = u8>>(input: I) -> Result<Vec<u8>, Error> {
+                ::decode::decode_iter(&$dec, input)
+            }
+            /// See the generic
+            /// [`decode_partial`](../decode/fn.decode_partial.html)
+            /// function for details.
+            pub fn decode_partial(input: &[u8], output: &mut [u8])
+                                  -> Result<(usize, usize), Error> {
+                ::decode::decode_partial(&$dec, input, output)
+            }
+            /// See the generic
+            /// [`decode_in_place`](../decode/fn.decode_in_place.html)
+            /// function for details.
+            pub fn decode_in_place(buffer: &mut [u8]) -> Result<usize, Error> {
+                ::decode::decode_in_place(&$dec, buffer)
+            }
+            /// See the generic
+            /// [`decode_opt_pad_len`](../decode/fn.decode_opt_pad_len.html)
+            /// function for details.
+            pub fn decode_opt_pad_len(len: usize) -> Result<usize, Error> {
+                ::decode::decode_opt_pad_len(&BASE, len)
+            }
+            /// See the generic
+            /// [`decode_opt_pad_mut`](../decode/fn.decode_opt_pad_mut.html)
+            /// function for details.
+            pub fn decode_opt_pad_mut(input: &[u8], output: &mut [u8]) -> Result<usize, Error> {
+                ::decode::decode_opt_pad_mut(&$dec, input, output)
+            }
+            /// See the generic
+            /// [`decode_opt_pad`](../decode/fn.decode_opt_pad.html)
+            /// function for details.
+            pub fn decode_opt_pad(input: &[u8]) -> Result<Vec<u8>, Error> {
+                ::decode::decode_opt_pad(&$dec, input)
+            }
+            /// See the generic
+            /// [`decode_ignore`](../decode/fn.decode_ignore.html)
+            /// function for details.
+            pub fn decode_ignore(ignore: &[u8], input: &[u8]) -> Result<Vec<u8>, Error> {
+                ::decode::decode_ignore(&$dec, ignore, input)
             }
             #[test]
             fn check() {
-                use base::{Spec, equal, valid};
+                use base::{Base, Spec, equal, valid};
                 const SPEC: Spec = Spec { val: $r, pad: $p };
                 assert_eq!(BASE.val.len(), 256);
                 assert_eq!(BASE.sym.len(), 1 << BASE.bit);
                 valid(&SPEC).unwrap();
                 valid(&BASE).unwrap();
                 equal(&BASE, &SPEC).unwrap();
+                for s in 0..128u8 {
+                    if let Some(v) = BASE.val(s) {
+                        assert_eq!($dec.val(s), Some(v));
+                        assert_eq!($dec.sym(v), s);
+                    }
+                }
+            }
+        }
+    };
+    (#[$d: meta] $(#[$a: meta])* mod $n: ident;
+     $b: expr, $p: expr, $o: ident, $r: expr, $s: expr, $($v: expr),*,) =>
+    {
+        base!{
+            @imp #[$d] $(#[$a])* mod $n, BASE, {};
+            $b, $p, $o, $r, $s, $($v),*,
+        }
+    };
+    (#[$d: meta] $(#[$a: meta])* mod $n: ident, { $($x: item)* };
+     $b: expr, $p: expr, $r: expr, $s: expr, $($v: expr),*,; $($w: expr),*,) =>
+    {
+        base!{
+            @imp #[$d] $(#[$a])* mod $n, DECODE, {
+                // Same as `BASE` but with the aliases accepted by decoding.
+                static DECODE: ::base::Opt<Static> = ::base::Opt {
+                    val: ascii!($($w),*), sym: $s, bit: $b, pad: $p,
+                    order: ::base::BitOrder::MostSignificantFirst,
+                    _phantom: ::std::marker::PhantomData
+                };
+                $($x)*
+            };
+            $b, $p, MostSignificantFirst, $r, $s, $($v),*,
+        }
+    };
+    (#[$d: meta] $(#[$a: meta])* mod $n: ident;
+     $b: expr, $p: expr, $r: expr, $s: expr, $($v: expr),*,; $($w: expr),*,) =>
+    {
+        base!{
+            #[$d] $(#[$a])* mod $n, {};
+            $b, $p, $r, $s, $($v),*,; $($w),*,
+        }
+    };
+    (#[$d: meta] $(#[$a: meta])* mod $n: ident;
+     $b: expr, $p: expr, $r: expr, $s: expr, $($v: expr),*,) =>
+    {
+        base!{
+            @imp #[$d] $(#[$a])* mod $n, BASE, {};
+            $b, $p, MostSignificantFirst, $r, $s, $($v),*,
+        }
+    };
+}
+
+macro_rules! radix {
+    (@imp #[$d: meta] $(#[$a: meta])* mod $n: ident, { $($x: item)* };
+     $s: expr, $($v: expr),*,) =>
+    {
+        #[$d]
+        ///
+        /// See the generic [`radix`](../radix/index.html) module for
+        /// details about this module functions.
+        ///
+        /// # Definition
+        $(#[$a])*
+        pub mod $n {
+            use ::decode::Error;
+            const X_: u8 = 128;
+            /// Force static dispatch.
+            pub enum Static {}
+            static RADIX: ::radix::Opt<Static> = ::radix::Opt {
+                val: ascii!($($v),*), sym: $s, _phantom: ::std::marker::PhantomData
+            };
+            /// Gives access to the radix.
+            pub fn radix() -> &'static ::radix::Opt<Static> {
+                &RADIX
+            }
+            $($x)*
+            /// See the generic [`encode_len`](../radix/fn.encode_len.html) function for details.
+            pub fn encode_len(len: usize) -> usize {
+                ::radix::encode_len(&RADIX, len)
+            }
+            /// See the generic [`decode_len`](../radix/fn.decode_len.html) function for details.
+            pub fn decode_len(len: usize) -> usize {
+                ::radix::decode_len(&RADIX, len)
+            }
+            /// See the generic [`encode_mut`](../radix/fn.encode_mut.html) function for details.
+            pub fn encode_mut(input: &[u8], output: &mut [u8]) -> usize {
+                ::radix::encode_mut(&RADIX, input, output)
+            }
+            /// See the generic [`decode_mut`](../radix/fn.decode_mut.html) function for details.
+            pub fn decode_mut(input: &[u8], output: &mut [u8]) -> Result<usize, Error> {
+                ::radix::decode_mut(&RADIX, input, output)
+            }
+            /// See the generic [`encode`](../radix/fn.encode.html) function for details.
+            pub fn encode(input: &[u8]) -> String {
+                ::radix::encode(&RADIX, input)
+            }
+            /// See the generic [`decode`](../radix/fn.decode.html) function for details.
+            pub fn decode(input: &[u8]) -> Result<Vec<u8>, Error> {
+                ::radix::decode(&RADIX, input)
+            }
+            #[test]
+            fn check() {
+                use radix::Radix;
+                assert_eq!(RADIX.val.len(), 256);
+                assert!(2 <= RADIX.radix() && RADIX.radix() <= 128);
+                let mut card = 0;
+                for s in 0..256usize {
+                    if let Some(v) = RADIX.val(s as u8) {
+                        assert!(s < 128);
+                        assert_eq!(RADIX.sym(v) as usize, s);
+                        card += 1;
+                    }
+                }
+                assert_eq!(card, RADIX.radix());
             }
         }
     };
+    (#[$d: meta] $(#[$a: meta])* mod $n: ident; $c: expr; $s: expr, $($v: expr),*,) => {
+        radix!{
+            @imp #[$d] $(#[$a])* mod $n, {
+                /// The chunk size of the chunked functions.
+                pub const CHUNK: usize = $c;
+                /// See the generic
+                /// [`encode_chunked_len`](../radix/fn.encode_chunked_len.html)
+                /// function for details.
+                pub fn encode_chunked_len(len: usize) -> usize {
+                    ::radix::encode_chunked_len(&RADIX, CHUNK, len)
+                }
+                /// See the generic
+                /// [`decode_chunked_len`](../radix/fn.decode_chunked_len.html)
+                /// function for details.
+                pub fn decode_chunked_len(len: usize) -> Result<usize, Error> {
+                    ::radix::decode_chunked_len(&RADIX, CHUNK, len)
+                }
+                /// See the generic
+                /// [`encode_chunked_mut`](../radix/fn.encode_chunked_mut.html)
+                /// function for details.
+                pub fn encode_chunked_mut(input: &[u8], output: &mut [u8]) {
+                    ::radix::encode_chunked_mut(&RADIX, CHUNK, input, output)
+                }
+                /// See the generic
+                /// [`decode_chunked_mut`](../radix/fn.decode_chunked_mut.html)
+                /// function for details.
+                pub fn decode_chunked_mut(input: &[u8], output: &mut [u8]) -> Result<(), Error> {
+                    ::radix::decode_chunked_mut(&RADIX, CHUNK, input, output)
+                }
+                /// See the generic
+                /// [`encode_chunked`](../radix/fn.encode_chunked.html)
+                /// function for details.
+                pub fn encode_chunked(input: &[u8]) -> String {
+                    ::radix::encode_chunked(&RADIX, CHUNK, input)
+                }
+                /// See the generic
+                /// [`decode_chunked`](../radix/fn.decode_chunked.html)
+                /// function for details.
+                pub fn decode_chunked(input: &[u8]) -> Result<Vec<u8>, Error> {
+                    ::radix::decode_chunked(&RADIX, CHUNK, input)
+                }
+            };
+            $s, $($v),*,
+        }
+    };
+    (#[$d: meta] $(#[$a: meta])* mod $n: ident; $s: expr, $($v: expr),*,) => {
+        radix!{ @imp #[$d] $(#[$a])* mod $n, {}; $s, $($v),*, }
+    };
 }
 
 base!{
@@ -247,6 +628,49 @@ base!{
     X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
 }
 
+base!{
+    /// Base 4 Encoding with Nucleotide Alphabet.
+    ///
+    /// Symbols are `ACGT`. No padding is required.
+    ///
+    /// Each byte is encoded as 4 nucleotides, most significant bits
+    /// first, with `A`, `C`, `G`, and `T` having values 0 to 3. Encoding
+    /// produces uppercase symbols. Decoding accepts both lowercase and
+    /// uppercase symbols, even mixed.
+    ///
+    /// Since complementary nucleotides have complementary values, the
+    /// [`reverse_complement`](fn.reverse_complement.html) function
+    /// computes the reverse complement of a sequence directly on its
+    /// packed form.
+    mod dna, {
+        /// Reverse complements a packed sequence.
+        ///
+        /// The input is a sequence of nucleotides packed as by
+        /// [`encode`](fn.encode.html). The output is the packed sequence
+        /// read backwards with `A` and `T`, and `C` and `G` swapped.
+        pub fn reverse_complement(input: &[u8]) -> Vec<u8> {
+            input.iter().rev().map(|&x| {
+                let x = !x;
+                x >> 6 | x >> 2 & 0x0c | x << 2 & 0x30 | x << 6
+            }).collect()
+        }
+    };
+    2, b'=', &[(b'A', b'A'), (b'C', b'C'), (b'G', b'G'), (b'T', b'T')], b"ACGT",
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, 0_, X_, 1_, X_, X_, X_, 2_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, 3_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    ;
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, 0_, X_, 1_, X_, X_, X_, 2_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, 3_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, 0_, X_, 1_, X_, X_, X_, 2_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, 3_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+}
+
 base!{
     /// Base 8 Encoding.
     ///
@@ -280,6 +704,51 @@ base!{
 }
 pub use base16 as hex;
 
+base!{
+    /// Base 16 Encoding with Lowercase Alphabet.
+    ///
+    /// Symbols are `0-9a-f`. No padding is required.
+    ///
+    /// Encoding produces lowercase symbols. Decoding accepts both lowercase
+    /// and uppercase symbols, even mixed.
+    mod hexlower;
+    4, b'=', &[(b'0', b'9'), (b'a', b'f')],
+    b"0123456789abcdef",
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    0_, 1_, 2_, 3_, 4_, 5_, 6_, 7_, 8_, 9_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, 10, 11, 12, 13, 14, 15, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    ;
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    0_, 1_, 2_, 3_, 4_, 5_, 6_, 7_, 8_, 9_, X_, X_, X_, X_, X_, X_,
+    X_, 10, 11, 12, 13, 14, 15, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, 10, 11, 12, 13, 14, 15, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+}
+
+base!{
+    /// Base 16 Encoding with ModHex Alphabet.
+    ///
+    /// Symbols are `cbdefghijklnrtuv`. No padding is required.
+    ///
+    /// The symbols are located at the same position on most keyboard
+    /// layouts, which makes this encoding suitable for data typed by
+    /// devices emulating a keyboard, like YubiKey one-time passwords.
+    mod modhex;
+    4, b'=', &[(b'c', b'c'), (b'b', b'b'), (b'd', b'l'), (b'n', b'n'), (b'r', b'r'),
+               (b't', b'v')],
+    b"cbdefghijklnrtuv",
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, 1_, 0_, 2_, 3_, 4_, 5_, 6_, 7_, 8_, 9_, 10, X_, 11, X_,
+    X_, X_, 12, X_, 13, 14, 15, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+}
+
 base!{
     /// Base 32 Encoding.
     ///
@@ -299,6 +768,25 @@ base!{
     X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
 }
 
+base!{
+    /// Base 32 Encoding with Lowercase Alphabet.
+    ///
+    /// Symbols are `a-z2-7`. Padding is `=`.
+    ///
+    /// This is the `base32` alphabet in lowercase, as used by onion
+    /// addresses and DNS labels. Use `encode_nopad` and `decode_nopad` for
+    /// the unpadded form.
+    mod base32lower;
+    5, b'=', &[(b'a', b'z'), (b'2', b'7')],
+    b"abcdefghijklmnopqrstuvwxyz234567",
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, 26, 27, 28, 29, 30, 31, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, 0_, 1_, 2_, 3_, 4_, 5_, 6_, 7_, 8_, 9_, 10, 11, 12, 13, 14,
+    15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, X_, X_, X_, X_, X_,
+}
+
 base!{
     /// Base 32 Encoding with Extended Hex Alphabet.
     ///
@@ -355,3 +843,331 @@ base!{
     X_, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40,
     41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, X_, X_, X_, X_, X_,
 }
+
+base!{
+    /// Base 32 Encoding with z-base-32 Alphabet.
+    ///
+    /// Symbols are `ybndrfg8ejkmcpqxot1uwisza345h769`. No padding is
+    /// required: the `encode_nopad` and `decode_nopad` functions are
+    /// meant to be used.
+    ///
+    /// # Conformance
+    ///
+    /// [z-base-32](http://philzimmermann.com/docs/human-oriented-base-32-encoding.txt)
+    /// compliant when encoding byte strings.
+    mod zbase32;
+    5, b'=', &[(b'y', b'y'), (b'b', b'b'), (b'n', b'n'), (b'd', b'd'), (b'r', b'r'),
+              (b'f', b'g'), (b'8', b'8'), (b'e', b'e'), (b'j', b'k'), (b'm', b'm'),
+              (b'c', b'c'), (b'p', b'q'), (b'x', b'x'), (b'o', b'o'), (b't', b't'),
+              (b'1', b'1'), (b'u', b'u'), (b'w', b'w'), (b'i', b'i'), (b's', b's'),
+              (b'z', b'z'), (b'a', b'a'), (b'3', b'5'), (b'h', b'h'), (b'7', b'7'),
+              (b'6', b'6'), (b'9', b'9')],
+    b"ybndrfg8ejkmcpqxot1uwisza345h769",
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, 18, X_, 25, 26, 27, 30, 29, 7_, 31, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, 24, 1_, 12, 3_, 8_, 5_, 6_, 28, 21, 9_, 10, X_, 11, 2_, 16,
+    13, 14, 4_, 22, 17, 19, X_, 20, 15, 0_, 23, X_, X_, X_, X_, X_,
+}
+
+base!{
+    /// Base 32 Encoding with Geohash Alphabet.
+    ///
+    /// Symbols are `0-9b-hjkmnp-z`. Padding is `=`.
+    ///
+    /// Geohashes are usually not a multiple of 8 bits long. Only
+    /// geohashes of byte-aligned data can be decoded with this
+    /// module, typically using `decode_nopad`.
+    mod geohash;
+    5, b'=', &[(b'0', b'9'), (b'b', b'h'), (b'j', b'k'), (b'm', b'n'), (b'p', b'z')],
+    b"0123456789bcdefghjkmnpqrstuvwxyz",
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    0_, 1_, 2_, 3_, 4_, 5_, 6_, 7_, 8_, 9_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, 10, 11, 12, 13, 14, 15, 16, X_, 17, 18, X_, 19, 20, X_,
+    21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, X_, X_, X_, X_, X_,
+}
+
+base!{
+    /// Base 32 Encoding with Word-safe Alphabet.
+    ///
+    /// Symbols are `2-9CFGHJMPQRVWXcfghjmpqrvwx`. Padding is `=`.
+    ///
+    /// The alphabet contains no vowels and no easily confused
+    /// symbols, which prevents encoded data from forming words. Since
+    /// the alphabet uses both cases as distinct symbols, decoding is
+    /// case-sensitive. Use `encode_nopad` and `decode_nopad` for the
+    /// unpadded form.
+    mod base32wordsafe;
+    5, b'=', &[(b'2', b'9'), (b'C', b'C'), (b'F', b'H'), (b'J', b'J'), (b'M', b'M'),
+               (b'P', b'R'), (b'V', b'X'), (b'c', b'c'), (b'f', b'h'), (b'j', b'j'),
+               (b'm', b'm'), (b'p', b'r'), (b'v', b'x')],
+    b"23456789CFGHJMPQRVWXcfghjmpqrvwx",
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, 0_, 1_, 2_, 3_, 4_, 5_, 6_, 7_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, 8_, X_, X_, 9_, 10, 11, X_, 12, X_, X_, 13, X_, X_,
+    14, 15, 16, X_, X_, X_, 17, 18, 19, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, 20, X_, X_, 21, 22, 23, X_, 24, X_, X_, 25, X_, X_,
+    26, 27, 28, X_, X_, X_, 29, 30, 31, X_, X_, X_, X_, X_, X_, X_,
+}
+
+base!{
+    /// Base 64 Encoding with Word-safe Alphabet.
+    ///
+    /// Symbols are
+    /// `!#$%&*+-.23456789:?@BCDFGHJKLMNPQRSTVWXYZ^_bcdfghjkmnpqrstvwxyz~`.
+    /// Padding is `=`.
+    ///
+    /// The alphabet contains no vowels and none of `0`, `1`, and `l`,
+    /// which prevents encoded data from forming words. Punctuation
+    /// completes the consonants and digits. Decoding is case-sensitive.
+    /// Use `encode_nopad` and `decode_nopad` for the unpadded form.
+    mod base64wordsafe;
+    6, b'=', &[(b'!', b'!'), (b'#', b'&'), (b'*', b'+'), (b'-', b'.'), (b'2', b':'),
+               (b'?', b'@'), (b'B', b'D'), (b'F', b'H'), (b'J', b'N'), (b'P', b'T'),
+               (b'V', b'Z'), (b'^', b'_'), (b'b', b'd'), (b'f', b'h'), (b'j', b'k'),
+               (b'm', b'n'), (b'p', b't'), (b'v', b'z'), (b'~', b'~')],
+    b"!#$%&*+-.23456789:?@BCDFGHJKLMNPQRSTVWXYZ^_bcdfghjkmnpqrstvwxyz~",
+    X_, 0_, X_, 1_, 2_, 3_, 4_, X_, X_, X_, 5_, 6_, X_, 7_, 8_, X_,
+    X_, X_, 9_, 10, 11, 12, 13, 14, 15, 16, 17, X_, X_, X_, X_, 18,
+    19, X_, 20, 21, 22, X_, 23, 24, 25, X_, 26, 27, 28, 29, 30, X_,
+    31, 32, 33, 34, 35, X_, 36, 37, 38, 39, 40, X_, X_, X_, 41, 42,
+    X_, X_, 43, 44, 45, X_, 46, 47, 48, X_, 49, 50, X_, 51, 52, X_,
+    53, 54, 55, 56, 57, X_, 58, 59, 60, 61, 62, X_, X_, X_, 63, X_,
+}
+
+base!{
+    /// Base 32 Encoding with DNSCurve Alphabet.
+    ///
+    /// Symbols are `0-9b-df-hj-np-z`. Padding is `=`. Bits are
+    /// grouped least significant first. No padding is required: the
+    /// `encode_nopad` and `decode_nopad` functions are meant to be
+    /// used.
+    ///
+    /// # Conformance
+    ///
+    /// [DNSCurve](https://dnscurve.org/in-implement.html) compliant.
+    mod dnscurve;
+    5, b'=', LeastSignificantFirst,
+    &[(b'0', b'9'), (b'b', b'd'), (b'f', b'h'), (b'j', b'n'), (b'p', b'z')],
+    b"0123456789bcdfghjklmnpqrstuvwxyz",
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    0_, 1_, 2_, 3_, 4_, 5_, 6_, 7_, 8_, 9_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, 10, 11, 12, X_, 13, 14, 15, X_, 16, 17, 18, 19, 20, X_,
+    21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, X_, X_, X_, X_, X_,
+}
+
+base!{
+    /// Base 64 Encoding with crypt(3) Alphabet.
+    ///
+    /// Symbols are `./0-9A-Za-z`. Bits are grouped least significant
+    /// first. No padding is required: the `encode_nopad` and
+    /// `decode_nopad` functions are meant to be used.
+    ///
+    /// This is the itoa64 encoding of salts and hashes in MD5-crypt,
+    /// SHA-crypt, and similar schemes. These schemes permute the bytes
+    /// of the hash before encoding, which is not handled by this
+    /// module.
+    mod crypt;
+    6, b'=', LeastSignificantFirst,
+    &[(b'.', b'9'), (b'A', b'Z'), (b'a', b'z')],
+    b"./0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz",
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, 0_, 1_,
+    2_, 3_, 4_, 5_, 6_, 7_, 8_, 9_, 10, 11, X_, X_, X_, X_, X_, X_,
+    X_, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26,
+    27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, X_, X_, X_, X_, X_,
+    X_, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52,
+    53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, X_, X_, X_, X_, X_,
+}
+
+base!{
+    /// Base 64 Encoding with bcrypt Alphabet.
+    ///
+    /// Symbols are `./A-Za-z0-9`. No padding is required: the
+    /// `encode_nopad` and `decode_nopad` functions are meant to be used.
+    ///
+    /// In a `$2b$` hash string, the 22 symbols after the cost encode the
+    /// 16-byte salt and the remaining 31 symbols encode the 23-byte
+    /// digest.
+    mod bcrypt;
+    6, b'=', &[(b'.', b'/'), (b'A', b'Z'), (b'a', b'z'), (b'0', b'9')],
+    b"./ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, 0_, 1_,
+    54, 55, 56, 57, 58, 59, 60, 61, 62, 63, X_, X_, X_, X_, X_, X_,
+    X_, 2_, 3_, 4_, 5_, 6_, 7_, 8_, 9_, 10, 11, 12, 13, 14, 15, 16,
+    17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, X_, X_, X_, X_, X_,
+    X_, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42,
+    43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, X_, X_, X_, X_, X_,
+}
+
+pub mod base32crockford;
+pub mod nixbase32;
+
+radix!{
+    /// Base 10 Encoding.
+    ///
+    /// Symbols are `0-9`. Leading zero bytes are encoded as `0`.
+    mod base10;
+    b"0123456789",
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    0_, 1_, 2_, 3_, 4_, 5_, 6_, 7_, 8_, 9_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+}
+
+radix!{
+    /// Base 36 Encoding.
+    ///
+    /// Symbols are `0-9a-z`. Leading zero bytes are encoded as `0`.
+    mod base36;
+    b"0123456789abcdefghijklmnopqrstuvwxyz",
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    0_, 1_, 2_, 3_, 4_, 5_, 6_, 7_, 8_, 9_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+    25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, X_, X_, X_, X_, X_,
+}
+
+radix!{
+    /// Base 62 Encoding.
+    ///
+    /// Symbols are `0-9A-Za-z`. Leading zero bytes are encoded as `0`.
+    /// The chunked functions use chunks of 8 bytes encoded with 11
+    /// symbols.
+    mod base62;
+    8; b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz",
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    0_, 1_, 2_, 3_, 4_, 5_, 6_, 7_, 8_, 9_, X_, X_, X_, X_, X_, X_,
+    X_, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+    25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, X_, X_, X_, X_, X_,
+    X_, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50,
+    51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, X_, X_, X_, X_, X_,
+}
+
+radix!{
+    /// Base 58 Encoding with Bitcoin Alphabet.
+    ///
+    /// Symbols are `1-9A-HJ-NP-Za-km-z`. Leading zero bytes are encoded
+    /// as `1`.
+    ///
+    /// # Conformance
+    ///
+    /// [Bitcoin](https://en.bitcoin.it/wiki/Base58Check_encoding)
+    /// compliant.
+    ///
+    /// See [`base58flickr`](../base58flickr/index.html) and
+    /// [`base58ripple`](../base58ripple/index.html) for other
+    /// alphabets. Any other alphabet may be used by defining a
+    /// [`radix::Opt`](../radix/struct.Opt.html) and calling the generic
+    /// [`radix`](../radix/index.html) functions.
+    mod base58;
+    b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, 0_, 1_, 2_, 3_, 4_, 5_, 6_, 7_, 8_, X_, X_, X_, X_, X_, X_,
+    X_, 9_, 10, 11, 12, 13, 14, 15, 16, X_, 17, 18, 19, 20, 21, X_,
+    22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, X_, X_, X_, X_, X_,
+    X_, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, X_, 44, 45, 46,
+    47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, X_, X_, X_, X_, X_,
+}
+
+radix!{
+    /// Base 58 Encoding with Flickr Alphabet.
+    ///
+    /// Symbols are `1-9a-km-zA-HJ-NP-Z`. Leading zero bytes are encoded
+    /// as `1`.
+    mod base58flickr;
+    b"123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ",
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, 0_, 1_, 2_, 3_, 4_, 5_, 6_, 7_, 8_, X_, X_, X_, X_, X_, X_,
+    X_, 34, 35, 36, 37, 38, 39, 40, 41, X_, 42, 43, 44, 45, 46, X_,
+    47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, X_, X_, X_, X_, X_,
+    X_, 9_, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, X_, 20, 21, 22,
+    23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, X_, X_, X_, X_, X_,
+}
+
+radix!{
+    /// Base 58 Encoding with Ripple Alphabet.
+    ///
+    /// Symbols are `rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz`. Leading zero bytes
+    /// are encoded as `r`.
+    mod base58ripple;
+    b"rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz",
+    X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+    X_, 50, 33, 7_, 21, 41, 40, 27, 45, 8_, X_, X_, X_, X_, X_, X_,
+    X_, 54, 10, 38, 12, 14, 47, 15, 16, X_, 17, 18, 19, 20, 13, X_,
+    22, 23, 24, 25, 26, 11, 28, 29, 30, 31, 32, X_, X_, X_, X_, X_,
+    X_, 5_, 34, 35, 36, 37, 6_, 39, 3_, 49, 42, 43, X_, 44, 4_, 46,
+    1_, 48, 0_, 2_, 51, 52, 53, 9_, 55, 56, 57, X_, X_, X_, X_, X_,
+}
+
+radix!{
+    /// Base 94 Encoding.
+    ///
+    /// Symbols are the printable ascii from `!` to `~`, namely all
+    /// printable ascii except space. Leading zero bytes are encoded as
+    /// `!`. The chunked functions use chunks of 9 bytes encoded with 11
+    /// symbols.
+    ///
+    /// This is the densest encoding of this crate whose output contains
+    /// no whitespace. The output contains quotes and backslashes and
+    /// may need escaping.
+    mod base94;
+    9; b"!\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~",
+    X_, 0_, 1_, 2_, 3_, 4_, 5_, 6_, 7_, 8_, 9_, 10, 11, 12, 13, 14,
+    15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30,
+    31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46,
+    47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62,
+    63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78,
+    79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, X_,
+}
+
+pub mod base58check;
+pub mod base58monero;
+pub mod ascii85;
+pub mod z85;
+pub mod base85rfc1924;
+pub mod base85git;
+pub mod base91;
+pub mod base45;
+pub mod base24;
+pub mod bech32;
+pub mod onion;
+pub mod proquint;
+pub mod bip39;
+pub mod pgpwords;
+pub mod skey;
+pub mod bubblebabble;
+pub mod uuencode;
+pub mod xxencode;
+pub mod yenc;
+pub mod binhex;
+pub mod quotedprintable;
+pub mod encodedword;
+pub mod percent;
+pub mod formurlencoded;
+pub mod punycode;
+pub mod imaputf7;
+pub mod base64mime;
+pub mod base64forgiving;
+pub mod pem;
+pub mod age;
+pub mod armor;
+pub mod datauri;
+pub mod multibase;
+pub mod ihex;
+pub mod srec;
+pub mod leb128;
+pub mod zigzag;
+pub mod vlq;
+pub mod ulid;
+pub mod hashids;
+pub mod qralphanumeric;
+pub mod ext;
+pub mod prelude;
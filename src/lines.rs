@@ -0,0 +1,114 @@
+// Line-structured engine shared by uuencode and xxencode.
+//
+// A line starts with the symbol of its length, followed by groups of 3
+// bytes encoded with 4 symbols of 6 bits each, most significant first.
+// The last group is padded with zero bytes, which are ignored when
+// decoding. A file is framed by a `begin <mode> <name>` line and an
+// `end` line, the data lines being terminated by a line of length 0.
+
+use decode::Error;
+use decode::Error::*;
+use radix::Radix;
+use tool::div_ceil;
+
+pub const LINE: usize = 45;
+
+pub fn encode_line<R: Radix>(radix: &R, input: &[u8], output: &mut Vec<u8>) {
+    assert!(input.len() <= LINE);
+    output.push(radix.sym(input.len() as u8));
+    for group in input.chunks(3) {
+        let mut x = 0u32;
+        for i in 0 .. 3 {
+            x = x << 8 | *group.get(i).unwrap_or(&0) as u32;
+        }
+        for i in 0 .. 4 {
+            output.push(radix.sym((x >> 6 * (3 - i) & 63) as u8));
+        }
+    }
+    output.push(b'\n');
+}
+
+// Decodes a line without its line terminator. The position of the
+// line in the input is used to report errors.
+pub fn decode_line<R: Radix>(radix: &R, line: &[u8], pos: usize, output: &mut Vec<u8>)
+                             -> Result<(), Error> {
+    check!(BadLength, !line.is_empty());
+    let n = try!(radix.val(line[0]).ok_or(BadCharacter(pos))) as usize;
+    check!(BadLength, line.len() == 1 + 4 * div_ceil(n, 3));
+    let mut k = 0;
+    for (i, group) in line[1 ..].chunks(4).enumerate() {
+        let mut x = 0u32;
+        for (j, &s) in group.iter().enumerate() {
+            let v = try!(radix.val(s).ok_or(BadCharacter(pos + 1 + 4 * i + j)));
+            x = x << 6 | v as u32;
+        }
+        for j in 0 .. 3 {
+            if k < n {
+                output.push((x >> 8 * (2 - j)) as u8);
+                k += 1;
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn encode<R: Radix>(radix: &R, mode: u32, name: &str, input: &[u8]) -> String {
+    assert!(!name.contains('\n'));
+    let mut output = format!("begin {:o} {}\n", mode, name).into_bytes();
+    for line in input.chunks(LINE) {
+        encode_line(radix, line, &mut output);
+    }
+    encode_line(radix, &[], &mut output);
+    output.extend_from_slice(b"end\n");
+    unsafe {
+        // This is valid because the header is valid and symbols are
+        // ascii.
+        String::from_utf8_unchecked(output)
+    }
+}
+
+// Splits the input in lines and returns each line with its position.
+// Line terminators, `\n` or `\r\n`, are removed.
+pub fn lines(input: &[u8]) -> Vec<(usize, &[u8])> {
+    let mut output = Vec::new();
+    let mut pos = 0;
+    for line in input.split(|&x| x == b'\n') {
+        let n = line.len();
+        output.push((pos, if line.ends_with(b"\r") { &line[.. n - 1] } else { line }));
+        pos += n + 1;
+    }
+    output
+}
+
+fn decode_header(line: &[u8], pos: usize) -> Result<(u32, String), Error> {
+    check!(BadCharacter(pos), line.starts_with(b"begin "));
+    let mut mode = 0u32;
+    let mut i = 6;
+    while i < line.len() && line[i] != b' ' {
+        check!(BadCharacter(pos + i), b'0' <= line[i] && line[i] <= b'7' && mode >> 29 == 0);
+        mode = mode << 3 | (line[i] - b'0') as u32;
+        i += 1;
+    }
+    check!(BadCharacter(pos + i), i > 6 && i < line.len());
+    let name = try!(String::from_utf8(line[i + 1 ..].to_vec())
+                    .map_err(|e| BadCharacter(pos + i + 1 + e.utf8_error().valid_up_to())));
+    Ok((mode, name))
+}
+
+pub fn decode<R: Radix>(radix: &R, input: &[u8]) -> Result<(u32, String, Vec<u8>), Error> {
+    let mut lines = lines(input).into_iter();
+    let (pos, header) = lines.next().unwrap();
+    let (mode, name) = try!(decode_header(header, pos));
+    let mut output = Vec::new();
+    loop {
+        let (pos, line) = try!(lines.next().ok_or(BadLength));
+        let len = output.len();
+        try!(decode_line(radix, line, pos, &mut output));
+        if output.len() == len {
+            break;
+        }
+    }
+    let (pos, line) = try!(lines.next().ok_or(BadLength));
+    check!(BadCharacter(pos), line == b"end");
+    Ok((mode, name, output))
+}
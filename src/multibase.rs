@@ -0,0 +1,151 @@
+//! Multibase Encoding.
+//!
+//! The encoded data is prefixed with a character identifying its
+//! base, such that decoding does not need to know the base in advance.
+//! The supported bases are listed in the [`Code`](enum.Code.html)
+//! enum.
+//!
+//! # Conformance
+//!
+//! [Multibase](https://github.com/multiformats/multibase) compliant
+//! for the supported bases.
+
+use decode::Error;
+use decode::Error::*;
+
+/// Supported bases.
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+pub enum Code {
+    /// Lowercase [`base16`](../hexlower/index.html), prefixed by `f`.
+    Base16Lower,
+
+    /// Uppercase [`base16`](../base16/index.html), prefixed by `F`.
+    Base16Upper,
+
+    /// Lowercase [`base32`](../base32lower/index.html) without
+    /// padding, prefixed by `b`.
+    Base32Lower,
+
+    /// Uppercase [`base32`](../base32/index.html) without padding,
+    /// prefixed by `B`.
+    Base32Upper,
+
+    /// Lowercase [`base32`](../base32lower/index.html) with padding,
+    /// prefixed by `c`.
+    Base32PadLower,
+
+    /// Uppercase [`base32`](../base32/index.html) with padding,
+    /// prefixed by `C`.
+    Base32PadUpper,
+
+    /// [`base58`](../base58/index.html) with the Bitcoin alphabet,
+    /// prefixed by `z`.
+    Base58Btc,
+
+    /// [`base64`](../base64/index.html) without padding, prefixed by
+    /// `m`.
+    Base64,
+
+    /// [`base64`](../base64/index.html) with padding, prefixed by `M`.
+    Base64Pad,
+
+    /// [`base64url`](../base64url/index.html) without padding,
+    /// prefixed by `u`.
+    Base64Url,
+
+    /// [`base64url`](../base64url/index.html) with padding, prefixed
+    /// by `U`.
+    Base64UrlPad,
+}
+
+use self::Code::*;
+
+const CODES: [Code; 11] = [
+    Base16Lower, Base16Upper, Base32Lower, Base32Upper, Base32PadLower, Base32PadUpper,
+    Base58Btc, Base64, Base64Pad, Base64Url, Base64UrlPad,
+];
+
+impl Code {
+    /// Returns the prefix.
+    pub fn prefix(self) -> u8 {
+        match self {
+            Base16Lower => b'f',
+            Base16Upper => b'F',
+            Base32Lower => b'b',
+            Base32Upper => b'B',
+            Base32PadLower => b'c',
+            Base32PadUpper => b'C',
+            Base58Btc => b'z',
+            Base64 => b'm',
+            Base64Pad => b'M',
+            Base64Url => b'u',
+            Base64UrlPad => b'U',
+        }
+    }
+
+    /// Returns the base of a prefix.
+    pub fn from_prefix(x: u8) -> Option<Code> {
+        CODES.iter().cloned().find(|c| c.prefix() == x)
+    }
+}
+
+/// Encodes with allocation.
+pub fn encode(code: Code, input: &[u8]) -> String {
+    let data = match code {
+        Base16Lower => ::hexlower::encode(input),
+        Base16Upper => ::base16::encode(input),
+        Base32Lower => ::base32lower::encode_nopad(input),
+        Base32Upper => ::base32::encode_nopad(input),
+        Base32PadLower => ::base32lower::encode(input),
+        Base32PadUpper => ::base32::encode(input),
+        Base58Btc => ::base58::encode(input),
+        Base64 => ::base64::encode_nopad(input),
+        Base64Pad => ::base64::encode(input),
+        Base64Url => ::base64url::encode_nopad(input),
+        Base64UrlPad => ::base64url::encode(input),
+    };
+    let mut output = String::with_capacity(1 + data.len());
+    output.push(code.prefix() as char);
+    output.push_str(&data);
+    output
+}
+
+/// Decodes with allocation.
+///
+/// This function returns the base and the decoded data.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadLength` if the input is empty.
+/// - `Error::BadCharacter(0)` if the prefix is not supported.
+///
+/// Decoding may also fail in the circumstances defined by the
+/// decoding function of the base.
+pub fn decode(input: &[u8]) -> Result<(Code, Vec<u8>), Error> {
+    check!(BadLength, !input.is_empty());
+    let code = try!(Code::from_prefix(input[0]).ok_or(BadCharacter(0)));
+    let data = &input[1 ..];
+    let output = match code {
+        Base16Lower => ::hexlower::decode(data),
+        Base16Upper => ::base16::decode(data),
+        Base32Lower => ::base32lower::decode_nopad(data),
+        Base32Upper => ::base32::decode_nopad(data),
+        Base32PadLower => ::base32lower::decode(data),
+        Base32PadUpper => ::base32::decode(data),
+        Base58Btc => ::base58::decode(data),
+        Base64 => ::base64::decode_nopad(data),
+        Base64Pad => ::base64::decode(data),
+        Base64Url => ::base64url::decode_nopad(data),
+        Base64UrlPad => ::base64url::decode(data),
+    };
+    Ok((code, try!(output.map_err(|e| e.shift(1)))))
+}
+
+#[test]
+fn check() {
+    for &code in CODES.iter() {
+        assert_eq!(Code::from_prefix(code.prefix()), Some(code));
+    }
+}
@@ -0,0 +1,108 @@
+//! Base 32 Encoding with Nix Alphabet.
+//!
+//! Symbols are `0-9a-df-np-sv-z`. No padding is used.
+//!
+//! The input is read as a little-endian number and encoded in groups
+//! of 5 bits, most significant group first. In other words, the
+//! output is the reverse of the encoding with least significant bit
+//! first grouping. A 20-byte hash is encoded with 32 symbols and a
+//! 32-byte hash with 52 symbols.
+//!
+//! See the generic [`encode`](../encode/index.html) and
+//! [`decode`](../decode/index.html) modules for details about this
+//! module functions.
+//!
+//! # Conformance
+//!
+//! [Nix](https://nixos.org/) compliant, as used by store paths and
+//! `nix-hash --base32`.
+
+use base::{BitOrder, Opt};
+use decode::Error;
+
+const X_: u8 = 128;
+
+/// Force static dispatch.
+pub enum Static {}
+
+static BASE: Opt<Static> = Opt {
+    val: ascii!(
+        X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+        0_, 1_, 2_, 3_, 4_, 5_, 6_, 7_, 8_, 9_, X_, X_, X_, X_, X_, X_,
+        X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+        X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+        X_, 10, 11, 12, 13, X_, 14, 15, 16, 17, 18, 19, 20, 21, 22, X_,
+        23, 24, 25, 26, X_, X_, 27, 28, 29, 30, 31, X_, X_, X_, X_, X_),
+    sym: b"0123456789abcdfghijklmnpqrsvwxyz", bit: 5, pad: b'=',
+    order: BitOrder::LeastSignificantFirst, _phantom: ::std::marker::PhantomData
+};
+
+/// Gives access to the base.
+///
+/// This base groups bits least significant first and does not
+/// reverse its output.
+pub fn base() -> &'static Opt<Static> {
+    &BASE
+}
+
+/// See the generic
+/// [`encode_nopad_len`](../encode/fn.encode_nopad_len.html) function
+/// for details.
+pub fn encode_len(len: usize) -> usize {
+    ::encode::encode_nopad_len(&BASE, len)
+}
+
+/// Encodes without allocation.
+///
+/// See the generic
+/// [`encode_nopad_mut`](../encode/fn.encode_nopad_mut.html) function
+/// for details.
+pub fn encode_mut(input: &[u8], output: &mut [u8]) {
+    ::encode::encode_nopad_mut(&BASE, input, output);
+    output.reverse();
+}
+
+/// Encodes with allocation.
+///
+/// See the generic [`encode_nopad`](../encode/fn.encode_nopad.html)
+/// function for details.
+pub fn encode(input: &[u8]) -> String {
+    let mut output = vec![0u8; encode_len(input.len())];
+    encode_mut(input, &mut output);
+    unsafe {
+        // This is valid because symbols are ascii.
+        String::from_utf8_unchecked(output)
+    }
+}
+
+/// Decodes with allocation.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`Error`](../decode/enum.Error.html). Reported positions refer to
+/// the input as given.
+pub fn decode(input: &[u8]) -> Result<Vec<u8>, Error> {
+    let n = input.len();
+    let reversed: Vec<u8> = input.iter().rev().cloned().collect();
+    ::decode::decode_nopad(&BASE, &reversed).map_err(|e| e.map(|p| n - 1 - p))
+}
+
+#[test]
+fn check() {
+    use base::{Base, Spec, equal, valid};
+    const SPEC: Spec = Spec {
+        val: &[(b'0', b'9'), (b'a', b'd'), (b'f', b'n'), (b'p', b's'), (b'v', b'z')],
+        pad: b'=',
+    };
+    assert_eq!(BASE.val.len(), 256);
+    assert_eq!(BASE.sym.len(), 1 << BASE.bit);
+    valid(&SPEC).unwrap();
+    valid(&BASE).unwrap();
+    equal(&BASE, &SPEC).unwrap();
+    for s in 0..128u8 {
+        if let Some(v) = BASE.val(s) {
+            assert_eq!(BASE.sym(v), s);
+        }
+    }
+}
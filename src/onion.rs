@@ -0,0 +1,67 @@
+//! Tor Version 3 Onion Addresses.
+//!
+//! An address is the [`base32lower`](../base32lower/index.html)
+//! encoding without padding of the 32-byte ed25519 public key, a
+//! 2-byte checksum, and the version byte `3`, followed by `.onion`.
+//! The checksum is the first 2 bytes of the SHA3-256 digest of
+//! `.onion checksum`, the public key, and the version byte.
+//!
+//! # Conformance
+//!
+//! [Tor rend-spec-v3](https://spec.torproject.org/rend-spec/encoding-onion-addresses.html)
+//! compliant.
+
+use checksum::sha3_256;
+use decode::Error;
+use decode::Error::*;
+
+/// The version byte.
+pub const VERSION: u8 = 3;
+
+/// The address suffix.
+pub const SUFFIX: &'static str = ".onion";
+
+fn checksum(key: &[u8]) -> [u8; 2] {
+    let mut data = Vec::with_capacity(15 + 32 + 1);
+    data.extend_from_slice(b".onion checksum");
+    data.extend_from_slice(key);
+    data.push(VERSION);
+    let digest = sha3_256(&data);
+    [digest[0], digest[1]]
+}
+
+/// Encodes a public key.
+pub fn encode(key: &[u8; 32]) -> String {
+    let mut data = Vec::with_capacity(35);
+    data.extend_from_slice(key);
+    data.extend_from_slice(&checksum(key));
+    data.push(VERSION);
+    let mut output = ::base32lower::encode_nopad(&data);
+    output.push_str(SUFFIX);
+    output
+}
+
+/// Decodes a public key.
+///
+/// The `.onion` suffix is optional.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`Error`](../decode/enum.Error.html). It returns
+/// `Error::BadLength` if the address is not 56 symbols long and
+/// `Error::BadChecksum` if the version or the checksum does not
+/// match.
+pub fn decode(input: &[u8]) -> Result<[u8; 32], Error> {
+    let mut input = input;
+    if input.ends_with(SUFFIX.as_bytes()) {
+        input = &input[.. input.len() - SUFFIX.len()];
+    }
+    check!(BadLength, input.len() == 56);
+    let data = try!(::base32lower::decode_nopad(input));
+    check!(BadChecksum, data[34] == VERSION);
+    check!(BadChecksum, checksum(&data[.. 32]) == data[32 .. 34]);
+    let mut key = [0u8; 32];
+    key.copy_from_slice(&data[.. 32]);
+    Ok(key)
+}
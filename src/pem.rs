@@ -0,0 +1,156 @@
+//! PEM Encoding.
+//!
+//! The input is encoded with [`base64`](../base64/index.html) in
+//! lines of 64 characters, between a `-----BEGIN <label>-----` line
+//! and a `-----END <label>-----` line. The label describes the
+//! content, like `CERTIFICATE` or `PRIVATE KEY`.
+//!
+//! Decoding supports a [`Mode`](enum.Mode.html) corresponding to the
+//! strict and lax grammars of the specification.
+//!
+//! # Conformance
+//!
+//! [RFC 7468](https://tools.ietf.org/html/rfc7468) compliant.
+//! Encoding uses `\n` line terminators.
+
+use decode::Error;
+use decode::Error::*;
+use lines::lines;
+
+/// The length of encoded lines.
+pub const LINE: usize = 64;
+
+const BEGIN: &'static [u8] = b"-----BEGIN ";
+const END: &'static [u8] = b"-----END ";
+const DASHES: &'static [u8] = b"-----";
+
+/// Decoding modes.
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+pub enum Mode {
+    /// Follows the strict grammar.
+    ///
+    /// The input must be a single message, optionally followed by a
+    /// line terminator. Lines must be exactly 64 characters long,
+    /// except the last one, and must not contain whitespace.
+    Strict,
+
+    /// Follows the lax grammar.
+    ///
+    /// Text before the first boundary and after the last one is
+    /// ignored, whitespace is allowed anywhere in the boundaries
+    /// indentation and in the encoded data, lines may be of any
+    /// length, and the label of the last boundary is not checked.
+    Lax,
+}
+
+// Returns whether a label is valid: printable ascii characters,
+// possibly separated by single spaces or hyphens.
+fn valid_label(label: &[u8]) -> bool {
+    let sep = |x: u8| x == b' ' || x == b'-';
+    label.iter().all(|&x| b' ' <= x && x <= b'~')
+        && label.first().map_or(true, |&x| !sep(x))
+        && label.last().map_or(true, |&x| !sep(x))
+        && label.windows(2).all(|x| !(sep(x[0]) && sep(x[1])))
+}
+
+// Returns the label of a boundary line.
+fn boundary<'a>(line: &'a [u8], kind: &[u8]) -> Option<&'a [u8]> {
+    let n = line.len();
+    if n < kind.len() + DASHES.len() || !line.starts_with(kind) || !line.ends_with(DASHES) {
+        return None;
+    }
+    let label = &line[kind.len() .. n - DASHES.len()];
+    if valid_label(label) { Some(label) } else { None }
+}
+
+fn space(x: u8) -> bool {
+    match x {
+        b' ' | b'\t' | b'\r' | b'\n' | 0x0b | 0x0c => true,
+        _ => false,
+    }
+}
+
+fn trim(line: &[u8]) -> &[u8] {
+    let i = line.iter().position(|&x| !space(x)).unwrap_or(line.len());
+    let j = line.iter().rposition(|&x| !space(x)).map_or(i, |j| j + 1);
+    &line[i .. j]
+}
+
+/// Encodes with allocation.
+///
+/// # Panics
+///
+/// Panics if `label` is not valid, namely if it contains characters
+/// which are not printable ascii, or if it starts or ends with a space
+/// or a hyphen, or contains two consecutive such characters.
+pub fn encode(label: &str, input: &[u8]) -> String {
+    assert!(valid_label(label.as_bytes()));
+    let mut output = format!("-----BEGIN {}-----\n", label);
+    for line in input.chunks(LINE / 4 * 3) {
+        output.push_str(&::base64::encode(line));
+        output.push('\n');
+    }
+    output.push_str(&format!("-----END {}-----\n", label));
+    output
+}
+
+/// Decodes with allocation.
+///
+/// This function returns the label and the payload.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadCharacter` if a boundary is invalid, if the labels
+/// differ in strict mode, if a line has an invalid length or contains
+/// whitespace in strict mode, or if there is text after the message in
+/// strict mode. The position of an invalid line is the one of its
+/// first character.
+/// - `Error::BadLength` if a boundary is missing.
+///
+/// Decoding may also fail in the circumstances defined by
+/// [`base64::decode`](../base64/fn.decode.html), once the line breaks
+/// are removed. Positions refer to the input.
+pub fn decode(input: &[u8], mode: Mode) -> Result<(String, Vec<u8>), Error> {
+    let mut lines = lines(input).into_iter();
+    let label = match mode {
+        Mode::Strict => {
+            let (pos, line) = lines.next().unwrap();
+            try!(boundary(line, BEGIN).ok_or(BadCharacter(pos)))
+        }
+        Mode::Lax => try!(lines.by_ref().filter_map(|(_, line)| boundary(trim(line), BEGIN))
+                          .next().ok_or(BadLength)),
+    };
+    let mut pos = Vec::with_capacity(input.len());
+    let mut data = Vec::with_capacity(input.len());
+    let mut short = None;
+    loop {
+        let (start, line) = try!(lines.next().ok_or(BadLength));
+        if let Some(end) = boundary(if mode == Mode::Lax { trim(line) } else { line }, END) {
+            check!(BadCharacter(start), mode == Mode::Lax || end == label);
+            break;
+        }
+        if mode == Mode::Strict {
+            check!(BadCharacter(start), short.is_none());
+            check!(BadCharacter(start), !line.is_empty() && line.len() <= LINE);
+            if line.len() < LINE {
+                short = Some(start);
+            }
+        }
+        for (i, &x) in line.iter().enumerate() {
+            check!(BadCharacter(start + i), mode == Mode::Lax || !space(x));
+            if !space(x) {
+                pos.push(start + i);
+                data.push(x);
+            }
+        }
+    }
+    if mode == Mode::Strict {
+        if let Some((start, line)) = lines.next() {
+            check!(BadCharacter(start), line.is_empty() && lines.next().is_none());
+        }
+    }
+    let output = try!(::base64::decode(&data).map_err(|e| e.map(|p| pos[p])));
+    Ok((label.iter().map(|&x| x as char).collect(), output))
+}
@@ -0,0 +1,197 @@
+//! Percent-Encoding.
+//!
+//! Bytes of an [`AsciiSet`](struct.AsciiSet.html) are encoded as `%`
+//! followed by their value with 2 uppercase hexadecimal digits. Other
+//! bytes represent themselves. Controls, `%`, and non-ascii bytes are
+//! always encoded, such that decoding is the inverse of encoding.
+//!
+//! Presets are provided for the components of a URL. Decoding accepts
+//! lowercase hexadecimal digits and does not depend on the set.
+//!
+//! # Conformance
+//!
+//! [RFC 3986](https://tools.ietf.org/html/rfc3986#section-2.1)
+//! compliant. The presets follow the [URL
+//! Standard](https://url.spec.whatwg.org/#percent-encoded-bytes), with
+//! `%` added.
+
+use decode::Error;
+use decode::Error::*;
+use tool::hex_val;
+
+/// Set of ascii characters to encode.
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+pub struct AsciiSet {
+    mask: [u32; 4],
+}
+
+impl AsciiSet {
+    /// Creates a set from the characters to encode.
+    ///
+    /// Controls and `%` are always part of the set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chars` contains non-ascii characters.
+    pub fn new(chars: &[u8]) -> AsciiSet {
+        AsciiSet { mask: [!0, 1 << 5, 0, 1 << 31] }.with(chars)
+    }
+
+    /// Returns the union of the set and some characters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chars` contains non-ascii characters.
+    pub fn with(mut self, chars: &[u8]) -> AsciiSet {
+        for &x in chars {
+            assert!(x < 128);
+            self.mask[x as usize / 32] |= 1 << (x % 32);
+        }
+        self
+    }
+
+    /// Returns whether a byte is encoded.
+    pub fn contains(&self, x: u8) -> bool {
+        x >= 128 || self.mask[x as usize / 32] >> (x % 32) & 1 == 1
+    }
+}
+
+/// The fragment set: `` "<>` `` and space.
+pub const FRAGMENT: AsciiSet = AsciiSet {
+    mask: [0xffffffff, 0x50000025, 0x00000000, 0x80000001],
+};
+
+/// The query set: `"#<>` and space.
+pub const QUERY: AsciiSet = AsciiSet {
+    mask: [0xffffffff, 0x5000002d, 0x00000000, 0x80000000],
+};
+
+/// The path set: the query set and `` ?`{} ``.
+pub const PATH: AsciiSet = AsciiSet {
+    mask: [0xffffffff, 0xd000002d, 0x00000000, 0xa8000001],
+};
+
+/// The userinfo set: the path set and `/:;=@[\]^|`.
+pub const USERINFO: AsciiSet = AsciiSet {
+    mask: [0xffffffff, 0xfc00802d, 0x78000001, 0xb8000001],
+};
+
+/// The component set: the userinfo set and `$&+,`.
+pub const COMPONENT: AsciiSet = AsciiSet {
+    mask: [0xffffffff, 0xfc00987d, 0x78000001, 0xb8000001],
+};
+
+/// The form set: the component set and `!'()~`.
+///
+/// This set is used by
+/// [`formurlencoded`](../formurlencoded/index.html).
+pub const FORM: AsciiSet = AsciiSet {
+    mask: [0xffffffff, 0xfc009bff, 0x78000001, 0xf8000001],
+};
+
+/// Converts an input to its output length.
+pub fn encode_len(input: &[u8], set: &AsciiSet) -> usize {
+    input.len() + 2 * input.iter().filter(|&&x| set.contains(x)).count()
+}
+
+/// Converts an input length to its maximum output length.
+///
+/// The actual output length is returned by
+/// [`decode_mut`](fn.decode_mut.html).
+pub fn decode_len(len: usize) -> usize {
+    len
+}
+
+/// Encodes without allocation.
+///
+/// # Panics
+///
+/// Panics if `output.len() != encode_len(input, set)`.
+pub fn encode_mut(input: &[u8], set: &AsciiSet, output: &mut [u8]) {
+    assert_eq!(output.len(), encode_len(input, set));
+    let mut j = 0;
+    for &x in input {
+        if set.contains(x) {
+            output[j] = b'%';
+            ::base16::encode_mut(&[x], &mut output[j + 1 .. j + 3]);
+            j += 3;
+        } else {
+            output[j] = x;
+            j += 1;
+        }
+    }
+}
+
+/// Decodes without allocation.
+///
+/// This function returns the output length.
+///
+/// # Failures
+///
+/// Returns `Error::BadCharacter` if a `%` is not followed by 2
+/// hexadecimal digits.
+///
+/// # Panics
+///
+/// Panics if `output.len() != decode_len(input.len())`.
+pub fn decode_mut(input: &[u8], output: &mut [u8]) -> Result<usize, Error> {
+    assert_eq!(output.len(), decode_len(input.len()));
+    let mut i = 0;
+    let mut j = 0;
+    while i < input.len() {
+        output[j] = input[i];
+        if input[i] == b'%' {
+            let h = input.get(i + 1).and_then(|&x| hex_val(x, true));
+            let l = input.get(i + 2).and_then(|&x| hex_val(x, true));
+            match (h, l) {
+                (Some(h), Some(l)) => output[j] = h << 4 | l,
+                _ => return Err(BadCharacter(i)),
+            }
+            i += 2;
+        }
+        i += 1;
+        j += 1;
+    }
+    Ok(j)
+}
+
+/// Encodes with allocation.
+///
+/// This function is a wrapper for [`encode_mut`](fn.encode_mut.html)
+/// that allocates an output of the correct size using
+/// [`encode_len`](fn.encode_len.html).
+pub fn encode(input: &[u8], set: &AsciiSet) -> String {
+    let mut output = vec![0u8; encode_len(input, set)];
+    encode_mut(input, set, &mut output);
+    unsafe {
+        // This is valid because non-ascii bytes are encoded.
+        String::from_utf8_unchecked(output)
+    }
+}
+
+/// Decodes with allocation.
+///
+/// This function is a wrapper for [`decode_mut`](fn.decode_mut.html)
+/// that allocates an output of the correct size using
+/// [`decode_len`](fn.decode_len.html).
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`decode_mut`](fn.decode_mut.html).
+pub fn decode(input: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut output = vec![0u8; decode_len(input.len())];
+    let len = try!(decode_mut(input, &mut output));
+    output.truncate(len);
+    Ok(output)
+}
+
+#[test]
+fn check() {
+    assert_eq!(FRAGMENT, AsciiSet::new(b" \"<>`"));
+    assert_eq!(QUERY, AsciiSet::new(b" \"#<>"));
+    assert_eq!(PATH, QUERY.with(b"?`{}"));
+    assert_eq!(USERINFO, PATH.with(b"/:;=@[\\]^|"));
+    assert_eq!(COMPONENT, USERINFO.with(b"$&+,"));
+    assert_eq!(FORM, COMPONENT.with(b"!'()~"));
+}
@@ -0,0 +1,19 @@
+//! Prelude module.
+//!
+//! This module re-exports the common encodings, the decoding error,
+//! and the extension traits, such that a single glob import is
+//! enough for most applications.
+//!
+//! # Examples
+//!
+//! ```
+//! use data_encoding::prelude::*;
+//! assert_eq!(BASE64.encode(b"fo"), "Zm8=");
+//! assert_eq!(b"fo".to_hex(), "666f");
+//! assert_eq!("Zm8".from_base64(), Err(DecodeError::BadLength));
+//! ```
+
+pub use base::Base;
+pub use decode::Error as DecodeError;
+pub use ext::{DecodeExt, EncodeExt};
+pub use specification::{BASE16, BASE32, BASE32HEX, BASE64, BASE64URL, Encoding};
@@ -0,0 +1,88 @@
+//! Proquint Encoding.
+//!
+//! Each group of 2 bytes is read as a big-endian 16-bit number and
+//! encoded as a pronounceable quintet of letters: a consonant from
+//! `bdfghjklmnprstvz` for 4 bits, a vowel from `aiou` for 2 bits, a
+//! consonant, a vowel, and a consonant. Quintets are separated by
+//! hyphens.
+//!
+//! # Conformance
+//!
+//! [Proquints](https://arxiv.org/html/0901.4016) compliant.
+
+use decode::Error;
+use decode::Error::*;
+
+const CON: &'static [u8; 16] = b"bdfghjklmnprstvz";
+const VOW: &'static [u8; 4] = b"aiou";
+
+fn val(alphabet: &[u8], x: u8) -> Option<u16> {
+    alphabet.iter().position(|&y| y == x).map(|v| v as u16)
+}
+
+/// Converts an input length to its output length.
+///
+/// # Panics
+///
+/// Panics if `len` is odd.
+pub fn encode_len(len: usize) -> usize {
+    assert_eq!(len % 2, 0);
+    if len == 0 { 0 } else { 6 * len / 2 - 1 }
+}
+
+/// Encodes with allocation.
+///
+/// # Panics
+///
+/// Panics if `input.len()` is odd.
+pub fn encode(input: &[u8]) -> String {
+    let mut output = Vec::with_capacity(encode_len(input.len()));
+    for (i, word) in input.chunks(2).enumerate() {
+        if i > 0 {
+            output.push(b'-');
+        }
+        let x = (word[0] as usize) << 8 | word[1] as usize;
+        output.push(CON[x >> 12]);
+        output.push(VOW[x >> 10 & 3]);
+        output.push(CON[x >> 6 & 15]);
+        output.push(VOW[x >> 4 & 3]);
+        output.push(CON[x & 15]);
+    }
+    unsafe {
+        // This is valid because letters are ascii.
+        String::from_utf8_unchecked(output)
+    }
+}
+
+/// Decodes with allocation.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadLength` if the input is not a sequence of quintets
+/// separated by hyphens.
+/// - `Error::BadCharacter` if a quintet contains a letter at an
+/// inappropriate position or if quintets are not separated by a
+/// hyphen.
+pub fn decode(input: &[u8]) -> Result<Vec<u8>, Error> {
+    if input.is_empty() {
+        return Ok(Vec::new());
+    }
+    check!(BadLength, (input.len() + 1) % 6 == 0);
+    let mut output = Vec::with_capacity((input.len() + 1) / 3);
+    for (i, chunk) in input.chunks(6).enumerate() {
+        if chunk.len() == 6 {
+            check!(BadCharacter(6 * i + 5), chunk[5] == b'-');
+        }
+        let mut x = 0u16;
+        for j in 0 .. 5 {
+            let (alphabet, bit): (&[u8], _) = if j % 2 == 0 { (CON, 4) } else { (VOW, 2) };
+            let v = try!(val(alphabet, chunk[j]).ok_or(BadCharacter(6 * i + j)));
+            x = x << bit | v;
+        }
+        output.push((x >> 8) as u8);
+        output.push(x as u8);
+    }
+    Ok(output)
+}
@@ -0,0 +1,191 @@
+//! Punycode Encoding.
+//!
+//! Punycode represents a Unicode string with ascii characters. The
+//! ascii characters of the string are copied, followed by `-` if
+//! there are some. The other characters are encoded as a sequence of
+//! insertions, each being a variable-length integer with symbols
+//! `a-z0-9` and an adaptive bias.
+//!
+//! The [`encode_label`](fn.encode_label.html) and
+//! [`decode_label`](fn.decode_label.html) functions handle the `xn--`
+//! prefix of internationalized domain name labels. They do not perform
+//! any mapping or normalization of the labels.
+//!
+//! # Conformance
+//!
+//! [RFC 3492](https://tools.ietf.org/html/rfc3492) compliant.
+
+use decode::Error;
+use decode::Error::*;
+
+const BASE: u64 = 36;
+const TMIN: u64 = 1;
+const TMAX: u64 = 26;
+const SKEW: u64 = 38;
+const DAMP: u64 = 700;
+const BIAS: u64 = 72;
+const N: u64 = 128;
+
+/// The prefix of encoded labels.
+pub const PREFIX: &'static str = "xn--";
+
+fn adapt(mut delta: u64, len: u64, first: bool) -> u64 {
+    delta /= if first { DAMP } else { 2 };
+    delta += delta / len;
+    let mut k = 0;
+    while delta > (BASE - TMIN) * TMAX / 2 {
+        delta /= BASE - TMIN;
+        k += BASE;
+    }
+    k + (BASE - TMIN + 1) * delta / (delta + SKEW)
+}
+
+fn threshold(k: u64, bias: u64) -> u64 {
+    if k <= bias + TMIN { TMIN } else if k >= bias + TMAX { TMAX } else { k - bias }
+}
+
+fn sym(v: u64) -> u8 {
+    b"abcdefghijklmnopqrstuvwxyz0123456789"[v as usize]
+}
+
+fn val(x: u8) -> Option<u64> {
+    match x {
+        b'a' ..= b'z' => Some((x - b'a') as u64),
+        b'A' ..= b'Z' => Some((x - b'A') as u64),
+        b'0' ..= b'9' => Some((x - b'0') as u64 + 26),
+        _ => None,
+    }
+}
+
+/// Encodes with allocation.
+pub fn encode(input: &str) -> String {
+    let input: Vec<u64> = input.chars().map(|c| c as u64).collect();
+    let mut output: Vec<u8> = input.iter().filter(|&&c| c < N).map(|&c| c as u8).collect();
+    let b = output.len();
+    if b > 0 {
+        output.push(b'-');
+    }
+    let mut n = N;
+    let mut delta = 0;
+    let mut bias = BIAS;
+    let mut h = b;
+    while h < input.len() {
+        let m = *input.iter().filter(|&&c| c >= n).min().unwrap();
+        delta += (m - n) * (h as u64 + 1);
+        n = m;
+        for &c in &input {
+            if c < n {
+                delta += 1;
+            }
+            if c != n {
+                continue;
+            }
+            let mut q = delta;
+            let mut k = BASE;
+            loop {
+                let t = threshold(k, bias);
+                if q < t {
+                    break;
+                }
+                output.push(sym(t + (q - t) % (BASE - t)));
+                q = (q - t) / (BASE - t);
+                k += BASE;
+            }
+            output.push(sym(q));
+            bias = adapt(delta, h as u64 + 1, h == b);
+            delta = 0;
+            h += 1;
+        }
+        delta += 1;
+        n += 1;
+    }
+    unsafe {
+        // This is valid because the output is ascii.
+        String::from_utf8_unchecked(output)
+    }
+}
+
+/// Decodes with allocation.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadCharacter` if the input contains a non-ascii character
+/// before the last `-` or a non-symbol after it, or if an insertion
+/// overflows or is not a valid character. The position of an invalid
+/// insertion is the one of its first symbol.
+/// - `Error::BadLength` if the last insertion is incomplete.
+pub fn decode(input: &[u8]) -> Result<String, Error> {
+    let sep = input.iter().rposition(|&x| x == b'-');
+    let b = sep.unwrap_or(0);
+    let mut output = Vec::with_capacity(input.len());
+    for (i, &x) in input[.. b].iter().enumerate() {
+        check!(BadCharacter(i), x < 128);
+        output.push(x as char);
+    }
+    let mut n = N;
+    let mut pos = 0u64;
+    let mut bias = BIAS;
+    let mut i = sep.map_or(0, |b| b + 1);
+    while i < input.len() {
+        let start = i;
+        let old = pos;
+        let mut w = 1u64;
+        let mut k = BASE;
+        loop {
+            check!(BadLength, i < input.len());
+            let v = try!(val(input[i]).ok_or(BadCharacter(i)));
+            i += 1;
+            pos = try!(w.checked_mul(v).and_then(|x| x.checked_add(pos))
+                       .ok_or(BadCharacter(start)));
+            let t = threshold(k, bias);
+            if v < t {
+                break;
+            }
+            w = try!(w.checked_mul(BASE - t).ok_or(BadCharacter(start)));
+            k += BASE;
+        }
+        let len = output.len() as u64 + 1;
+        bias = adapt(pos - old, len, old == 0);
+        n = try!((pos / len).checked_add(n).ok_or(BadCharacter(start)));
+        pos %= len;
+        check!(BadCharacter(start), n <= 0x10ffff);
+        let c = try!(::std::char::from_u32(n as u32).ok_or(BadCharacter(start)));
+        output.insert(pos as usize, c);
+        pos += 1;
+    }
+    Ok(output.into_iter().collect())
+}
+
+/// Encodes a label with allocation.
+///
+/// Labels with only ascii characters are returned unchanged. Other
+/// labels are encoded and prefixed with `xn--`.
+pub fn encode_label(input: &str) -> String {
+    if input.is_ascii() {
+        return input.to_string();
+    }
+    format!("{}{}", PREFIX, encode(input))
+}
+
+/// Decodes a label with allocation.
+///
+/// Labels without the `xn--` prefix, in any case, are returned
+/// unchanged.
+///
+/// # Failures
+///
+/// Returns `Error::BadCharacter` if the input is not ascii. Decoding
+/// may also fail in the circumstances defined by
+/// [`decode`](fn.decode.html).
+pub fn decode_label(input: &[u8]) -> Result<String, Error> {
+    if let Some(i) = input.iter().position(|&x| x >= 128) {
+        return Err(BadCharacter(i));
+    }
+    let n = PREFIX.len();
+    if input.len() < n || !input[.. n].eq_ignore_ascii_case(PREFIX.as_bytes()) {
+        return Ok(input.iter().map(|&x| x as char).collect());
+    }
+    decode(&input[n ..]).map_err(|e| e.shift(n))
+}
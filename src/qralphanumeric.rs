@@ -0,0 +1,186 @@
+//! QR Code Alphanumeric Mode Encoding.
+//!
+//! Symbols are `0-9A-Z $%*+-./:`, with values 0 to 44. Text is
+//! encoded as a bit stream, most significant bit first: each pair of
+//! symbols is encoded in 11 bits as `45 * v1 + v2`, and a final single
+//! symbol is encoded in 6 bits.
+//!
+//! Bit streams are given as a byte slice and a number of bits. The
+//! unused bits of the last byte are zero when encoding and ignored
+//! when decoding. Decoding needs the number of symbols, which QR codes
+//! store in the segment header. The
+//! [`encode_segment`](fn.encode_segment.html) and
+//! [`decode_segment`](fn.decode_segment.html) functions handle this
+//! header.
+//!
+//! # Conformance
+//!
+//! [ISO/IEC 18004](https://www.iso.org/standard/62021.html) compliant
+//! for alphanumeric mode segments.
+
+use decode::Error;
+use decode::Error::*;
+
+/// The symbols in value order.
+pub const SYMBOLS: &'static [u8; 45] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+/// The mode indicator of alphanumeric segments.
+pub const MODE: u8 = 0b0010;
+
+fn val(x: u8) -> Option<u8> {
+    SYMBOLS.iter().position(|&s| s == x).map(|v| v as u8)
+}
+
+struct Writer {
+    data: Vec<u8>,
+    len: usize,
+}
+
+impl Writer {
+    fn push(&mut self, x: u32, n: usize) {
+        for i in (0 .. n).rev() {
+            if self.len % 8 == 0 {
+                self.data.push(0);
+            }
+            if x >> i & 1 == 1 {
+                *self.data.last_mut().unwrap() |= 0x80 >> self.len % 8;
+            }
+            self.len += 1;
+        }
+    }
+}
+
+fn read(input: &[u8], pos: usize, n: usize) -> Result<u32, Error> {
+    check!(BadLength, pos + n <= 8 * input.len());
+    let mut x = 0;
+    for i in pos .. pos + n {
+        x = x << 1 | (input[i / 8] >> 7 - i % 8 & 1) as u32;
+    }
+    Ok(x)
+}
+
+/// Returns the number of bits of the encoding of `len` symbols.
+pub fn encode_len(len: usize) -> usize {
+    len / 2 * 11 + len % 2 * 6
+}
+
+/// Returns the number of bits of the symbol count of a version.
+///
+/// # Panics
+///
+/// Panics if `version` is not between 1 and 40 inclusive.
+pub fn count_bits(version: u8) -> usize {
+    match version {
+        1 ..= 9 => 9,
+        10 ..= 26 => 11,
+        27 ..= 40 => 13,
+        _ => panic!("Version is not between 1 and 40."),
+    }
+}
+
+fn encode_into(input: &[u8], output: &mut Writer) -> Result<(), Error> {
+    for (i, pair) in input.chunks(2).enumerate() {
+        let mut x = 0;
+        for (j, &s) in pair.iter().enumerate() {
+            x = 45 * x + try!(val(s).ok_or(BadCharacter(2 * i + j))) as u32;
+        }
+        output.push(x, if pair.len() == 2 { 11 } else { 6 });
+    }
+    Ok(())
+}
+
+/// Encodes with allocation.
+///
+/// This function returns the bit stream and its number of bits.
+///
+/// # Failures
+///
+/// Returns `Error::BadCharacter` if the input contains a non-symbol.
+pub fn encode(input: &[u8]) -> Result<(Vec<u8>, usize), Error> {
+    let mut output = Writer { data: Vec::new(), len: 0 };
+    try!(encode_into(input, &mut output));
+    Ok((output.data, output.len))
+}
+
+/// Encodes a segment with allocation.
+///
+/// The segment starts with the mode indicator and the symbol count
+/// for the given version. This function returns the bit stream and its
+/// number of bits.
+///
+/// # Panics
+///
+/// Panics if `version` is not between 1 and 40 inclusive, or if the
+/// symbol count does not fit in
+/// [`count_bits(version)`](fn.count_bits.html) bits.
+///
+/// # Failures
+///
+/// Returns `Error::BadCharacter` if the input contains a non-symbol.
+pub fn encode_segment(version: u8, input: &[u8]) -> Result<(Vec<u8>, usize), Error> {
+    let n = count_bits(version);
+    assert!(input.len() < 1 << n);
+    let mut output = Writer { data: Vec::new(), len: 0 };
+    output.push(MODE as u32, 4);
+    output.push(input.len() as u32, n);
+    try!(encode_into(input, &mut output));
+    Ok((output.data, output.len))
+}
+
+fn decode_from(input: &[u8], mut pos: usize, len: usize) -> Result<(String, usize), Error> {
+    // Each symbol takes at least 5.5 bits, so the input bounds the
+    // output regardless of the claimed length.
+    let max = (8 * input.len()).saturating_sub(pos) / 5;
+    let mut output = String::with_capacity(::std::cmp::min(len, max));
+    for _ in 0 .. len / 2 {
+        let x = try!(read(input, pos, 11));
+        check!(BadCharacter(pos), x < 45 * 45);
+        output.push(SYMBOLS[x as usize / 45] as char);
+        output.push(SYMBOLS[x as usize % 45] as char);
+        pos += 11;
+    }
+    if len % 2 == 1 {
+        let x = try!(read(input, pos, 6));
+        check!(BadCharacter(pos), x < 45);
+        output.push(SYMBOLS[x as usize] as char);
+        pos += 6;
+    }
+    Ok((output, pos))
+}
+
+/// Decodes `len` symbols with allocation.
+///
+/// Bits after the encoded symbols are ignored.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadCharacter` if a group of bits encodes a value out of
+/// range. The position is the offset in bits of the group.
+/// - `Error::BadLength` if the input is too short.
+pub fn decode(input: &[u8], len: usize) -> Result<String, Error> {
+    decode_from(input, 0, len).map(|(output, _)| output)
+}
+
+/// Decodes a segment with allocation.
+///
+/// This function returns the text and the number of bits read, such
+/// that the next segment starts at this offset.
+///
+/// # Panics
+///
+/// Panics if `version` is not between 1 and 40 inclusive.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`decode`](fn.decode.html). It also returns
+/// `Error::BadCharacter(0)` if the mode indicator is not
+/// [`MODE`](constant.MODE.html).
+pub fn decode_segment(version: u8, input: &[u8]) -> Result<(String, usize), Error> {
+    let n = count_bits(version);
+    check!(BadCharacter(0), try!(read(input, 0, 4)) == MODE as u32);
+    let len = try!(read(input, 4, n)) as usize;
+    decode_from(input, 4 + n, len)
+}
@@ -0,0 +1,163 @@
+//! Quoted-Printable Encoding.
+//!
+//! Printable ascii characters, except `=`, represent themselves. Other
+//! bytes are encoded as `=` followed by their value with 2 uppercase
+//! hexadecimal digits. Spaces and tabs represent themselves, except at
+//! the end of a line where they are encoded. Encoded lines are at
+//! most 76 characters long: longer lines are split with soft line
+//! breaks, namely `=` followed by `\r\n`.
+//!
+//! In text mode, the line breaks of the input, `\n` or `\r\n`, are
+//! encoded as `\r\n`. In binary mode, all line breaks are soft and
+//! `\r` and `\n` are encoded.
+//!
+//! Decoding supports a [`Mode`](enum.Mode.html) and a
+//! [`Whitespace`](enum.Whitespace.html) policy for the whitespace at
+//! the end of lines, which may have been added in transport.
+//!
+//! # Conformance
+//!
+//! [RFC 2045](https://tools.ietf.org/html/rfc2045#section-6.7)
+//! compliant.
+
+use decode::Error;
+use decode::Error::*;
+use lines::lines;
+use tool::{hex_val, push_escape};
+
+/// The maximum length of an encoded line.
+pub const LINE: usize = 76;
+
+/// Decoding modes.
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+pub enum Mode {
+    /// Rejects non-conforming inputs.
+    ///
+    /// Lines must be terminated by `\r\n` and be at most 76 characters
+    /// long, and escapes must use uppercase hexadecimal digits.
+    Strict,
+
+    /// Accepts common deviations.
+    ///
+    /// Lines may be terminated by `\n` and be of any length, escapes
+    /// may use lowercase hexadecimal digits, and invalid escapes and
+    /// non-printable characters represent themselves.
+    Lenient,
+}
+
+/// Policies for whitespace at the end of lines.
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+pub enum Whitespace {
+    /// Removes the whitespace as required by RFC 2045.
+    Strip,
+
+    /// Keeps the whitespace.
+    Keep,
+}
+
+/// Encodes with allocation.
+///
+/// The input is encoded in text mode if `binary` is false and in
+/// binary mode otherwise.
+pub fn encode(input: &[u8], binary: bool) -> String {
+    let mut output = Vec::with_capacity(input.len() * 3 / 2);
+    let mut col = 0;
+    let mut i = 0;
+    while i < input.len() {
+        let x = input[i];
+        let eol = |j: usize| -> bool {
+            !binary && (input[j ..].starts_with(b"\n") || input[j ..].starts_with(b"\r\n"))
+        };
+        if eol(i) {
+            output.extend_from_slice(b"\r\n");
+            col = 0;
+            i += if x == b'\r' { 2 } else { 1 };
+            continue;
+        }
+        let last = i + 1 == input.len() || eol(i + 1);
+        let literal = match x {
+            b'=' => false,
+            b' ' | b'\t' => !last,
+            33 ..= 126 => true,
+            _ => false,
+        };
+        let len = if literal { 1 } else { 3 };
+        // The last character of a line may use the column of the soft
+        // line break.
+        if col + len > LINE - 1 && !(last && col + len <= LINE) {
+            output.extend_from_slice(b"=\r\n");
+            col = 0;
+        }
+        if literal {
+            output.push(x);
+        } else {
+            push_escape(&mut output, b'=', x);
+        }
+        col += len;
+        i += 1;
+    }
+    unsafe {
+        // This is valid because the output is ascii.
+        String::from_utf8_unchecked(output)
+    }
+}
+
+/// Decodes with allocation.
+///
+/// Hard line breaks are decoded as `\r\n`.
+///
+/// # Failures
+///
+/// In strict mode, decoding may fail in the following circumstances:
+///
+/// - `Error::BadLength` if a line is longer than 76 characters.
+/// - `Error::BadCharacter` if a line is terminated by `\n` only, if an
+/// escape is invalid, or if the input contains a character which is
+/// neither printable ascii, nor a space, nor a tab.
+///
+/// In lenient mode, decoding does not fail.
+pub fn decode(input: &[u8], mode: Mode, whitespace: Whitespace) -> Result<Vec<u8>, Error> {
+    let mut output = Vec::with_capacity(input.len());
+    let lines = lines(input);
+    let n = lines.len();
+    for (k, (pos, mut line)) in lines.into_iter().enumerate() {
+        if mode == Mode::Strict {
+            let end = pos + line.len();
+            check!(BadCharacter(end), k + 1 == n || input[end] == b'\r');
+            check!(BadLength, line.len() <= LINE);
+        }
+        if whitespace == Whitespace::Strip {
+            while line.ends_with(b" ") || line.ends_with(b"\t") {
+                line = &line[.. line.len() - 1];
+            }
+        }
+        let soft = line.ends_with(b"=");
+        if soft {
+            line = &line[.. line.len() - 1];
+        }
+        let mut i = 0;
+        while i < line.len() {
+            let x = line[i];
+            if x == b'=' {
+                if i + 2 < line.len() {
+                    let lower = mode == Mode::Lenient;
+                    if let (Some(h), Some(l)) = (hex_val(line[i + 1], lower),
+                                                 hex_val(line[i + 2], lower)) {
+                        output.push(h << 4 | l);
+                        i += 3;
+                        continue;
+                    }
+                }
+                check!(BadCharacter(pos + i), mode == Mode::Lenient);
+            } else if mode == Mode::Strict {
+                check!(BadCharacter(pos + i), x == b' ' || x == b'\t' || 33 <= x && x <= 126);
+            }
+            output.push(x);
+            i += 1;
+        }
+        if !soft && k + 1 < n {
+            output.extend_from_slice(b"\r\n");
+        }
+    }
+    Ok(output)
+}
@@ -0,0 +1,454 @@
+//! Generic radix module.
+//!
+//! This module defines a generic interface, namely
+//! [`Radix`](trait.Radix.html), and an optimized implementation,
+//! namely [`Opt`](struct.Opt.html), for positional numerical systems
+//! with any radix from 2 to 128 (not only powers of two). It also
+//! defines the generic encoding and decoding functions for such
+//! systems.
+//!
+//! Since the radix is not necessarily a power of two, input symbols do
+//! not map to a fixed number of bits. The input is thus seen as a
+//! big-endian number which is converted from radix 256 to the target
+//! radix. Leading zero bytes are preserved: each leading zero byte is
+//! encoded as a leading symbol of value zero, and reciprocally. This
+//! conversion is quadratic in the input length.
+//!
+//! The chunked functions, like
+//! [`encode_chunked`](fn.encode_chunked.html), split the input in
+//! chunks of at most 16 bytes which are converted independently. Their
+//! output length only depends on the input length and they are linear
+//! in the input length, at the cost of a slightly longer output.
+//!
+//! The encoding and decoding functions satisfy the same properties as
+//! the [`encode`](../encode/index.html) and
+//! [`decode`](../decode/index.html) modules: they are inverse of each
+//! other and decoding rejects non-canonical inputs.
+
+use std::marker::PhantomData;
+
+use decode::Error;
+use decode::Error::*;
+use tool::div_ceil;
+
+/// Generic interface.
+///
+/// A radix implementation defines its symbols in value order. The
+/// radix is the number of symbols. Symbols must be ascii and the
+/// radix must be between 2 and 128 inclusive.
+pub trait Radix {
+    /// Returns the radix.
+    fn radix(&self) -> usize;
+
+    /// Returns the value of a symbol.
+    ///
+    /// In other words, when `val(s)` returns:
+    ///
+    /// - `Some(v)`: `s` is a symbol with value `v`.
+    /// - `None`: `s` is not a symbol.
+    fn val(&self, x: u8) -> Option<u8>;
+
+    /// Returns the symbol of a value.
+    ///
+    /// # Panics
+    ///
+    /// May panic when input is not a value.
+    fn sym(&self, x: u8) -> u8;
+}
+
+/// Optimized implementation.
+///
+/// This implementation uses static arrays for constant-time lookup.
+/// It also uses a phantom type to enable static dispatch on demand.
+pub struct Opt<T> {
+    /// Symbol to value association.
+    ///
+    /// This array must have size 256 and defines `val(s)` as
+    /// `Some(val[s])` if `val[s] < 128` and `None` otherwise.
+    pub val: &'static [u8],
+
+    /// Value to symbol association.
+    ///
+    /// This array defines `sym(v)` as `sym[v]` and `radix()` as its
+    /// length.
+    pub sym: &'static [u8],
+
+    pub _phantom: PhantomData<T>,
+}
+
+impl<T> Radix for Opt<T> {
+    fn radix(&self) -> usize {
+        self.sym.len()
+    }
+
+    fn val(&self, x: u8) -> Option<u8> {
+        let v = self.val[x as usize];
+        if v < 128 { Some(v) } else { None }
+    }
+
+    fn sym(&self, x: u8) -> u8 {
+        self.sym[x as usize]
+    }
+}
+
+// Returns the largest number of bits a symbol always holds.
+fn floor_log2(r: usize) -> usize {
+    let mut b = 0;
+    while 2 << b <= r {
+        b += 1;
+    }
+    b
+}
+
+/// Converts an input length to its maximum output length.
+///
+/// This function is meant to be used in conjunction with
+/// [`encode_mut`](fn.encode_mut.html). The actual output length
+/// depends on the input value and is returned by
+/// [`encode_mut`](fn.encode_mut.html).
+///
+/// # Panics
+///
+/// May panic if `radix` does not satisfy the `Radix` invariants.
+pub fn encode_len<R: Radix>(radix: &R, len: usize) -> usize {
+    div_ceil(8 * len, floor_log2(radix.radix()))
+}
+
+/// Converts an input length to its maximum output length.
+///
+/// This function is meant to be used in conjunction with
+/// [`decode_mut`](fn.decode_mut.html). Since a symbol holds less than
+/// 8 bits, the maximum output length is the input length.
+pub fn decode_len<R: Radix>(_radix: &R, len: usize) -> usize {
+    len
+}
+
+/// Generic encoding function without allocation.
+///
+/// This function takes a radix implementation, a shared input slice,
+/// a mutable output slice, and encodes the input slice to the start
+/// of the output slice. It returns the length of the encoded data
+/// which may be smaller than the output length.
+///
+/// # Panics
+///
+/// Panics if `output.len() != encode_len(input.len())`. May also
+/// panic if `radix` does not satisfy the `Radix` invariants.
+pub fn encode_mut<R: Radix>(radix: &R, input: &[u8], output: &mut [u8]) -> usize {
+    assert_eq!(output.len(), encode_len(radix, input.len()));
+    let r = radix.radix();
+    let zeros = input.iter().take_while(|&&x| x == 0).count();
+    let mut n = 0;
+    {
+        // Digits are stored in little-endian order.
+        let digits = &mut output[zeros ..];
+        for &x in &input[zeros ..] {
+            let mut carry = x as usize;
+            for d in digits[.. n].iter_mut() {
+                carry += (*d as usize) << 8;
+                *d = (carry % r) as u8;
+                carry /= r;
+            }
+            while carry > 0 {
+                digits[n] = (carry % r) as u8;
+                n += 1;
+                carry /= r;
+            }
+        }
+        digits[.. n].reverse();
+    }
+    for x in output[.. zeros + n].iter_mut() {
+        *x = radix.sym(*x);
+    }
+    zeros + n
+}
+
+/// Generic encoding function with allocation.
+///
+/// This function is a wrapper for [`encode_mut`](fn.encode_mut.html)
+/// that allocates an output of sufficient size using
+/// [`encode_len`](fn.encode_len.html).
+///
+/// # Panics
+///
+/// May panic if `radix` does not satisfy the `Radix` invariants.
+pub fn encode<R: Radix>(radix: &R, input: &[u8]) -> String {
+    let mut output = vec![0u8; encode_len(radix, input.len())];
+    let len = encode_mut(radix, input, &mut output);
+    output.truncate(len);
+    unsafe {
+        // This is valid because symbols are ascii.
+        String::from_utf8_unchecked(output)
+    }
+}
+
+/// Generic decoding function without allocation.
+///
+/// This function takes a radix implementation, a shared input slice,
+/// a mutable output slice, and decodes the input slice to the start
+/// of the output slice. It returns the length of the decoded data
+/// which may be smaller than the output length.
+///
+/// # Failures
+///
+/// Decoding fails with `Error::BadCharacter` if the input contains a
+/// non-symbol.
+///
+/// # Panics
+///
+/// Panics if `output.len() != decode_len(input.len())`. May also
+/// panic if `radix` does not satisfy the `Radix` invariants.
+pub fn decode_mut<R: Radix>
+    (radix: &R, input: &[u8], output: &mut [u8]) -> Result<usize, Error>
+{
+    assert_eq!(output.len(), decode_len(radix, input.len()));
+    let r = radix.radix();
+    let zero = radix.sym(0);
+    let zeros = input.iter().take_while(|&&x| x == zero).count();
+    let mut n = 0;
+    {
+        // Bytes are stored in little-endian order.
+        let bytes = &mut output[zeros ..];
+        for (i, &x) in input[zeros ..].iter().enumerate() {
+            let mut carry = try!(radix.val(x).ok_or(BadCharacter(zeros + i))) as usize;
+            for b in bytes[.. n].iter_mut() {
+                carry += *b as usize * r;
+                *b = carry as u8;
+                carry >>= 8;
+            }
+            while carry > 0 {
+                bytes[n] = carry as u8;
+                n += 1;
+                carry >>= 8;
+            }
+        }
+        bytes[.. n].reverse();
+    }
+    for x in output[.. zeros].iter_mut() {
+        *x = 0;
+    }
+    Ok(zeros + n)
+}
+
+/// Generic decoding function with allocation.
+///
+/// This function is a wrapper for [`decode_mut`](fn.decode_mut.html)
+/// that allocates an output of sufficient size using
+/// [`decode_len`](fn.decode_len.html).
+///
+/// # Failures
+///
+/// Decoding fails with `Error::BadCharacter` if the input contains a
+/// non-symbol.
+///
+/// # Panics
+///
+/// May panic if `radix` does not satisfy the `Radix` invariants.
+pub fn decode<R: Radix>(radix: &R, input: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut output = vec![0u8; decode_len(radix, input.len())];
+    let len = try!(decode_mut(radix, input, &mut output));
+    output.truncate(len);
+    Ok(output)
+}
+
+/// Returns the number of symbols of a chunk.
+///
+/// A chunk of `len` bytes is encoded with the smallest number of
+/// symbols able to represent all numbers of `8 * len` bits.
+///
+/// # Panics
+///
+/// Panics if `len > 16`. May also panic if `radix` does not satisfy
+/// the `Radix` invariants.
+pub fn chunk_len<R: Radix>(radix: &R, len: usize) -> usize {
+    assert!(len <= 16);
+    let max = if len == 16 { !0u128 } else { (1u128 << 8 * len) - 1 };
+    let r = radix.radix() as u128;
+    let mut p = 1u128;
+    let mut w = 0;
+    while p <= max {
+        w += 1;
+        match p.checked_mul(r) {
+            Some(q) => p = q,
+            None => break,
+        }
+    }
+    w
+}
+
+fn encode_chunk<R: Radix>(radix: &R, input: &[u8], output: &mut [u8]) {
+    let r = radix.radix() as u128;
+    let mut x = 0u128;
+    for &b in input {
+        x = x << 8 | b as u128;
+    }
+    for o in output.iter_mut().rev() {
+        *o = radix.sym((x % r) as u8);
+        x /= r;
+    }
+}
+
+fn decode_chunk<R: Radix>
+    (radix: &R, input: &[u8], output: &mut [u8]) -> Result<(), Error>
+{
+    let r = radix.radix() as u128;
+    let mut x = 0u128;
+    for (i, &s) in input.iter().enumerate() {
+        let v = try!(radix.val(s).ok_or(BadCharacter(i)));
+        x = try!(x.checked_mul(r).and_then(|x| x.checked_add(v as u128))
+                 .ok_or(BadCharacter(0)));
+    }
+    check!(BadCharacter(0), output.len() == 16 || x >> 8 * output.len() == 0);
+    for o in output.iter_mut().rev() {
+        *o = x as u8;
+        x >>= 8;
+    }
+    Ok(())
+}
+
+/// Converts an input length to its output length (chunked).
+///
+/// This function is meant to be used in conjunction with
+/// [`encode_chunked_mut`](fn.encode_chunked_mut.html).
+///
+/// # Panics
+///
+/// Panics if `chunk` is not between 1 and 16 inclusive. May also
+/// panic if `radix` does not satisfy the `Radix` invariants.
+pub fn encode_chunked_len<R: Radix>(radix: &R, chunk: usize, len: usize) -> usize {
+    assert!(1 <= chunk && chunk <= 16);
+    len / chunk * chunk_len(radix, chunk) + chunk_len(radix, len % chunk)
+}
+
+/// Converts an input length to its output length (chunked).
+///
+/// This function is meant to be used in conjunction with
+/// [`decode_chunked_mut`](fn.decode_chunked_mut.html).
+///
+/// # Failures
+///
+/// Invalid input length returns `Error::BadLength`.
+///
+/// # Panics
+///
+/// Panics if `chunk` is not between 1 and 16 inclusive. May also
+/// panic if `radix` does not satisfy the `Radix` invariants.
+pub fn decode_chunked_len<R: Radix>
+    (radix: &R, chunk: usize, len: usize) -> Result<usize, Error>
+{
+    assert!(1 <= chunk && chunk <= 16);
+    let dec = chunk_len(radix, chunk);
+    let last = len % dec;
+    for k in 0 .. chunk {
+        if chunk_len(radix, k) == last {
+            return Ok(len / dec * chunk + k);
+        }
+    }
+    Err(BadLength)
+}
+
+/// Generic encoding function without allocation (chunked).
+///
+/// This function takes a radix implementation, a chunk size, a shared
+/// input slice, a mutable output slice, and encodes the input slice
+/// to the output slice. The input is split in chunks of `chunk` bytes
+/// (except the last one which may be shorter) which are encoded
+/// independently using [`chunk_len`](fn.chunk_len.html) symbols each.
+///
+/// Contrary to [`encode_mut`](fn.encode_mut.html), the output length
+/// only depends on the input length and encoding is linear in the
+/// input length. Chunks may thus be encoded as they arrive.
+///
+/// # Panics
+///
+/// Panics if `output.len() != encode_chunked_len(chunk,
+/// input.len())`. May also panic if `radix` does not satisfy the
+/// `Radix` invariants.
+pub fn encode_chunked_mut<R: Radix>
+    (radix: &R, chunk: usize, input: &[u8], output: &mut [u8])
+{
+    assert_eq!(output.len(), encode_chunked_len(radix, chunk, input.len()));
+    let dec = chunk_len(radix, chunk);
+    for (i, input) in input.chunks(chunk).enumerate() {
+        let len = chunk_len(radix, input.len());
+        encode_chunk(radix, input, &mut output[dec * i .. dec * i + len]);
+    }
+}
+
+/// Generic decoding function without allocation (chunked).
+///
+/// This function takes a radix implementation, a chunk size, a shared
+/// input slice, a mutable output slice, and decodes the input slice
+/// to the output slice. It is the inverse of
+/// [`encode_chunked_mut`](fn.encode_chunked_mut.html).
+///
+/// # Failures
+///
+/// Decoding fails with `Error::BadLength` if the input length is not
+/// a valid encoded length, and with `Error::BadCharacter` if the
+/// input contains a non-symbol or if a chunk does not fit in its
+/// bytes (the position is then the one of the first symbol of the
+/// chunk).
+///
+/// # Panics
+///
+/// Panics if `output.len() != decode_chunked_len(chunk,
+/// input.len()).unwrap()`. May also panic if `radix` does not
+/// satisfy the `Radix` invariants.
+pub fn decode_chunked_mut<R: Radix>
+    (radix: &R, chunk: usize, input: &[u8], output: &mut [u8]) -> Result<(), Error>
+{
+    let olen = try!(decode_chunked_len(radix, chunk, input.len()));
+    assert_eq!(output.len(), olen);
+    let dec = chunk_len(radix, chunk);
+    for (i, output) in output.chunks_mut(chunk).enumerate() {
+        let len = chunk_len(radix, output.len());
+        try!(decode_chunk(radix, &input[dec * i .. dec * i + len], output)
+             .map_err(|e| e.shift(dec * i)));
+    }
+    Ok(())
+}
+
+/// Generic encoding function with allocation (chunked).
+///
+/// This function is a wrapper for
+/// [`encode_chunked_mut`](fn.encode_chunked_mut.html) that allocates
+/// an output of the correct size using
+/// [`encode_chunked_len`](fn.encode_chunked_len.html).
+///
+/// # Panics
+///
+/// Panics if `chunk` is not between 1 and 16 inclusive. May also
+/// panic if `radix` does not satisfy the `Radix` invariants.
+pub fn encode_chunked<R: Radix>(radix: &R, chunk: usize, input: &[u8]) -> String {
+    let mut output = vec![0u8; encode_chunked_len(radix, chunk, input.len())];
+    encode_chunked_mut(radix, chunk, input, &mut output);
+    unsafe {
+        // This is valid because symbols are ascii.
+        String::from_utf8_unchecked(output)
+    }
+}
+
+/// Generic decoding function with allocation (chunked).
+///
+/// This function is a wrapper for
+/// [`decode_chunked_mut`](fn.decode_chunked_mut.html) that allocates
+/// an output of the correct size using
+/// [`decode_chunked_len`](fn.decode_chunked_len.html).
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`decode_chunked_mut`](fn.decode_chunked_mut.html).
+///
+/// # Panics
+///
+/// Panics if `chunk` is not between 1 and 16 inclusive. May also
+/// panic if `radix` does not satisfy the `Radix` invariants.
+pub fn decode_chunked<R: Radix>
+    (radix: &R, chunk: usize, input: &[u8]) -> Result<Vec<u8>, Error>
+{
+    let mut output = vec![0u8; try!(decode_chunked_len(radix, chunk, input.len()))];
+    try!(decode_chunked_mut(radix, chunk, input, &mut output));
+    Ok(output)
+}
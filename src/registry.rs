@@ -0,0 +1,144 @@
+//! Named encoding registry module.
+//!
+//! This module defines a [`Registry`](struct.Registry.html) type,
+//! mapping names to [`Encoding`](../specification/struct.Encoding.html)
+//! values, with or without padding. The
+//! [`standard`](struct.Registry.html#method.standard) registry knows
+//! the common encodings by the name of their module, with a `-nopad`
+//! suffix for their unpadded variant. Applications may register their
+//! own encodings at runtime, for instance from
+//! [descriptors](../specification/struct.Specification.html#method.from_str).
+//!
+//! # Examples
+//!
+//! ```
+//! use data_encoding::registry::Registry;
+//! let mut registry = Registry::standard();
+//! assert_eq!(registry.get("base32hex-nopad").unwrap().encode(b"f"), "CO");
+//! registry.register("bin", "symbols=01".parse().unwrap(), false);
+//! assert_eq!(registry.get("bin").unwrap().decode(b"01100110").unwrap(), b"f");
+//! ```
+
+use std::collections::HashMap;
+
+use base::Base;
+use decode::Error;
+use specification::{Encoding, Specification};
+
+/// Registry entry.
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub struct Entry {
+    /// The encoding.
+    pub encoding: Encoding,
+
+    /// Whether the encoding is padded.
+    pub padding: bool,
+}
+
+impl Entry {
+    /// Encodes with allocation.
+    ///
+    /// This is the [`encode`] or [`encode_nopad`] method of the
+    /// encoding, according to padding.
+    ///
+    /// [`encode`]: ../specification/struct.Encoding.html#method.encode
+    /// [`encode_nopad`]: ../specification/struct.Encoding.html#method.encode_nopad
+    pub fn encode(&self, input: &[u8]) -> String {
+        if self.padding { self.encoding.encode(input) } else { self.encoding.encode_nopad(input) }
+    }
+
+    /// Decodes with allocation.
+    ///
+    /// This is the [`decode`] or [`decode_nopad`] method of the
+    /// encoding, according to padding.
+    ///
+    /// # Failures
+    ///
+    /// Decoding may fail in the circumstances defined by
+    /// [`Error`](../decode/enum.Error.html).
+    ///
+    /// [`decode`]: ../specification/struct.Encoding.html#method.decode
+    /// [`decode_nopad`]: ../specification/struct.Encoding.html#method.decode_nopad
+    pub fn decode(&self, input: &[u8]) -> Result<Vec<u8>, Error> {
+        if self.padding { self.encoding.decode(input) } else { self.encoding.decode_nopad(input) }
+    }
+}
+
+/// Named encoding registry.
+#[derive(Clone,Debug,Default)]
+pub struct Registry {
+    entries: HashMap<String, Entry>,
+}
+
+// Builds the encoding of a valid ascii base.
+fn encoding<B: Base + ?Sized>(base: &B) -> Encoding {
+    let mut spec = Specification::new();
+    spec.symbols = (0 .. 1 << base.bit()).map(|v| base.sym(v as u8) as char).collect();
+    spec.padding = base.pad() as char;
+    spec.bit_order = base.bit_order();
+    spec.encoding().unwrap()
+}
+
+impl Registry {
+    /// Returns an empty registry.
+    pub fn new() -> Registry {
+        Registry { entries: HashMap::new() }
+    }
+
+    /// Returns the standard registry.
+    ///
+    /// The registry contains `base2`, `base4`, `base8`, `base16`,
+    /// `hex` (lowercase and accepting uppercase), `base32`,
+    /// `base32hex`, `zbase32`, `dnscurve`, `base64`, and `base64url`.
+    /// The padded encodings among them are also registered with a
+    /// `-nopad` suffix.
+    pub fn standard() -> Registry {
+        let mut registry = Registry::new();
+        let unpadded: [(&str, &dyn Base); 6] = [
+            ("base2", ::base2::base()), ("base4", ::base4::base()),
+            ("base8", ::base8::base()), ("base16", ::base16::base()),
+            ("zbase32", ::zbase32::base()), ("dnscurve", ::dnscurve::base()),
+        ];
+        for &(name, base) in &unpadded {
+            let _ = registry.register(name, encoding(base), false);
+        }
+        let hex = encoding(::hexlower::base()).translate("ABCDEF", "abcdef").unwrap();
+        let _ = registry.register("hex", hex, false);
+        let padded: [(&str, &dyn Base); 4] = [
+            ("base32", ::base32::base()), ("base32hex", ::base32hex::base()),
+            ("base64", ::base64::base()), ("base64url", ::base64url::base()),
+        ];
+        for &(name, base) in &padded {
+            let _ = registry.register(name, encoding(base), true);
+            let _ = registry.register(&format!("{}-nopad", name), encoding(base), false);
+        }
+        registry
+    }
+
+    /// Registers an encoding.
+    ///
+    /// The previous entry of this name, if any, is replaced and
+    /// returned.
+    pub fn register(&mut self, name: &str, encoding: Encoding, padding: bool) -> Option<Entry> {
+        self.entries.insert(name.to_string(), Entry { encoding: encoding, padding: padding })
+    }
+
+    /// Unregisters an encoding.
+    ///
+    /// The entry of this name, if any, is returned.
+    pub fn unregister(&mut self, name: &str) -> Option<Entry> {
+        self.entries.remove(name)
+    }
+
+    /// Returns the entry of a name.
+    pub fn get(&self, name: &str) -> Option<&Entry> {
+        self.entries.get(name)
+    }
+
+    /// Returns the registered names in lexicographic order.
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.entries.keys().map(|n| n as &str).collect();
+        names.sort();
+        names
+    }
+}
@@ -0,0 +1,1341 @@
+//! Runtime specification module.
+//!
+//! This module defines a [`Specification`](struct.Specification.html)
+//! type, describing a base by its symbols in value order, its padding,
+//! its bit order, and its decoding translations, and an
+//! [`Encoding`](struct.Encoding.html) type built from a specification.
+//! Unlike the bases of the other modules, specifications are plain
+//! data: they may be built at runtime, for instance from a
+//! configuration file, without macros or generics.
+//!
+//! An encoding implements the [`Base`](../base/trait.Base.html)
+//! interface and is valid by construction. It works with all the
+//! generic functions of the [`encode`](../encode/index.html) and
+//! [`decode`](../decode/index.html) modules. Encodings may also be
+//! built at compile time, in `const` and `static` items, with the
+//! [`Encoding::new`](struct.Encoding.html#method.new) const function
+//! or the [`new_encoding!`](../macro.new_encoding.html) macro.
+//!
+//! # Examples
+//!
+//! ```
+//! use data_encoding::specification::Specification;
+//! let mut spec = Specification::new();
+//! spec.symbols.push_str("0123456789abcdef");
+//! let hex = spec.encoding().unwrap();
+//! assert_eq!(data_encoding::encode::encode(&hex, b"\x01\xab"), "01ab");
+//! assert_eq!(hex.decode(b"01ab").unwrap(), b"\x01\xab");
+//! ```
+//!
+//! The RFC 4648 encodings are also provided as constants:
+//!
+//! ```
+//! use data_encoding::specification::{BASE32, BASE64};
+//! assert_eq!(BASE64.encode(b"foobar"), "Zm9vYmFy");
+//! assert_eq!(BASE32.decode(b"MZXW6===").unwrap(), b"foo");
+//! ```
+
+use std::{error, fmt, str};
+
+use base::{Base, BitOrder, enc, dec};
+use decode::Error;
+
+/// Builds an encoding at compile time.
+///
+/// The `symbols` field is required, the `padding` and `bit_order`
+/// fields are optional and default to `'='` and
+/// `MostSignificantFirst`. The expansion is a constant
+/// [`Encoding`](specification/struct.Encoding.html): an invalid
+/// specification is a compilation error.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use]
+/// extern crate data_encoding;
+/// use data_encoding::specification::Encoding;
+/// const HEX: Encoding = new_encoding!{
+///     symbols: "0123456789abcdef",
+/// };
+/// const DNSCURVE: Encoding = new_encoding!{
+///     symbols: "0123456789bcdfghjklmnpqrstuvwxyz",
+///     bit_order: LeastSignificantFirst,
+/// };
+/// # fn main() {
+/// assert_eq!(data_encoding::encode::encode(&HEX, b"\x01\xab"), "01ab");
+/// assert_eq!(data_encoding::encode::encode_nopad(&DNSCURVE, b"f"), "63");
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// #[macro_use]
+/// extern crate data_encoding;
+/// const HEX: data_encoding::specification::Encoding = new_encoding!{
+///     symbols: "0123456789abcdeff",
+/// };
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! new_encoding {
+    (@imp $s: expr, $p: expr, $o: ident) => {{
+        const ENCODING: $crate::specification::Encoding =
+            match $crate::specification::Encoding::new($s, $p, $crate::base::BitOrder::$o) {
+                Ok(e) => e,
+                Err(e) => e.panic(),
+            };
+        ENCODING
+    }};
+    (symbols: $s: expr, padding: $p: expr, bit_order: $o: ident $(,)*) => {
+        $crate::new_encoding!(@imp $s, $p, $o)
+    };
+    (symbols: $s: expr, padding: $p: expr $(,)*) => {
+        $crate::new_encoding!(@imp $s, $p, MostSignificantFirst)
+    };
+    (symbols: $s: expr, bit_order: $o: ident $(,)*) => {
+        $crate::new_encoding!(@imp $s, '=', $o)
+    };
+    (symbols: $s: expr $(,)*) => {
+        $crate::new_encoding!(@imp $s, '=', MostSignificantFirst)
+    };
+}
+
+/// Base specification.
+#[derive(Clone,Debug,PartialEq,Eq,Hash)]
+pub struct Specification {
+    /// The symbols in value order.
+    ///
+    /// There must be 2, 4, 8, 16, 32, or 64 symbols. Symbols
+    /// must be ascii and distinct.
+    pub symbols: String,
+
+    /// The padding.
+    ///
+    /// The padding must be ascii and must not be a symbol. It is not
+    /// restricted to `=`: legacy formats using `-` or `*` as filler
+    /// are supported. The same character is produced when encoding
+    /// and expected when decoding.
+    pub padding: char,
+
+    /// The bit order.
+    pub bit_order: BitOrder,
+
+    /// The translations accepted by decoding.
+    pub translate: Translate,
+
+    /// Whether decoding checks the trailing bits.
+    ///
+    /// See [`Base::check_trailing_bits`] for details.
+    ///
+    /// [`Base::check_trailing_bits`]: ../base/trait.Base.html#method.check_trailing_bits
+    pub check_trailing_bits: bool,
+
+    /// The characters ignored by decoding.
+    ///
+    /// These characters must be ascii and must not be symbols,
+    /// padding, nor translated. They are skipped by the
+    /// [`decode`](struct.Encoding.html#method.decode) method of the
+    /// encoding, for instance to accept wrapped input.
+    pub ignore: String,
+
+    /// The wrapping of encoded output.
+    pub wrap: Wrap,
+}
+
+/// Encoding wrapping.
+///
+/// The [`encode`](struct.Encoding.html#method.encode) method of the
+/// encoding writes `separator` after each `width` symbols and after
+/// the last line, as described by
+/// [`encode_wrap`](../encode/fn.encode_wrap.html). A width of zero
+/// disables wrapping.
+#[derive(Clone,Debug,PartialEq,Eq,Hash)]
+pub struct Wrap {
+    /// The number of symbols per line.
+    ///
+    /// The width must be a multiple of the decoding length, for
+    /// instance 4 for `base64` and 8 for `base32`.
+    pub width: usize,
+
+    /// The line separator.
+    ///
+    /// The separator must be ascii and at most 8 characters long.
+    pub separator: String,
+}
+
+/// Decoding translations.
+///
+/// Decoding maps each character of `from` to the symbol at the same
+/// position in `to`, for instance lowercase to uppercase. Encoding is
+/// not affected.
+#[derive(Clone,Debug,PartialEq,Eq,Hash)]
+pub struct Translate {
+    /// The characters to translate.
+    ///
+    /// These characters must be ascii and must not be symbols nor
+    /// padding. They must be distinct.
+    pub from: String,
+
+    /// The symbols they are translated to.
+    ///
+    /// There must be as many symbols as characters to translate.
+    pub to: String,
+}
+
+/// Specification errors.
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+pub enum SpecificationError {
+    /// The number of symbols is not 2, 4, 8, 16, 32, or 64.
+    BadSize,
+
+    /// A symbol is not ascii.
+    NotAscii(char),
+
+    /// A symbol appears twice.
+    Duplicate(char),
+
+    /// The padding is not ascii.
+    PadNotAscii,
+
+    /// The padding is a symbol.
+    PadSymbol,
+
+    /// The translation has not as many characters as symbols.
+    BadTranslate,
+
+    /// A translation is not to a symbol.
+    NotSymbol(char),
+
+    /// The wrapping width is not a multiple of the decoding length, or
+    /// the separator is not ascii or too long.
+    BadWrap,
+
+    /// The descriptor is malformed at this byte offset.
+    Syntax(usize),
+}
+
+impl fmt::Display for SpecificationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::SpecificationError::*;
+        match self {
+            &BadSize => write!(f, "Size is not 2, 4, 8, 16, 32, or 64."),
+            &NotAscii(s) => write!(f, "Symbol {:?} is not ascii.", s),
+            &Duplicate(s) => write!(f, "Symbol {:?} is duplicated.", s),
+            &PadNotAscii => write!(f, "Padding is not ascii."),
+            &PadSymbol => write!(f, "Padding is a symbol."),
+            &BadTranslate => write!(f, "Translation lengths differ."),
+            &NotSymbol(s) => write!(f, "Translation to {:?} is not a symbol.", s),
+            &BadWrap => write!(f, "Wrapping is invalid."),
+            &Syntax(p) => write!(f, "Descriptor is malformed at offset {}.", p),
+        }
+    }
+}
+
+impl error::Error for SpecificationError {
+    fn description(&self) -> &str {
+        use self::SpecificationError::*;
+        match self {
+            &BadSize => "size must be 2, 4, 8, 16, 32, or 64",
+            &NotAscii(_) => "symbols must be ascii",
+            &Duplicate(_) => "symbols must be distinct",
+            &PadNotAscii => "padding must be ascii",
+            &PadSymbol => "padding must not be a symbol",
+            &BadTranslate => "translation lengths must be equal",
+            &NotSymbol(_) => "translations must be to symbols",
+            &BadWrap => "wrapping must be valid",
+            &Syntax(_) => "descriptor must be well-formed",
+        }
+    }
+}
+
+impl SpecificationError {
+    /// Panics with the description of the error.
+    ///
+    /// This function is a `const fn`: when evaluated at compile time,
+    /// the panic is a compilation error.
+    pub const fn panic(self) -> ! {
+        use self::SpecificationError::*;
+        match self {
+            BadSize => panic!("Size is not 2, 4, 8, 16, 32, or 64."),
+            NotAscii(_) => panic!("Symbol is not ascii."),
+            Duplicate(_) => panic!("Symbol is duplicated."),
+            PadNotAscii => panic!("Padding is not ascii."),
+            PadSymbol => panic!("Padding is a symbol."),
+            BadTranslate => panic!("Translation lengths differ."),
+            NotSymbol(_) => panic!("Translation is not to a symbol."),
+            BadWrap => panic!("Wrapping is invalid."),
+            Syntax(_) => panic!("Descriptor is malformed."),
+        }
+    }
+}
+
+impl Specification {
+    /// Returns an empty specification.
+    ///
+    /// The specification has no symbols, `=` as padding, most
+    /// significant bit first order, no translations, checks trailing
+    /// bits, and has no ignored characters nor wrapping.
+    pub const fn new() -> Specification {
+        Specification {
+            symbols: String::new(),
+            padding: '=',
+            bit_order: BitOrder::MostSignificantFirst,
+            translate: Translate { from: String::new(), to: String::new() },
+            check_trailing_bits: true,
+            ignore: String::new(),
+            wrap: Wrap { width: 0, separator: String::new() },
+        }
+    }
+
+    /// Builds the encoding of a specification.
+    ///
+    /// # Failures
+    ///
+    /// Returns an error if the specification is not valid, as
+    /// described by [`SpecificationError`](enum.SpecificationError.html).
+    pub fn encoding(&self) -> Result<Encoding, SpecificationError> {
+        let encoding = try!(Encoding::new(&self.symbols, self.padding, self.bit_order));
+        let encoding = try!(encoding.translate(&self.translate.from, &self.translate.to));
+        let encoding = try!(encoding.ignore(&self.ignore));
+        let encoding = try!(encoding.wrap(self.wrap.width, &self.wrap.separator));
+        Ok(encoding.trailing_bits(self.check_trailing_bits))
+    }
+}
+
+// Writes a quoted string with escapes.
+fn quote(f: &mut fmt::Formatter, input: &str) -> fmt::Result {
+    try!(write!(f, "\""));
+    for c in input.chars() {
+        try!(match c {
+            '"' => write!(f, "\\\""),
+            '\\' => write!(f, "\\\\"),
+            '\n' => write!(f, "\\n"),
+            '\r' => write!(f, "\\r"),
+            '\t' => write!(f, "\\t"),
+            c => write!(f, "{}", c),
+        });
+    }
+    write!(f, "\"")
+}
+
+impl fmt::Display for Specification {
+    /// Formats a specification as a descriptor.
+    ///
+    /// The descriptor is parsed back by
+    /// [`from_str`](#method.from_str). Fields with their default value
+    /// are omitted, except symbols and padding.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "symbols="));
+        try!(quote(f, &self.symbols));
+        try!(write!(f, "; padding="));
+        let mut padding = [0u8; 4];
+        try!(quote(f, self.padding.encode_utf8(&mut padding)));
+        if self.bit_order == BitOrder::LeastSignificantFirst {
+            try!(write!(f, "; bit_order=lsb"));
+        }
+        if !self.translate.from.is_empty() || !self.translate.to.is_empty() {
+            try!(write!(f, "; translate="));
+            try!(quote(f, &self.translate.from));
+            try!(write!(f, ":"));
+            try!(quote(f, &self.translate.to));
+        }
+        if !self.check_trailing_bits {
+            try!(write!(f, "; check_trailing_bits=false"));
+        }
+        if !self.ignore.is_empty() {
+            try!(write!(f, "; ignore="));
+            try!(quote(f, &self.ignore));
+        }
+        if self.wrap.width != 0 || !self.wrap.separator.is_empty() {
+            try!(write!(f, "; wrap={}:", self.wrap.width));
+            try!(quote(f, &self.wrap.separator));
+        }
+        Ok(())
+    }
+}
+
+// Descriptor parser. Positions are byte offsets in the descriptor.
+struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).cloned()
+    }
+
+    fn space(&mut self) {
+        while self.peek().map_or(false, |x| x == b' ' || x == b'\t') {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, x: u8) -> Result<(), SpecificationError> {
+        self.space();
+        check!(SpecificationError::Syntax(self.pos), self.peek() == Some(x));
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn key(&mut self) -> &'a [u8] {
+        self.space();
+        let start = self.pos;
+        while self.peek().map_or(false, |x| x == b'_' || x.is_ascii_alphanumeric()) {
+            self.pos += 1;
+        }
+        &self.input[start .. self.pos]
+    }
+
+    // Parses a quoted or bare token, with backslash escapes.
+    fn token(&mut self) -> Result<String, SpecificationError> {
+        use self::SpecificationError::*;
+        self.space();
+        let quote = match self.peek() {
+            Some(q) if q == b'"' || q == b'\'' => { self.pos += 1; Some(q) }
+            _ => None,
+        };
+        let start = self.pos;
+        let mut output = Vec::new();
+        loop {
+            let x = match (self.peek(), quote) {
+                (None, None) => break,
+                (None, Some(_)) => return Err(Syntax(self.pos)),
+                (Some(x), Some(q)) if x == q => { self.pos += 1; break }
+                (Some(x), None) if b" \t;:=\"'".contains(&x) => break,
+                (Some(x), _) => x,
+            };
+            self.pos += 1;
+            if x != b'\\' {
+                output.push(x);
+                continue;
+            }
+            let y = try!(self.peek().ok_or(Syntax(self.pos)));
+            output.push(match y {
+                b'n' => b'\n',
+                b'r' => b'\r',
+                b't' => b'\t',
+                y if y.is_ascii_punctuation() || y == b' ' => y,
+                _ => return Err(Syntax(self.pos)),
+            });
+            self.pos += 1;
+        }
+        check!(Syntax(start), quote.is_some() || !output.is_empty());
+        // This is valid because the input is a string split at ascii.
+        Ok(String::from_utf8(output).unwrap())
+    }
+}
+
+impl str::FromStr for Specification {
+    type Err = SpecificationError;
+
+    /// Parses a specification from a descriptor.
+    ///
+    /// A descriptor is a list of `key=value` fields separated by `;`.
+    /// Values are bare or quoted, with `'` or `"`, and accept the
+    /// `\n`, `\r`, and `\t` escapes and a backslash before any
+    /// punctuation or space. Bare values end at whitespace or
+    /// punctuation among `;:='"`. The fields are:
+    ///
+    /// - `symbols=SYMBOLS`
+    /// - `padding=PADDING`, a single character
+    /// - `bit_order=msb` or `bit_order=lsb`
+    /// - `translate=FROM:TO`
+    /// - `check_trailing_bits=true` or `check_trailing_bits=false`
+    /// - `ignore=CHARACTERS`
+    /// - `wrap=WIDTH:SEPARATOR`
+    ///
+    /// Missing fields have the value of
+    /// [`Specification::new`](#method.new).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_encoding::specification::Encoding;
+    /// let mime: Encoding = "symbols=ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/; \
+    ///                       padding='='; ignore=\"\\r\\n\"; wrap=76:\\r\\n".parse().unwrap();
+    /// assert_eq!(mime.encode(b"foo"), "Zm9v\r\n");
+    /// assert_eq!(mime.decode(b"Zm9v\r\nYmFy\r\n").unwrap(), b"foobar");
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// Returns `SpecificationError::Syntax` if the descriptor is
+    /// malformed or has an unknown key. The specification itself is
+    /// not validated.
+    fn from_str(input: &str) -> Result<Specification, SpecificationError> {
+        use self::SpecificationError::*;
+        let mut parser = Parser { input: input.as_bytes(), pos: 0 };
+        let mut spec = Specification::new();
+        loop {
+            parser.space();
+            if parser.peek() == None { break; }
+            let start = parser.pos;
+            let key = parser.key();
+            try!(parser.expect(b'='));
+            let value = parser.pos;
+            match key {
+                b"symbols" => spec.symbols = try!(parser.token()),
+                b"padding" => {
+                    let padding = try!(parser.token());
+                    let mut chars = padding.chars();
+                    spec.padding = try!(chars.next().ok_or(Syntax(value)));
+                    check!(Syntax(value), chars.next() == None);
+                }
+                b"bit_order" => spec.bit_order = match &try!(parser.token()) as &str {
+                    "msb" => BitOrder::MostSignificantFirst,
+                    "lsb" => BitOrder::LeastSignificantFirst,
+                    _ => return Err(Syntax(value)),
+                },
+                b"translate" => {
+                    spec.translate.from = try!(parser.token());
+                    try!(parser.expect(b':'));
+                    spec.translate.to = try!(parser.token());
+                }
+                b"check_trailing_bits" => spec.check_trailing_bits = match &try!(parser.token()) as &str {
+                    "true" => true,
+                    "false" => false,
+                    _ => return Err(Syntax(value)),
+                },
+                b"ignore" => spec.ignore = try!(parser.token()),
+                b"wrap" => {
+                    spec.wrap.width = try!(try!(parser.token()).parse().map_err(|_| Syntax(value)));
+                    try!(parser.expect(b':'));
+                    spec.wrap.separator = try!(parser.token());
+                }
+                _ => return Err(Syntax(start)),
+            }
+            parser.space();
+            if parser.peek() == None { break; }
+            try!(parser.expect(b';'));
+        }
+        Ok(spec)
+    }
+}
+
+impl str::FromStr for Encoding {
+    type Err = SpecificationError;
+
+    /// Parses an encoding from a descriptor.
+    ///
+    /// See [`Specification::from_str`] for the descriptor syntax.
+    ///
+    /// # Failures
+    ///
+    /// Returns an error if the descriptor is malformed or the
+    /// specification is not valid.
+    ///
+    /// [`Specification::from_str`]: struct.Specification.html#method.from_str
+    fn from_str(input: &str) -> Result<Encoding, SpecificationError> {
+        let spec: Specification = try!(input.parse());
+        spec.encoding()
+    }
+}
+
+/// Runtime implementation.
+///
+/// This implementation uses arrays for constant-time lookup, like
+/// [`Opt`](../base/struct.Opt.html), but owns them. It is built from
+/// a [`Specification`](struct.Specification.html). Equality compares
+/// the tables, so two encodings are equal if and only if the
+/// specifications returned by their `specification` method are equal.
+/// Specifications that only list translations or ignored characters in
+/// a different order are not equal but build equal encodings.
+#[derive(Clone,PartialEq,Eq,Hash)]
+pub struct Encoding {
+    val: [u8; 256],
+    dec: [u8; 256],
+    sym: [u8; 64],
+    bit: u8,
+    pad: u8,
+    order: BitOrder,
+    check: bool,
+    width: usize,
+    sep: [u8; 8],
+    sep_len: usize,
+}
+
+// Value of the ignored characters in the decoding table.
+const IGNORE: u8 = 129;
+
+// Decodes the character starting at `input[i]`.
+const fn utf8(input: &[u8], i: usize) -> char {
+    let x = input[i] as u32;
+    let (n, mut c) = if x >= 0xf0 { (3, x & 0x07) }
+        else if x >= 0xe0 { (2, x & 0x0f) } else { (1, x & 0x1f) };
+    let mut j = 1;
+    while j <= n {
+        c = c << 6 | input[i + j] as u32 & 0x3f;
+        j += 1;
+    }
+    match char::from_u32(c) {
+        Some(c) => c,
+        None => char::REPLACEMENT_CHARACTER,
+    }
+}
+
+impl Encoding {
+    /// Builds an encoding from its symbols, padding, and bit order.
+    ///
+    /// This function behaves like
+    /// [`Specification::encoding`](struct.Specification.html#method.encoding)
+    /// but is a `const fn`: it may be evaluated in a `const` or
+    /// `static` initializer, in which case the tables are computed
+    /// and validated at compile time and no runtime initialization is
+    /// needed. The [`panic`](enum.SpecificationError.html#method.panic)
+    /// function turns an error into a compilation error in such
+    /// context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_encoding::base::BitOrder;
+    /// use data_encoding::specification::Encoding;
+    /// static HEX: Encoding =
+    ///     match Encoding::new("0123456789abcdef", '=', BitOrder::MostSignificantFirst) {
+    ///         Ok(e) => e,
+    ///         Err(e) => e.panic(),
+    ///     };
+    /// assert_eq!(data_encoding::encode::encode(&HEX, b"\x01\xab"), "01ab");
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// Returns an error if the specification is not valid, as
+    /// described by [`SpecificationError`](enum.SpecificationError.html).
+    pub const fn new(symbols: &str, padding: char, bit_order: BitOrder)
+                     -> Result<Encoding, SpecificationError> {
+        use self::SpecificationError::*;
+        let symbols = symbols.as_bytes();
+        let mut val = [128u8; 256];
+        let mut sym = [0u8; 64];
+        let mut n = 0;
+        while n < symbols.len() {
+            let s = symbols[n];
+            check!(NotAscii(utf8(symbols, n)), s < 128);
+            check!(Duplicate(s as char), val[s as usize] == 128);
+            check!(BadSize, n < 64);
+            val[s as usize] = n as u8;
+            sym[n] = s;
+            n += 1;
+        }
+        let bit = match n {
+            2 => 1, 4 => 2, 8 => 3, 16 => 4, 32 => 5, 64 => 6,
+            _ => return Err(BadSize),
+        };
+        check!(PadNotAscii, (padding as u32) < 128);
+        check!(PadSymbol, val[padding as usize] == 128);
+        Ok(Encoding {
+            val: val, dec: val, sym: sym, bit: bit, pad: padding as u8, order: bit_order,
+            check: true, width: 0, sep: [0; 8], sep_len: 0,
+        })
+    }
+
+    /// Adds decoding translations to an encoding.
+    ///
+    /// Each character of `from` is decoded as the symbol at the same
+    /// position in `to`, as described by
+    /// [`Translate`](struct.Translate.html). Translations only apply
+    /// to the decoding methods of the encoding: the generic functions
+    /// of the [`decode`](../decode/index.html) module only accept
+    /// symbols. Like [`new`](#method.new), this function is a `const
+    /// fn`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_encoding::specification::BASE16;
+    /// let hex = BASE16.translate("abcdef", "ABCDEF").unwrap();
+    /// assert_eq!(hex.decode(b"01aB").unwrap(), b"\x01\xab");
+    /// assert_eq!(hex.encode(b"\x01\xab"), "01AB");
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// Returns an error if the translation is not valid, as described
+    /// by [`SpecificationError`](enum.SpecificationError.html).
+    pub const fn translate(mut self, from: &str, to: &str)
+                           -> Result<Encoding, SpecificationError> {
+        use self::SpecificationError::*;
+        let from = from.as_bytes();
+        let to = to.as_bytes();
+        let mut i = 0;
+        while i < from.len() {
+            check!(NotAscii(utf8(from, i)), from[i] < 128);
+            i += 1;
+        }
+        let mut i = 0;
+        while i < to.len() {
+            check!(NotAscii(utf8(to, i)), to[i] < 128);
+            i += 1;
+        }
+        check!(BadTranslate, from.len() == to.len());
+        let mut i = 0;
+        while i < from.len() {
+            let (f, t) = (from[i], to[i]);
+            check!(Duplicate(f as char), self.dec[f as usize] == 128);
+            check!(PadSymbol, f != self.pad);
+            check!(NotSymbol(t as char), self.val[t as usize] < 128);
+            self.dec[f as usize] = self.val[t as usize];
+            i += 1;
+        }
+        Ok(self)
+    }
+
+    /// Sets whether decoding checks the trailing bits.
+    ///
+    /// Encodings check trailing bits by default, which only accepts
+    /// canonical input. Lenient decoding ignores them instead, for
+    /// interoperability with encoders that do not clear them. See
+    /// [`Base::check_trailing_bits`] for details. Like
+    /// [`new`](#method.new), this function is a `const fn`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_encoding::specification::BASE64;
+    /// assert!(BASE64.decode(b"Zh==").is_err());
+    /// assert_eq!(BASE64.trailing_bits(false).decode(b"Zh==").unwrap(), b"f");
+    /// ```
+    ///
+    /// [`Base::check_trailing_bits`]: ../base/trait.Base.html#method.check_trailing_bits
+    pub const fn trailing_bits(mut self, check: bool) -> Encoding {
+        self.check = check;
+        self
+    }
+
+    /// Adds ignored characters to an encoding.
+    ///
+    /// The characters of `ignore` are skipped by the
+    /// [`decode`](#method.decode) method, as described by
+    /// [`decode_ignore`](../decode/fn.decode_ignore.html). Like
+    /// [`new`](#method.new), this function is a `const fn`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_encoding::specification::BASE64;
+    /// let mime = BASE64.ignore("\r\n").unwrap();
+    /// assert_eq!(mime.decode(b"Zm9v\r\nYmFy\r\n").unwrap(), b"foobar");
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// Returns an error if a character is not ascii, or is a symbol, a
+    /// translated character, padding, or ignored twice.
+    pub const fn ignore(mut self, ignore: &str) -> Result<Encoding, SpecificationError> {
+        use self::SpecificationError::*;
+        let ignore = ignore.as_bytes();
+        let mut i = 0;
+        while i < ignore.len() {
+            let x = ignore[i];
+            check!(NotAscii(utf8(ignore, i)), x < 128);
+            check!(Duplicate(x as char), self.dec[x as usize] == 128);
+            check!(PadSymbol, x != self.pad);
+            self.dec[x as usize] = IGNORE;
+            i += 1;
+        }
+        Ok(self)
+    }
+
+    /// Sets the wrapping of an encoding.
+    ///
+    /// The [`encode`](#method.encode), [`encode_len`](#method.encode_len),
+    /// and [`encode_mut`](#method.encode_mut) methods then behave like
+    /// their wrapped version, as described by
+    /// [`Wrap`](struct.Wrap.html). A width of zero disables wrapping.
+    /// Like [`new`](#method.new), this function is a `const fn`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use data_encoding::specification::BASE64;
+    /// let mime = BASE64.wrap(8, "\r\n").unwrap();
+    /// assert_eq!(mime.encode(b"foobarbaz"), "Zm9vYmFy\r\nYmF6\r\n");
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// Returns `SpecificationError::BadWrap` if the width is not a
+    /// multiple of the decoding length, or if the separator is not
+    /// ascii or longer than 8 characters.
+    pub const fn wrap(mut self, width: usize, separator: &str)
+                      -> Result<Encoding, SpecificationError> {
+        use self::SpecificationError::*;
+        let separator = separator.as_bytes();
+        let dec = match self.bit { 1 | 2 | 4 => 8, 3 | 6 => 24, 5 => 40, _ => 56 } / self.bit as usize;
+        check!(BadWrap, width % dec == 0);
+        check!(BadWrap, separator.len() <= 8);
+        let mut i = 0;
+        while i < separator.len() {
+            check!(BadWrap, separator[i] < 128);
+            self.sep[i] = separator[i];
+            i += 1;
+        }
+        while i < 8 {
+            self.sep[i] = 0;
+            i += 1;
+        }
+        self.width = width;
+        self.sep_len = separator.len();
+        Ok(self)
+    }
+
+    fn separator(&self) -> &str {
+        unsafe {
+            // This is valid because the separator is ascii.
+            str::from_utf8_unchecked(&self.sep[.. self.sep_len])
+        }
+    }
+
+    fn is_ignored(&self, x: u8) -> bool {
+        self.dec[x as usize] == IGNORE
+    }
+
+    // Returns whether the input contains ignored characters.
+    fn skips(&self, input: &[u8]) -> bool {
+        input.iter().any(|&x| self.is_ignored(x))
+    }
+
+    // Returns the ignored characters in ascii order and their number.
+    fn ignored(&self) -> ([u8; 128], usize) {
+        let mut ignored = [0u8; 128];
+        let mut n = 0;
+        for x in 0 .. 128u8 {
+            if self.is_ignored(x) {
+                ignored[n] = x;
+                n += 1;
+            }
+        }
+        (ignored, n)
+    }
+
+    // Removes the ignored characters.
+    fn strip(&self, input: &[u8]) -> Vec<u8> {
+        input.iter().cloned().filter(|&x| !self.is_ignored(x)).collect()
+    }
+
+    // Returns the position in the input of the character at position
+    // `pos` once the ignored characters are removed.
+    fn position(&self, input: &[u8], pos: usize) -> usize {
+        (0 .. input.len()).filter(|&i| !self.is_ignored(input[i])).nth(pos).unwrap_or(input.len())
+    }
+
+    /// Returns the specification of an encoding.
+    ///
+    /// The translations and ignored characters are listed in ascii
+    /// order.
+    pub fn specification(&self) -> Specification {
+        let n = 1 << self.bit;
+        let mut translate = Translate { from: String::new(), to: String::new() };
+        for s in 0 .. 128u8 {
+            let v = self.dec[s as usize];
+            if self.val[s as usize] == 128 && v < 128 {
+                translate.from.push(s as char);
+                translate.to.push(self.sym[v as usize] as char);
+            }
+        }
+        Specification {
+            symbols: self.sym[.. n].iter().map(|&s| s as char).collect(),
+            padding: self.pad as char,
+            bit_order: self.order,
+            translate: translate,
+            check_trailing_bits: self.check,
+            ignore: { let (x, n) = self.ignored(); x[.. n].iter().map(|&x| x as char).collect() },
+            wrap: Wrap { width: self.width, separator: self.separator().to_string() },
+        }
+    }
+}
+
+impl Encoding {
+    /// See the generic [`encode_len`](../encode/fn.encode_len.html)
+    /// function for details.
+    ///
+    /// The length includes separators if the encoding wraps.
+    pub fn encode_len(&self, len: usize) -> usize {
+        if self.width == 0 { return ::encode::encode_len(self, len); }
+        ::encode::encode_wrap_len(self, self.width, self.separator().as_bytes(), len)
+    }
+
+    /// See the generic
+    /// [`encode_nopad_len`](../encode/fn.encode_nopad_len.html)
+    /// function for details.
+    pub fn encode_nopad_len(&self, len: usize) -> usize {
+        ::encode::encode_nopad_len(self, len)
+    }
+
+    /// See the generic [`encode_mut`](../encode/fn.encode_mut.html)
+    /// function for details.
+    ///
+    /// The output is wrapped if the encoding wraps.
+    pub fn encode_mut(&self, input: &[u8], output: &mut [u8]) {
+        if self.width == 0 { return ::encode::encode_mut(self, input, output); }
+        ::encode::encode_wrap_mut(self, self.width, self.separator().as_bytes(), input, output)
+    }
+
+    /// See the generic
+    /// [`encode_nopad_mut`](../encode/fn.encode_nopad_mut.html)
+    /// function for details.
+    pub fn encode_nopad_mut(&self, input: &[u8], output: &mut [u8]) {
+        ::encode::encode_nopad_mut(self, input, output)
+    }
+
+    /// See the generic
+    /// [`encode_mut_str`](../encode/fn.encode_mut_str.html) function
+    /// for details.
+    ///
+    /// The output is wrapped if the encoding wraps.
+    pub fn encode_mut_str<'a>(&self, input: &[u8], output: &'a mut [u8]) -> &'a mut str {
+        self.encode_mut(input, output);
+        unsafe {
+            // This is valid because symbols and separators are ascii.
+            str::from_utf8_unchecked_mut(output)
+        }
+    }
+
+    /// See the generic
+    /// [`encode_nopad_mut_str`](../encode/fn.encode_nopad_mut_str.html)
+    /// function for details.
+    pub fn encode_nopad_mut_str<'a>(&self, input: &[u8], output: &'a mut [u8]) -> &'a mut str {
+        ::encode::encode_nopad_mut_str(self, input, output)
+    }
+
+    /// See the generic [`encode`](../encode/fn.encode.html) function
+    /// for details.
+    ///
+    /// The output is wrapped if the encoding wraps.
+    pub fn encode(&self, input: &[u8]) -> String {
+        if self.width == 0 { return ::encode::encode(self, input); }
+        ::encode::encode_wrap(self, self.width, self.separator(), input)
+    }
+
+    /// See the generic [`encode_nopad`](../encode/fn.encode_nopad.html)
+    /// function for details.
+    pub fn encode_nopad(&self, input: &[u8]) -> String {
+        ::encode::encode_nopad(self, input)
+    }
+
+    /// See the generic [`try_encode`](../encode/fn.try_encode.html)
+    /// function for details.
+    ///
+    /// The output is wrapped if the encoding wraps.
+    pub fn try_encode(&self, input: &[u8]) -> Result<String, Error> {
+        if self.width == 0 { return ::encode::try_encode(self, input); }
+        ::encode::try_encode_wrap(self, self.width, self.separator(), input)
+    }
+
+    /// See the generic
+    /// [`try_encode_nopad`](../encode/fn.try_encode_nopad.html)
+    /// function for details.
+    pub fn try_encode_nopad(&self, input: &[u8]) -> Result<String, Error> {
+        ::encode::try_encode_nopad(self, input)
+    }
+
+    /// See the generic
+    /// [`encode_append`](../encode/fn.encode_append.html) function for
+    /// details.
+    ///
+    /// The output is wrapped if the encoding wraps.
+    pub fn encode_append(&self, input: &[u8], output: &mut String) {
+        let len = self.encode_len(input.len());
+        let output = unsafe {
+            // This is valid because symbols and separators are ascii.
+            output.as_mut_vec()
+        };
+        let start = output.len();
+        output.resize(start + len, 0);
+        self.encode_mut(input, &mut output[start ..]);
+    }
+
+    /// See the generic
+    /// [`encode_write`](../encode/fn.encode_write.html) function for
+    /// details.
+    ///
+    /// The output is wrapped if the encoding wraps.
+    pub fn encode_write<W: fmt::Write>(&self, input: &[u8], output: &mut W) -> fmt::Result {
+        if self.width == 0 { return ::encode::encode_write(self, input, output); }
+        let ilen = self.width * self.bit() / 8;
+        for line in input.chunks(ilen) {
+            try!(::encode::encode_write(self, line, output));
+            try!(output.write_str(self.separator()));
+        }
+        Ok(())
+    }
+
+    /// See the generic
+    /// [`encode_extend`](../encode/fn.encode_extend.html) function for
+    /// details.
+    ///
+    /// The output is wrapped if the encoding wraps.
+    pub fn encode_extend<E: Extend<u8>>(&self, input: &[u8], output: &mut E) {
+        if self.width == 0 { return ::encode::encode_extend(self, input, output); }
+        let ilen = self.width * self.bit() / 8;
+        for line in input.chunks(ilen) {
+            ::encode::encode_extend(self, line, output);
+            output.extend(self.separator().bytes());
+        }
+    }
+
+    /// See the generic
+    /// [`encode_in_place`](../encode/fn.encode_in_place.html) function
+    /// for details.
+    ///
+    /// The output is not wrapped.
+    pub fn encode_in_place(&self, buffer: &mut [u8], len: usize) {
+        ::encode::encode_in_place(self, buffer, len)
+    }
+
+    /// See the generic
+    /// [`encode_wrap_len`](../encode/fn.encode_wrap_len.html) function
+    /// for details.
+    pub fn encode_wrap_len(&self, width: usize, separator: &[u8], len: usize) -> usize {
+        ::encode::encode_wrap_len(self, width, separator, len)
+    }
+
+    /// See the generic
+    /// [`encode_wrap_mut`](../encode/fn.encode_wrap_mut.html) function
+    /// for details.
+    pub fn encode_wrap_mut(&self, width: usize, separator: &[u8],
+                           input: &[u8], output: &mut [u8]) {
+        ::encode::encode_wrap_mut(self, width, separator, input, output)
+    }
+
+    /// See the generic [`encode_wrap`](../encode/fn.encode_wrap.html)
+    /// function for details.
+    pub fn encode_wrap(&self, width: usize, separator: &str, input: &[u8]) -> String {
+        ::encode::encode_wrap(self, width, separator, input)
+    }
+
+    /// See the generic [`decode_len`](../decode/fn.decode_len.html)
+    /// function for details.
+    pub fn decode_len(&self, len: usize) -> usize {
+        ::decode::decode_len(self, len)
+    }
+
+    /// See the generic
+    /// [`decode_nopad_len`](../decode/fn.decode_nopad_len.html)
+    /// function for details.
+    pub fn decode_nopad_len(&self, len: usize) -> Result<usize, Error> {
+        ::decode::decode_nopad_len(self, len)
+    }
+
+    /// See the generic [`decode_mut`](../decode/fn.decode_mut.html)
+    /// function for details.
+    ///
+    /// The ignored characters of the encoding are not skipped, since
+    /// the output length is given by the input length.
+    pub fn decode_mut(&self, input: &[u8], output: &mut [u8]) -> Result<usize, Error> {
+        ::decode::decode_mut(&Decoder(self), input, output)
+    }
+
+    /// See the generic
+    /// [`decode_nopad_mut`](../decode/fn.decode_nopad_mut.html)
+    /// function for details.
+    ///
+    /// The ignored characters of the encoding are not skipped, like
+    /// [`decode_mut`](#method.decode_mut).
+    pub fn decode_nopad_mut(&self, input: &[u8], output: &mut [u8]) -> Result<(), Error> {
+        ::decode::decode_nopad_mut(&Decoder(self), input, output)
+    }
+
+    /// See the generic [`decode`](../decode/fn.decode.html) function
+    /// for details.
+    ///
+    /// The ignored characters of the encoding are skipped, as
+    /// described by [`decode_ignore`](../decode/fn.decode_ignore.html).
+    pub fn decode(&self, input: &[u8]) -> Result<Vec<u8>, Error> {
+        if !self.skips(input) { return ::decode::decode(&Decoder(self), input); }
+        let (ignored, n) = self.ignored();
+        ::decode::decode_ignore(&Decoder(self), &ignored[.. n], input)
+    }
+
+    /// See the generic [`decode_nopad`](../decode/fn.decode_nopad.html)
+    /// function for details.
+    ///
+    /// The ignored characters of the encoding are skipped, like
+    /// [`decode`](#method.decode).
+    pub fn decode_nopad(&self, input: &[u8]) -> Result<Vec<u8>, Error> {
+        if !self.skips(input) { return ::decode::decode_nopad(&Decoder(self), input); }
+        ::decode::decode_nopad(&Decoder(self), &self.strip(input))
+            .map_err(|e| e.map(|p| self.position(input, p)))
+    }
+
+    /// See the generic
+    /// [`decode_append`](../decode/fn.decode_append.html) function for
+    /// details.
+    ///
+    /// The ignored characters of the encoding are skipped, like
+    /// [`decode`](#method.decode).
+    pub fn decode_append(&self, input: &[u8], output: &mut Vec<u8>) -> Result<usize, Error> {
+        if !self.skips(input) {
+            return ::decode::decode_append(&Decoder(self), input, output);
+        }
+        let decoded = try!(self.decode(input));
+        output.extend_from_slice(&decoded);
+        Ok(decoded.len())
+    }
+
+    /// See the generic
+    /// [`decode_in_place`](../decode/fn.decode_in_place.html) function
+    /// for details.
+    ///
+    /// The ignored characters of the encoding are skipped, like
+    /// [`decode`](#method.decode). Reported positions refer to the
+    /// buffer as given.
+    pub fn decode_in_place(&self, buffer: &mut [u8]) -> Result<usize, Error> {
+        if !self.skips(buffer) { return ::decode::decode_in_place(&Decoder(self), buffer); }
+        let enc = enc(self);
+        let dec = dec(self);
+        let len = buffer.iter().filter(|&&x| !self.is_ignored(x)).count();
+        check!(Error::BadLength, len % dec == 0);
+        // Blocks are gathered with the position of their characters.
+        // The output never overlaps the characters left to read since
+        // it is shorter than the input.
+        let mut block = [0u8; 8];
+        let mut index = [0usize; 8];
+        let mut output = [0u8; 7];
+        let mut j = 0;
+        let mut r = 0;
+        let n = len / dec;
+        for i in 0 .. n {
+            let mut k = 0;
+            while k < dec {
+                if !self.is_ignored(buffer[j]) {
+                    block[k] = buffer[j];
+                    index[k] = j;
+                    k += 1;
+                }
+                j += 1;
+            }
+            // Only the last block may be padded.
+            let result = if i + 1 < n {
+                ::decode::decode_nopad_mut(&Decoder(self), &block[.. dec], &mut output[.. enc])
+                    .map(|_| enc)
+            } else {
+                ::decode::decode_mut(&Decoder(self), &block[.. dec], &mut output[.. enc])
+            };
+            r = try!(result.map_err(|e| e.map(|p| index[p])));
+            buffer[enc * i .. enc * i + r].copy_from_slice(&output[.. r]);
+        }
+        Ok(enc * n.saturating_sub(1) + r)
+    }
+
+    /// See the generic
+    /// [`decode_opt_pad_len`](../decode/fn.decode_opt_pad_len.html)
+    /// function for details.
+    pub fn decode_opt_pad_len(&self, len: usize) -> Result<usize, Error> {
+        ::decode::decode_opt_pad_len(self, len)
+    }
+
+    /// See the generic
+    /// [`decode_opt_pad_mut`](../decode/fn.decode_opt_pad_mut.html)
+    /// function for details.
+    ///
+    /// The ignored characters of the encoding are not skipped, like
+    /// [`decode_mut`](#method.decode_mut).
+    pub fn decode_opt_pad_mut(&self, input: &[u8], output: &mut [u8]) -> Result<usize, Error> {
+        ::decode::decode_opt_pad_mut(&Decoder(self), input, output)
+    }
+
+    /// See the generic
+    /// [`decode_opt_pad`](../decode/fn.decode_opt_pad.html) function
+    /// for details.
+    ///
+    /// The ignored characters of the encoding are skipped, like
+    /// [`decode`](#method.decode).
+    pub fn decode_opt_pad(&self, input: &[u8]) -> Result<Vec<u8>, Error> {
+        if !self.skips(input) { return ::decode::decode_opt_pad(&Decoder(self), input); }
+        ::decode::decode_opt_pad(&Decoder(self), &self.strip(input))
+            .map_err(|e| e.map(|p| self.position(input, p)))
+    }
+
+    /// See the generic
+    /// [`decode_ignore`](../decode/fn.decode_ignore.html) function for
+    /// details.
+    pub fn decode_ignore(&self, ignore: &[u8], input: &[u8]) -> Result<Vec<u8>, Error> {
+        ::decode::decode_ignore(&Decoder(self), ignore, input)
+    }
+
+    /// See the generic [`is_valid`](../decode/fn.is_valid.html)
+    /// function for details.
+    ///
+    /// The ignored characters of the encoding are skipped, like
+    /// [`decode`](#method.decode). The input is validated block by
+    /// block and nothing is allocated.
+    pub fn is_valid(&self, input: &[u8]) -> bool {
+        if !self.skips(input) { return ::decode::is_valid(&Decoder(self), input); }
+        let enc = enc(self);
+        let dec = dec(self);
+        let len = input.iter().filter(|&&x| !self.is_ignored(x)).count();
+        if len % dec != 0 { return false; }
+        let mut block = [0u8; 8];
+        let mut k = 0;
+        let mut n = 0;
+        for &x in input {
+            if self.is_ignored(x) { continue; }
+            block[k] = x;
+            k += 1;
+            if k < dec { continue; }
+            k = 0;
+            n += dec;
+            // Only the last block may be padded.
+            match ::decode::validate(&Decoder(self), &block[.. dec]) {
+                Ok(r) if r == enc || n == len => (),
+                _ => return false,
+            }
+        }
+        true
+    }
+
+    /// See the generic [`normalize`](../decode/fn.normalize.html)
+    /// function for details.
+    ///
+    /// Translations are applied, ignored characters are removed,
+    /// padding is added if missing, and the output is wrapped if the
+    /// encoding wraps. The output is canonical according to
+    /// [`is_canonical`](#method.is_canonical).
+    pub fn normalize(&self, input: &[u8]) -> Result<String, Error> {
+        if self.width == 0 && !self.skips(input) {
+            return ::decode::normalize(&Decoder(self), input);
+        }
+        Ok(self.encode(&try!(self.decode_opt_pad(input))))
+    }
+
+    /// See the generic [`is_canonical`](../decode/fn.is_canonical.html)
+    /// function for details.
+    ///
+    /// The canonical form of an encoding uses no translations nor
+    /// ignored characters, and is wrapped if the encoding wraps: the
+    /// input is canonical if it is the output of
+    /// [`encode`](#method.encode) for its decoding.
+    pub fn is_canonical(&self, input: &[u8]) -> bool {
+        if self.width == 0 {
+            return ::decode::is_canonical(self, input);
+        }
+        let sep = self.separator().as_bytes();
+        let mut input = input;
+        while !input.is_empty() {
+            if input.len() <= sep.len() { return false; }
+            let n = ::std::cmp::min(self.width, input.len() - sep.len());
+            let (line, rest) = input.split_at(n);
+            if !rest.starts_with(sep) { return false; }
+            input = &rest[sep.len() ..];
+            if n < self.width && !input.is_empty() { return false; }
+            if !input.is_empty() && line.contains(&self.pad) { return false; }
+            if !::decode::is_canonical(self, line) { return false; }
+        }
+        true
+    }
+}
+
+// Same as an encoding but with the translations accepted by decoding.
+struct Decoder<'a>(&'a Encoding);
+
+impl<'a> Base for Decoder<'a> {
+    fn bit(&self) -> usize {
+        self.0.bit as usize
+    }
+
+    fn pad(&self) -> u8 {
+        self.0.pad
+    }
+
+    fn val(&self, x: u8) -> Option<u8> {
+        let v = self.0.dec[x as usize];
+        if v < 128 { Some(v) } else { None }
+    }
+
+    fn sym(&self, x: u8) -> u8 {
+        self.0.sym[x as usize]
+    }
+
+    fn bit_order(&self) -> BitOrder {
+        self.0.order
+    }
+
+    fn check_trailing_bits(&self) -> bool {
+        self.0.check
+    }
+}
+
+impl fmt::Debug for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Encoding").field(&self.specification()).finish()
+    }
+}
+
+impl Base for Encoding {
+    fn bit(&self) -> usize {
+        self.bit as usize
+    }
+
+    fn pad(&self) -> u8 {
+        self.pad
+    }
+
+    fn val(&self, x: u8) -> Option<u8> {
+        let v = self.val[x as usize];
+        if v < 128 { Some(v) } else { None }
+    }
+
+    fn sym(&self, x: u8) -> u8 {
+        self.sym[x as usize]
+    }
+
+    fn bit_order(&self) -> BitOrder {
+        self.order
+    }
+
+    fn check_trailing_bits(&self) -> bool {
+        self.check
+    }
+}
+
+/// Base 16 Encoding.
+///
+/// This is the encoding of the [`base16`](../base16/index.html)
+/// module.
+pub const BASE16: Encoding = new_encoding!{
+    symbols: "0123456789ABCDEF",
+};
+
+/// Base 32 Encoding.
+///
+/// This is the encoding of the [`base32`](../base32/index.html)
+/// module.
+pub const BASE32: Encoding = new_encoding!{
+    symbols: "ABCDEFGHIJKLMNOPQRSTUVWXYZ234567",
+};
+
+/// Base 32 Encoding with Extended Hex Alphabet.
+///
+/// This is the encoding of the [`base32hex`](../base32hex/index.html)
+/// module.
+pub const BASE32HEX: Encoding = new_encoding!{
+    symbols: "0123456789ABCDEFGHIJKLMNOPQRSTUV",
+};
+
+/// Base 64 Encoding.
+///
+/// This is the encoding of the [`base64`](../base64/index.html)
+/// module.
+pub const BASE64: Encoding = new_encoding!{
+    symbols: "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+};
+
+/// Base 64 Encoding with URL and Filename Safe Alphabet.
+///
+/// This is the encoding of the [`base64url`](../base64url/index.html)
+/// module.
+pub const BASE64URL: Encoding = new_encoding!{
+    symbols: "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_",
+};
+
+#[test]
+fn check() {
+    use base::equal;
+    equal(&BASE16, ::base16::base()).unwrap();
+    equal(&BASE32, ::base32::base()).unwrap();
+    equal(&BASE32HEX, ::base32hex::base()).unwrap();
+    equal(&BASE64, ::base64::base()).unwrap();
+    equal(&BASE64URL, ::base64url::base()).unwrap();
+}
@@ -0,0 +1,314 @@
+//! Motorola S-record Encoding.
+//!
+//! A file is a sequence of records, one per line. A record is `S`,
+//! its type digit, and uppercase hexadecimal digits encoding its byte
+//! count, an address, its data, and a checksum. The byte count covers
+//! the address, the data, and the checksum. The checksum is the ones'
+//! complement of the sum of the other bytes. The supported record
+//! types are described in the [`Record`](enum.Record.html) enum.
+//!
+//! The [`records`](fn.records.html) function iterates over the records
+//! of a file. The [`encode`](fn.encode.html) and
+//! [`decode`](fn.decode.html) functions convert between a memory image
+//! and a file, with a configurable [`Width`](enum.Width.html) of
+//! addresses.
+//!
+//! Decoding accepts lowercase hexadecimal digits, `\n` and `\r\n`
+//! line terminators, and empty lines.
+//!
+//! # Conformance
+//!
+//! [S-record](https://en.wikipedia.org/wiki/SREC_(file_format))
+//! compliant for the record types S0 to S9, except the reserved S4.
+//! Encoding uses `\n` line terminators.
+
+use decode::Error;
+use decode::Error::*;
+use lines::lines;
+use tool::hex_val;
+
+/// The length of data records produced by [`encode`](fn.encode.html).
+pub const LINE: usize = 16;
+
+/// Address widths.
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+pub enum Width {
+    /// 16-bit addresses.
+    Bits16,
+
+    /// 24-bit addresses.
+    Bits24,
+
+    /// 32-bit addresses.
+    Bits32,
+}
+
+impl Width {
+    fn len(self) -> usize {
+        match self {
+            Width::Bits16 => 2,
+            Width::Bits24 => 3,
+            Width::Bits32 => 4,
+        }
+    }
+
+    fn max(self) -> u64 {
+        1 << 8 * self.len()
+    }
+}
+
+/// Records.
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub enum Record {
+    /// Header data (S0).
+    Header(Vec<u8>),
+
+    /// Data at an address (S1, S2, and S3).
+    Data {
+        /// The width of the address.
+        width: Width,
+
+        /// The address.
+        address: u32,
+
+        /// The data.
+        data: Vec<u8>,
+    },
+
+    /// Number of data records (S5 and S6).
+    ///
+    /// The width must be 16 or 24 bits.
+    Count {
+        /// The width of the count.
+        width: Width,
+
+        /// The count.
+        count: u32,
+    },
+
+    /// Start address, terminating the file (S7, S8, and S9).
+    Start {
+        /// The width of the address.
+        width: Width,
+
+        /// The address.
+        address: u32,
+    },
+}
+
+fn kind(record: &Record) -> (u8, Width, u32, &[u8]) {
+    match *record {
+        Record::Header(ref data) => (0, Width::Bits16, 0, data),
+        Record::Data { width, address, ref data } => (width.len() as u8 - 1, width, address, data),
+        Record::Count { width, count } => {
+            assert!(width != Width::Bits32);
+            (width.len() as u8 + 3, width, count, &[])
+        }
+        Record::Start { width, address } => (11 - width.len() as u8, width, address, &[]),
+    }
+}
+
+/// Encodes a record without line terminator.
+///
+/// # Panics
+///
+/// Panics if the address or count does not fit in its width, if a
+/// count has a 32-bit width, or if the byte count does not fit in a
+/// byte.
+pub fn encode_record(record: &Record) -> String {
+    let (kind, width, address, data) = kind(record);
+    let n = width.len();
+    assert!((address as u64) < width.max());
+    assert!(n + data.len() < 255);
+    let mut bytes = vec![(n + data.len() + 1) as u8];
+    for i in 0 .. n {
+        bytes.push((address >> 8 * (n - 1 - i)) as u8);
+    }
+    bytes.extend_from_slice(data);
+    let sum = bytes.iter().fold(0u8, |s, &x| s.wrapping_add(x));
+    bytes.push(!sum);
+    format!("S{}{}", kind, ::base16::encode(&bytes))
+}
+
+/// Decodes a record without line terminator.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadCharacter` if the record does not start with `S`,
+/// has an unknown type, contains a non-hexadecimal digit, or is a
+/// header with a non-zero address. The position of an invalid address
+/// is the one of its first digit.
+/// - `Error::BadLength` if the record does not match its byte count,
+/// or has a byte count invalid for its type.
+/// - `Error::BadChecksum` if the checksum does not match.
+pub fn decode_record(input: &[u8]) -> Result<Record, Error> {
+    check!(BadCharacter(0), input.first() == Some(&b'S'));
+    let kind = match input.get(1) {
+        Some(&x) if b'0' <= x && x <= b'9' && x != b'4' => x - b'0',
+        _ => return Err(BadCharacter(1)),
+    };
+    check!(BadLength, input.len() % 2 == 0 && input.len() >= 4);
+    let mut bytes = Vec::with_capacity(input.len() / 2 - 1);
+    for i in 1 .. input.len() / 2 {
+        let j = 2 * i;
+        let h = try!(hex_val(input[j], true).ok_or(BadCharacter(j)));
+        let l = try!(hex_val(input[j + 1], true).ok_or(BadCharacter(j + 1)));
+        bytes.push(h << 4 | l);
+    }
+    let width = match kind {
+        0 | 1 | 5 | 9 => Width::Bits16,
+        2 | 6 | 8 => Width::Bits24,
+        _ => Width::Bits32,
+    };
+    let m = bytes.len();
+    let n = width.len();
+    check!(BadLength, bytes[0] as usize + 1 == m && m >= n + 2);
+    check!(BadChecksum, bytes.iter().fold(0u8, |s, &x| s.wrapping_add(x)) == 0xff);
+    let address = bytes[1 .. n + 1].iter().fold(0u32, |a, &x| a << 8 | x as u32);
+    let data = bytes[n + 1 .. m - 1].to_vec();
+    check!(BadLength, kind <= 3 || data.is_empty());
+    Ok(match kind {
+        0 => {
+            check!(BadCharacter(4), address == 0);
+            Record::Header(data)
+        }
+        1 | 2 | 3 => Record::Data { width: width, address: address, data: data },
+        5 | 6 => Record::Count { width: width, count: address },
+        _ => Record::Start { width: width, address: address },
+    })
+}
+
+/// Iterator over the records of a file.
+///
+/// This iterator is returned by the [`records`](fn.records.html)
+/// function.
+pub struct Records<'a> {
+    lines: ::std::vec::IntoIter<(usize, &'a [u8])>,
+}
+
+impl<'a> Iterator for Records<'a> {
+    type Item = Result<Record, Error>;
+
+    fn next(&mut self) -> Option<Result<Record, Error>> {
+        for (pos, line) in self.lines.by_ref() {
+            if !line.is_empty() {
+                return Some(decode_record(line).map_err(|e| e.shift(pos)));
+            }
+        }
+        None
+    }
+}
+
+/// Iterates over the records of a file.
+///
+/// Empty lines are skipped. Errors are those of
+/// [`decode_record`](fn.decode_record.html), with positions in the
+/// file.
+pub fn records(input: &[u8]) -> Records<'_> {
+    Records { lines: lines(input).into_iter() }
+}
+
+/// Converts a memory image to records.
+///
+/// The records are a header record, data records of 16 bytes, a count
+/// record if the count fits in 24 bits, and a start record with the
+/// given address.
+///
+/// # Panics
+///
+/// Panics if the data does not fit below the maximum address of the
+/// width, or if the header has more than 252 bytes.
+pub fn to_records(header: &[u8], width: Width, address: u32, data: &[u8]) -> Vec<Record> {
+    assert!(address as u64 + data.len() as u64 <= width.max());
+    assert!(header.len() <= 252);
+    let mut output = vec![Record::Header(header.to_vec())];
+    for (i, chunk) in data.chunks(LINE).enumerate() {
+        let a = address + (i * LINE) as u32;
+        output.push(Record::Data { width: width, address: a, data: chunk.to_vec() });
+    }
+    let count = output.len() as u64 - 1;
+    if count < Width::Bits24.max() {
+        let w = if count < Width::Bits16.max() { Width::Bits16 } else { Width::Bits24 };
+        output.push(Record::Count { width: w, count: count as u32 });
+    }
+    output.push(Record::Start { width: width, address: address });
+    output
+}
+
+/// Encodes a memory image with allocation.
+///
+/// The records are those of [`to_records`](fn.to_records.html), each
+/// followed by `\n`.
+///
+/// # Panics
+///
+/// Panics in the circumstances defined by
+/// [`to_records`](fn.to_records.html).
+pub fn encode(header: &[u8], width: Width, address: u32, data: &[u8]) -> String {
+    let mut output = String::new();
+    for record in to_records(header, width, address, data) {
+        output.push_str(&encode_record(&record));
+        output.push('\n');
+    }
+    output
+}
+
+/// A decoded memory image.
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub struct Image {
+    /// The header data, empty if there is no header record.
+    pub header: Vec<u8>,
+
+    /// The address and data of each contiguous segment, in file order.
+    pub segments: Vec<(u32, Vec<u8>)>,
+
+    /// The start address.
+    pub start: u32,
+}
+
+/// Decodes a memory image with allocation.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`records`](fn.records.html). It also returns `Error::BadLength`
+/// if there is no start record or if a count record does not match
+/// the number of preceding data records, and `Error::BadCharacter` if
+/// a record follows the start record.
+pub fn decode(input: &[u8]) -> Result<Image, Error> {
+    let mut image = Image { header: Vec::new(), segments: Vec::new(), start: 0 };
+    let mut count = 0;
+    let mut lines = lines(input).into_iter();
+    loop {
+        let (pos, line) = try!(lines.next().ok_or(BadLength));
+        if line.is_empty() {
+            continue;
+        }
+        match try!(decode_record(line).map_err(|e| e.shift(pos))) {
+            Record::Header(data) => image.header = data,
+            Record::Data { address, data, .. } => {
+                count += 1;
+                match image.segments.last_mut() {
+                    Some(&mut (start, ref mut last))
+                        if start as u64 + last.len() as u64 == address as u64 => {
+                        last.extend_from_slice(&data);
+                        continue;
+                    }
+                    _ => (),
+                }
+                image.segments.push((address, data));
+            }
+            Record::Count { count: n, .. } => check!(BadLength, n == count),
+            Record::Start { address, .. } => {
+                image.start = address;
+                break;
+            }
+        }
+    }
+    for (pos, line) in lines {
+        check!(BadCharacter(pos), line.is_empty());
+    }
+    Ok(image)
+}
@@ -31,3 +31,88 @@ pub fn chunk_mut(x: &mut [u8], n: usize, i: usize) -> &mut [u8] {
     assert!(n * (i + 1) <= x.len());
     unsafe { chunk_mut_unchecked(x, n, i) }
 }
+
+// Initializes a slice of possibly uninitialized bytes.
+pub fn write_uninit(output: &mut [::std::mem::MaybeUninit<u8>], input: &[u8]) {
+    assert_eq!(output.len(), input.len());
+    for (o, &i) in output.iter_mut().zip(input) {
+        *o = ::std::mem::MaybeUninit::new(i);
+    }
+}
+
+// Returns the bytes of an initialized slice.
+pub unsafe fn assume_init(x: &mut [::std::mem::MaybeUninit<u8>]) -> &mut [u8] {
+    ::std::slice::from_raw_parts_mut(x.as_mut_ptr() as *mut u8, x.len())
+}
+
+// Panics unless the symbols of a base, and its padding if used, are
+// ascii. The output of such a base is always valid UTF-8.
+pub fn assert_ascii<B: ::base::Base + ?Sized>(base: &B, pad: bool) {
+    if base.bit() < 7 { return; }
+    assert!((0 .. 128).all(|v| base.sym(v) < 128) && (!pad || base.pad() < 128),
+            "non-ascii base, use encode_bytes instead");
+}
+
+// Removes separators.
+pub fn strip(input: &[u8], sep: u8) -> Vec<u8> {
+    input.iter().cloned().filter(|&x| x != sep).collect()
+}
+
+// Maps a position in the stripped input to a position in the input.
+pub fn unstrip(input: &[u8], sep: u8, pos: usize) -> usize {
+    let mut n = 0;
+    for (i, &x) in input.iter().enumerate() {
+        if x == sep { continue; }
+        if n == pos { return i; }
+        n += 1;
+    }
+    input.len()
+}
+
+// Splits the input on spaces and returns each word with its position.
+pub fn words(input: &str) -> Vec<(usize, &str)> {
+    let mut output = Vec::new();
+    let mut pos = 0;
+    for w in input.split(' ') {
+        output.push((pos, w));
+        pos += w.len() + 1;
+    }
+    output
+}
+
+// Splits the input on runs of ascii whitespace and returns each word
+// with its position. Leading and trailing whitespace is ignored.
+pub fn words_whitespace(input: &str) -> Vec<(usize, &str)> {
+    let mut output = Vec::new();
+    let mut start = None;
+    for (i, x) in input.bytes().enumerate() {
+        match (x.is_ascii_whitespace(), start) {
+            (true, Some(s)) => { output.push((s, &input[s .. i])); start = None; }
+            (false, None) => start = Some(i),
+            _ => (),
+        }
+    }
+    if let Some(s) = start {
+        output.push((s, &input[s ..]));
+    }
+    output
+}
+
+// Pushes a byte as a prefix followed by 2 uppercase hexadecimal digits.
+pub fn push_escape(output: &mut Vec<u8>, prefix: u8, x: u8) {
+    const HEX: &'static [u8] = b"0123456789ABCDEF";
+    output.push(prefix);
+    output.push(HEX[(x >> 4) as usize]);
+    output.push(HEX[(x & 15) as usize]);
+}
+
+// Returns the value of a hexadecimal digit. Lowercase digits are
+// accepted if `lower` is true.
+pub fn hex_val(x: u8, lower: bool) -> Option<u8> {
+    match x {
+        b'0' ..= b'9' => Some(x - b'0'),
+        b'A' ..= b'F' => Some(x - b'A' + 10),
+        b'a' ..= b'f' if lower => Some(x - b'a' + 10),
+        _ => None,
+    }
+}
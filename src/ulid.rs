@@ -0,0 +1,59 @@
+//! ULID Encoding.
+//!
+//! A ULID is a 128-bit value, namely a 48-bit timestamp followed by 80
+//! random bits, encoded as a big-endian number with 26
+//! [`base32crockford`](../base32crockford/index.html) symbols. Since
+//! 26 symbols hold 130 bits, the first symbol is at most `7`.
+//!
+//! Encoding produces uppercase symbols. Decoding also accepts
+//! lowercase symbols, but not the aliases of Crockford's Base 32.
+//!
+//! # Conformance
+//!
+//! [ULID](https://github.com/ulid/spec) compliant.
+
+use base::Base;
+use decode::Error;
+use decode::Error::*;
+
+/// The length of an encoded ULID.
+pub const LEN: usize = 26;
+
+/// Encodes with allocation.
+pub fn encode(input: &[u8; 16]) -> String {
+    let base = ::base32crockford::base();
+    let x = input.iter().fold(0u128, |x, &y| x << 8 | y as u128);
+    let mut output = vec![0u8; LEN];
+    for (i, y) in output.iter_mut().enumerate() {
+        *y = base.sym((x >> 5 * (LEN - 1 - i)) as u8 & 31);
+    }
+    unsafe {
+        // This is valid because symbols are ascii.
+        String::from_utf8_unchecked(output)
+    }
+}
+
+/// Decodes with allocation.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadLength` if the input is not 26 bytes long.
+/// - `Error::BadCharacter` if the input contains a non-symbol, or if
+/// the first symbol is greater than `7`.
+pub fn decode(input: &[u8]) -> Result<[u8; 16], Error> {
+    let base = ::base32crockford::base();
+    check!(BadLength, input.len() == LEN);
+    let mut x = 0u128;
+    for (i, &y) in input.iter().enumerate() {
+        let v = try!(base.val(y.to_ascii_uppercase()).ok_or(BadCharacter(i)));
+        check!(BadCharacter(0), i > 0 || v < 8);
+        x = x << 5 | v as u128;
+    }
+    let mut output = [0u8; 16];
+    for (i, y) in output.iter_mut().enumerate() {
+        *y = (x >> 8 * (15 - i)) as u8;
+    }
+    Ok(output)
+}
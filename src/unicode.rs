@@ -0,0 +1,198 @@
+//! Generic Unicode module.
+//!
+//! This module defines a specification, namely
+//! [`Spec`](struct.Spec.html), for positional numerical systems whose
+//! symbols are Unicode characters, each possibly taking several bytes
+//! in UTF-8. The radix is a power of two ranging from 2 to 65536. This
+//! is meant for dense encodings over channels which are limited in
+//! number of characters rather than bytes, like Base65536 or
+//! Base32768. It also defines the generic encoding and decoding
+//! functions for such systems.
+//!
+//! The input is seen as a sequence of bits, most significant first,
+//! which are grouped in values of `bit` bits. The last value may be
+//! shorter: if it holds at most `tail` bits, it is encoded with a
+//! symbol of the tail repertoire, otherwise with a symbol of the main
+//! repertoire. In both cases, the missing low bits are set to one.
+//!
+//! The encoding and decoding functions satisfy the same properties as
+//! the [`encode`](../encode/index.html) and
+//! [`decode`](../decode/index.html) modules: they are inverse of each
+//! other and decoding rejects non-canonical inputs.
+
+use std::char;
+use std::str;
+
+use decode::Error;
+use decode::Error::*;
+
+/// Specification.
+///
+/// Symbols are described with arrays of inclusive ranges of
+/// characters in value order. For instance, for `&[('a', 'z'), ('0',
+/// '9')]`, `'c'` has value 2 and `'1'` has value 27.
+///
+/// The constraints on a specification are checked by the
+/// [`valid`](fn.valid.html) function.
+pub struct Spec {
+    /// The main repertoire.
+    ///
+    /// These ranges must contain `1 << bit` characters.
+    pub sym: &'static [(char, char)],
+
+    /// The number of bits of the main symbols.
+    pub bit: u8,
+
+    /// The tail repertoire.
+    ///
+    /// These ranges must contain `1 << tail` characters if `tail` is
+    /// not zero, and no characters otherwise.
+    pub tail_sym: &'static [(char, char)],
+
+    /// The number of bits of the tail symbols.
+    pub tail: u8,
+}
+
+fn sym(ranges: &[(char, char)], mut v: u32) -> char {
+    for &(l, u) in ranges {
+        let n = u as u32 - l as u32 + 1;
+        if v < n {
+            return char::from_u32(l as u32 + v).unwrap();
+        }
+        v -= n;
+    }
+    unreachable!();
+}
+
+fn val(ranges: &[(char, char)], c: char) -> Option<u32> {
+    let mut t = 0;
+    for &(l, u) in ranges {
+        if l <= c && c <= u {
+            return Some(t + c as u32 - l as u32);
+        }
+        t += u as u32 - l as u32 + 1;
+    }
+    None
+}
+
+fn card(ranges: &[(char, char)]) -> Option<u32> {
+    let mut t = 0u32;
+    for &(l, u) in ranges {
+        if l > u {
+            return None;
+        }
+        t += u as u32 - l as u32 + 1;
+    }
+    Some(t)
+}
+
+/// Checks whether a specification is valid.
+///
+/// A specification is valid if:
+///
+/// - `1 <= bit && bit <= 16`, `tail < bit`, `tail <= 8`, and `bit -
+/// tail <= 8`, such that padding never covers a whole byte,
+/// - the repertoires have the sizes documented in
+/// [`Spec`](struct.Spec.html),
+/// - the ranges do not overlap, in and across repertoires.
+pub fn valid(spec: &Spec) -> bool {
+    let (bit, tail) = (spec.bit as u32, spec.tail as u32);
+    if bit < 1 || bit > 16 || tail >= bit || tail > 8 || bit - tail > 8 {
+        return false;
+    }
+    if card(spec.sym) != Some(1 << bit) {
+        return false;
+    }
+    if card(spec.tail_sym) != Some(if tail == 0 { 0 } else { 1 << tail }) {
+        return false;
+    }
+    let mut ranges: Vec<_> = spec.sym.iter().chain(spec.tail_sym).collect();
+    ranges.sort();
+    ranges.windows(2).all(|x| x[0].1 < x[1].0)
+}
+
+/// Converts an input length to its output length in characters.
+///
+/// # Panics
+///
+/// May panic if `spec` is not valid.
+pub fn encode_chars(spec: &Spec, len: usize) -> usize {
+    let bit = spec.bit as usize;
+    (8 * len + bit - 1) / bit
+}
+
+/// Generic encoding function with allocation.
+///
+/// # Panics
+///
+/// May panic if `spec` is not valid.
+pub fn encode(spec: &Spec, input: &[u8]) -> String {
+    let bit = spec.bit as usize;
+    let mut output = String::with_capacity(4 * encode_chars(spec, input.len()));
+    let mut x = 0u32;
+    let mut n = 0;
+    for &b in input {
+        x = x << 8 | b as u32;
+        n += 8;
+        while n >= bit {
+            n -= bit;
+            output.push(sym(spec.sym, x >> n));
+            x &= (1 << n) - 1;
+        }
+    }
+    if n > 0 {
+        let (ranges, width) = if n <= spec.tail as usize {
+            (spec.tail_sym, spec.tail as usize)
+        } else {
+            (spec.sym, bit)
+        };
+        let p = width - n;
+        output.push(sym(ranges, x << p | ((1 << p) - 1)));
+    }
+    output
+}
+
+/// Generic decoding function with allocation.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadCharacter` if the input is not valid UTF-8 or
+/// contains a non-symbol, a tail symbol which is not last, or a last
+/// symbol from the wrong repertoire. The position is in bytes.
+/// - `Error::BadLength` if the last symbol holds no input bits.
+/// - `Error::BadPadding` if the padding bits are not all set.
+///
+/// # Panics
+///
+/// May panic if `spec` is not valid.
+pub fn decode(spec: &Spec, input: &[u8]) -> Result<Vec<u8>, Error> {
+    let input = try!(str::from_utf8(input).map_err(|e| BadCharacter(e.valid_up_to())));
+    let bit = spec.bit as usize;
+    let mut output = Vec::with_capacity(input.len());
+    let mut x = 0u32;
+    let mut n = 0;
+    let mut last = None;
+    for (i, c) in input.char_indices() {
+        check!(BadCharacter(i), last.map_or(true, |(_, w)| w == bit));
+        let (v, width) = match val(spec.sym, c) {
+            Some(v) => (v, bit),
+            None => (try!(val(spec.tail_sym, c).ok_or(BadCharacter(i))), spec.tail as usize),
+        };
+        x = x << width | v;
+        n += width;
+        while n >= 8 {
+            n -= 8;
+            output.push((x >> n) as u8);
+            x &= (1 << n) - 1;
+        }
+        last = Some((i, width));
+    }
+    if let Some((i, width)) = last {
+        check!(BadLength, n < width);
+        check!(BadCharacter(i), width < bit || width - n > spec.tail as usize);
+        check!(BadPadding, x == (1 << n) - 1);
+    }
+    Ok(output)
+}
@@ -0,0 +1,117 @@
+//! Uuencoding.
+//!
+//! Symbols are the characters from `` ` `` (value 0) and `!` to `_`
+//! (values 1 to 63). Data is split in lines of at most 45 bytes. Each
+//! line starts with the symbol of its length, followed by groups of 3
+//! bytes encoded with 4 symbols, and ends with `\n`. The last group
+//! of a line is padded with zero bytes.
+//!
+//! The [`encode`](fn.encode.html) and [`decode`](fn.decode.html)
+//! functions work on whole files: the lines are preceded by a `begin
+//! <mode> <name>` line, where the mode is in octal, and followed by a
+//! line of length 0 and an `end` line. The
+//! [`encode_line`](fn.encode_line.html) and
+//! [`decode_line`](fn.decode_line.html) functions work on single
+//! lines, for callers processing their input incrementally.
+//!
+//! Encoding always uses `` ` `` for value 0. Decoding also accepts
+//! space for value 0, `\r\n` line terminators, and ignores padding
+//! bytes.
+//!
+//! # Conformance
+//!
+//! Compatible with the historical algorithm of [POSIX
+//! uuencode](https://pubs.opengroup.org/onlinepubs/9699919799/utilities/uuencode.html).
+
+use decode::Error;
+use radix::Opt;
+
+const X_: u8 = 128;
+
+/// Force static dispatch.
+pub enum Static {}
+
+static RADIX: Opt<Static> = Opt {
+    val: ascii!(
+        0_, 1_, 2_, 3_, 4_, 5_, 6_, 7_, 8_, 9_, 10, 11, 12, 13, 14, 15,
+        16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31,
+        32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
+        48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63,
+        0_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_,
+        X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_, X_),
+    sym: b"`!\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_",
+    _phantom: ::std::marker::PhantomData
+};
+
+/// The maximum number of bytes per line.
+pub const LINE: usize = ::lines::LINE;
+
+/// Gives access to the symbols.
+pub fn radix() -> &'static Opt<Static> {
+    &RADIX
+}
+
+/// Encodes a file.
+///
+/// # Panics
+///
+/// Panics if `name` contains `\n`.
+pub fn encode(mode: u32, name: &str, input: &[u8]) -> String {
+    ::lines::encode(&RADIX, mode, name, input)
+}
+
+/// Decodes a file.
+///
+/// This function returns the mode, the name, and the data. Lines after
+/// the `end` line are ignored.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadCharacter` if the `begin` or `end` line is invalid,
+/// or if a line contains a non-symbol.
+/// - `Error::BadLength` if a line does not match its length or if the
+/// input ends before the `end` line.
+pub fn decode(input: &[u8]) -> Result<(u32, String, Vec<u8>), Error> {
+    ::lines::decode(&RADIX, input)
+}
+
+/// Encodes a line.
+///
+/// The output ends with `\n`.
+///
+/// # Panics
+///
+/// Panics if `input.len() > 45`.
+pub fn encode_line(input: &[u8]) -> String {
+    let mut output = Vec::new();
+    ::lines::encode_line(&RADIX, input, &mut output);
+    unsafe {
+        // This is valid because symbols are ascii.
+        String::from_utf8_unchecked(output)
+    }
+}
+
+/// Decodes a line.
+///
+/// The line terminator, `\n` or `\r\n`, is optional.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadCharacter` if the line contains a non-symbol.
+/// - `Error::BadLength` if the line does not match its length.
+pub fn decode_line(input: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut line = input;
+    if line.ends_with(b"\n") {
+        line = &line[.. line.len() - 1];
+    }
+    if line.ends_with(b"\r") {
+        line = &line[.. line.len() - 1];
+    }
+    let mut output = Vec::new();
+    try!(::lines::decode_line(&RADIX, line, 0, &mut output));
+    Ok(output)
+}
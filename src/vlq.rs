@@ -0,0 +1,148 @@
+//! Base64 VLQ Encoding.
+//!
+//! An integer is mapped to its magnitude shifted left by one, with the
+//! least significant bit set if the integer is negative. The result is
+//! encoded in groups of 5 bits, least significant first. Each group is
+//! stored in a 6-bit value whose bit 5 is set if more groups follow,
+//! and each value is encoded with the [`base64`](../base64/index.html)
+//! symbols.
+//!
+//! Source maps use this encoding for their `mappings` field: a
+//! segment is a sequence of integers without separator, segments are
+//! separated by `,`, and lines are separated by `;`. The integers are
+//! deltas from the previous segment and are not interpreted by this
+//! module.
+//!
+//! Decoding rejects overlong encodings, negative zero, and integers
+//! which do not fit in 64 bits.
+//!
+//! # Conformance
+//!
+//! [Source Map Revision 3](https://sourcemaps.info/spec.html)
+//! compliant.
+
+use base::Base;
+use decode::Error;
+use decode::Error::*;
+
+fn encode_into(x: i64, output: &mut Vec<u8>) {
+    let base = ::base64::base();
+    let mut v = if x < 0 { (x as i128).wrapping_neg() << 1 | 1 } else { (x as i128) << 1 };
+    loop {
+        let y = (v & 0x1f) as u8;
+        v >>= 5;
+        output.push(base.sym(if v == 0 { y } else { y | 0x20 }));
+        if v == 0 {
+            break;
+        }
+    }
+}
+
+/// Encodes an integer with allocation.
+pub fn encode(x: i64) -> String {
+    let mut output = Vec::new();
+    encode_into(x, &mut output);
+    unsafe {
+        // This is valid because symbols are ascii.
+        String::from_utf8_unchecked(output)
+    }
+}
+
+/// Decodes an integer from the start of the input.
+///
+/// This function returns the integer and the number of bytes read.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadCharacter` if the input contains a non-symbol, a
+/// redundant symbol, or a symbol which makes the integer overflow, or
+/// if it encodes negative zero.
+/// - `Error::BadLength` if the input ends before the last symbol.
+pub fn decode(input: &[u8]) -> Result<(i64, usize), Error> {
+    let base = ::base64::base();
+    let mut v = 0u128;
+    for (i, &x) in input.iter().enumerate() {
+        let y = try!(base.val(x).ok_or(BadCharacter(i))) as u128;
+        check!(BadCharacter(i), i < 13);
+        v |= (y & 0x1f) << 5 * i;
+        if y & 0x20 != 0 {
+            continue;
+        }
+        check!(BadCharacter(i), i == 0 || y != 0);
+        check!(BadCharacter(i), v != 1 && v >> 1 <= 1 << 63);
+        let m = (v >> 1) as i128;
+        let x = if v & 1 == 1 { -m } else { m };
+        check!(BadCharacter(i), x <= ::std::i64::MAX as i128);
+        return Ok((x as i64, i + 1));
+    }
+    Err(BadLength)
+}
+
+/// Encodes a segment with allocation.
+pub fn encode_segment(input: &[i64]) -> String {
+    let mut output = Vec::new();
+    for &x in input {
+        encode_into(x, &mut output);
+    }
+    unsafe {
+        // This is valid because symbols are ascii.
+        String::from_utf8_unchecked(output)
+    }
+}
+
+/// Decodes a segment with allocation.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`decode`](fn.decode.html).
+pub fn decode_segment(input: &[u8]) -> Result<Vec<i64>, Error> {
+    let mut output = Vec::new();
+    let mut i = 0;
+    while i < input.len() {
+        let (x, n) = try!(decode(&input[i ..]).map_err(|e| e.shift(i)));
+        output.push(x);
+        i += n;
+    }
+    Ok(output)
+}
+
+/// Encodes a `mappings` field with allocation.
+///
+/// The input is a list of lines, each line being a list of segments.
+pub fn encode_mappings(input: &[Vec<Vec<i64>>]) -> String {
+    let lines: Vec<String> = input.iter().map(|line| {
+        let segments: Vec<String> = line.iter().map(|s| encode_segment(s)).collect();
+        segments.join(",")
+    }).collect();
+    lines.join(";")
+}
+
+/// Decodes a `mappings` field with allocation.
+///
+/// Empty segments are ignored, and the empty input has no lines.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`decode`](fn.decode.html).
+pub fn decode_mappings(input: &[u8]) -> Result<Vec<Vec<Vec<i64>>>, Error> {
+    let mut output = Vec::new();
+    if input.is_empty() {
+        return Ok(output);
+    }
+    let mut pos = 0;
+    for line in input.split(|&x| x == b';') {
+        let mut segments = Vec::new();
+        for segment in line.split(|&x| x == b',') {
+            if !segment.is_empty() {
+                segments.push(try!(decode_segment(segment).map_err(|e| e.shift(pos))));
+            }
+            pos += segment.len() + 1;
+        }
+        output.push(segments);
+    }
+    Ok(output)
+}
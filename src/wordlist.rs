@@ -0,0 +1,67 @@
+//! Generic wordlist module.
+//!
+//! This module defines a generic interface, namely
+//! [`Wordlist`](trait.Wordlist.html), for the dictionaries used by
+//! word-based encodings, like [`bip39`](../bip39/index.html). Some
+//! modules embed their standard dictionaries, like
+//! [`skey::DICTIONARY`](../skey/constant.DICTIONARY.html) or
+//! [`pgpwords::EVEN`](../pgpwords/constant.EVEN.html). Otherwise,
+//! dictionaries are provided by the caller, usually as a slice of words
+//! in value order.
+
+/// Generic interface.
+///
+/// A wordlist defines its words in value order. Words must be
+/// distinct, non-empty, and must not contain spaces.
+pub trait Wordlist {
+    /// Returns the number of words.
+    fn len(&self) -> usize;
+
+    /// Returns whether there are no words.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the word of a value.
+    ///
+    /// # Panics
+    ///
+    /// May panic when input is not a value.
+    fn word(&self, x: usize) -> &str;
+
+    /// Returns the value of a word.
+    ///
+    /// In other words, when `val(w)` returns:
+    ///
+    /// - `Some(v)`: `w` is a word with value `v`.
+    /// - `None`: `w` is not a word.
+    fn val(&self, w: &str) -> Option<usize>;
+}
+
+impl<'a> Wordlist for [&'a str] {
+    fn len(&self) -> usize {
+        <[&str]>::len(self)
+    }
+
+    fn word(&self, x: usize) -> &str {
+        self[x]
+    }
+
+    fn val(&self, w: &str) -> Option<usize> {
+        self.iter().position(|&x| x == w)
+    }
+}
+
+impl Wordlist for [String] {
+    fn len(&self) -> usize {
+        <[String]>::len(self)
+    }
+
+    fn word(&self, x: usize) -> &str {
+        &self[x]
+    }
+
+    fn val(&self, w: &str) -> Option<usize> {
+        self.iter().position(|x| x == w)
+    }
+}
@@ -0,0 +1,215 @@
+//! yEnc Encoding.
+//!
+//! Each byte is encoded as itself plus 42 modulo 256. Critical
+//! characters, namely NUL, LF, CR, and `=`, are escaped as `=`
+//! followed by the character plus 64 modulo 256. Tabs and spaces at
+//! the beginning or end of a line, and dots at the beginning of a
+//! line, are also escaped. Lines are terminated by `\r\n`.
+//!
+//! The [`encode`](fn.encode.html) and [`decode`](fn.decode.html)
+//! functions work on whole single-part files: the data is preceded by
+//! a `=ybegin` line giving the line length, the size, and the name,
+//! and followed by a `=yend` line giving the size and the CRC-32 of
+//! the data. The [`encode_mut`](fn.encode_mut.html) and
+//! [`decode_mut`](fn.decode_mut.html) functions work on the data
+//! only, without allocation.
+//!
+//! Since the output is not ascii, encoding functions return bytes.
+//!
+//! # Conformance
+//!
+//! [yEnc 1.3](http://www.yenc.org/yenc-draft.1.3.txt) compliant for
+//! single-part files.
+
+use checksum::crc32;
+use decode::Error;
+use decode::Error::*;
+use lines::lines;
+
+/// The default line length.
+pub const LINE: usize = 128;
+
+/// Converts an input length to its maximum output length.
+///
+/// This function is meant to be used in conjunction with
+/// [`encode_mut`](fn.encode_mut.html). The actual output length
+/// depends on the number of escaped bytes and is returned by
+/// [`encode_mut`](fn.encode_mut.html).
+///
+/// # Panics
+///
+/// Panics if `line` is zero.
+pub fn encode_len(len: usize, line: usize) -> usize {
+    assert!(line > 0);
+    2 * len + 2 * (2 * len / line + 1)
+}
+
+/// Encodes data without allocation.
+///
+/// Lines are at most `line` characters long, or `line + 1` when they
+/// end with an escaped byte. The last line is not terminated. This
+/// function returns the output length.
+///
+/// # Panics
+///
+/// Panics if `line` is zero or if
+/// `output.len() < encode_len(input.len(), line)`.
+pub fn encode_mut(input: &[u8], line: usize, output: &mut [u8]) -> usize {
+    assert!(output.len() >= encode_len(input.len(), line));
+    let mut n = 0;
+    let mut col = 0;
+    for (i, &x) in input.iter().enumerate() {
+        let y = x.wrapping_add(42);
+        let last = col + 1 >= line || i + 1 == input.len();
+        let escape = match y {
+            0 | b'\n' | b'\r' | b'=' => true,
+            b'\t' | b' ' => col == 0 || last,
+            b'.' => col == 0,
+            _ => false,
+        };
+        if escape {
+            output[n] = b'=';
+            output[n + 1] = y.wrapping_add(64);
+            n += 2;
+            col += 2;
+        } else {
+            output[n] = y;
+            n += 1;
+            col += 1;
+        }
+        if col >= line && i + 1 < input.len() {
+            output[n] = b'\r';
+            output[n + 1] = b'\n';
+            n += 2;
+            col = 0;
+        }
+    }
+    n
+}
+
+/// Decodes data without allocation.
+///
+/// Line terminators, `\r` and `\n`, are ignored. This function returns
+/// the output length.
+///
+/// # Failures
+///
+/// Returns `Error::BadCharacter` if an escape character is not
+/// followed by an escaped character.
+///
+/// # Panics
+///
+/// Panics if `output.len() < input.len()`.
+pub fn decode_mut(input: &[u8], output: &mut [u8]) -> Result<usize, Error> {
+    assert!(output.len() >= input.len());
+    let mut n = 0;
+    let mut i = 0;
+    while i < input.len() {
+        let x = match input[i] {
+            b'\r' | b'\n' => {
+                i += 1;
+                continue;
+            }
+            b'=' => {
+                i += 1;
+                check!(BadCharacter(i - 1), i < input.len());
+                check!(BadCharacter(i), input[i] != b'\r' && input[i] != b'\n');
+                input[i].wrapping_sub(64)
+            }
+            x => x,
+        };
+        output[n] = x.wrapping_sub(42);
+        n += 1;
+        i += 1;
+    }
+    Ok(n)
+}
+
+/// Encodes a file.
+///
+/// # Panics
+///
+/// Panics if `line` is zero or if `name` contains `\r` or `\n`.
+pub fn encode(name: &str, input: &[u8], line: usize) -> Vec<u8> {
+    assert!(!name.contains('\r') && !name.contains('\n'));
+    let header = format!("=ybegin line={} size={} name={}\r\n", line, input.len(), name);
+    let trailer = format!("=yend size={} crc32={:08x}\r\n", input.len(), crc32(input));
+    let mut output = header.into_bytes();
+    let start = output.len();
+    output.resize(start + encode_len(input.len(), line), 0);
+    let len = encode_mut(input, line, &mut output[start ..]);
+    output.truncate(start + len);
+    if !input.is_empty() {
+        output.extend_from_slice(b"\r\n");
+    }
+    output.extend_from_slice(trailer.as_bytes());
+    output
+}
+
+// Returns the value of a keyword in a header line.
+fn field<'a>(line: &'a [u8], key: &[u8]) -> Option<(usize, &'a [u8])> {
+    let mut pos = 0;
+    for word in line.split(|&x| x == b' ') {
+        if word.starts_with(key) && word.get(key.len()) == Some(&b'=') {
+            return Some((pos + key.len() + 1, &word[key.len() + 1 ..]));
+        }
+        pos += word.len() + 1;
+    }
+    None
+}
+
+fn number(value: &[u8], pos: usize, radix: u64) -> Result<u64, Error> {
+    check!(BadCharacter(pos), !value.is_empty());
+    let mut x = 0u64;
+    for (i, &c) in value.iter().enumerate() {
+        let v = try!((c as char).to_digit(radix as u32).ok_or(BadCharacter(pos + i)));
+        check!(BadCharacter(pos + i), x <= (u64::MAX - v as u64) / radix);
+        x = x * radix + v as u64;
+    }
+    Ok(x)
+}
+
+/// Decodes a file.
+///
+/// This function returns the name and the data. Lines before the
+/// `=ybegin` line and after the `=yend` line are ignored.
+///
+/// # Failures
+///
+/// Decoding may fail in the following circumstances:
+///
+/// - `Error::BadCharacter` if a header line is invalid or if the data
+/// is invalid, as defined by [`decode_mut`](fn.decode_mut.html).
+/// Multi-part files are not supported: `=ypart` lines are invalid.
+/// - `Error::BadLength` if there is no `=ybegin` or `=yend` line, or
+/// if the sizes do not match the data.
+/// - `Error::BadChecksum` if the CRC-32 does not match the data.
+pub fn decode(input: &[u8]) -> Result<(String, Vec<u8>), Error> {
+    let mut lines = lines(input).into_iter().skip_while(|&(_, x)| !x.starts_with(b"=ybegin "));
+    let (pos, header) = try!(lines.next().ok_or(BadLength));
+    // The name is the rest of the line and may contain spaces.
+    let (i, _) = try!(field(header, b"name").ok_or(BadCharacter(pos)));
+    let name = try!(String::from_utf8(header[i ..].to_vec())
+                    .map_err(|e| BadCharacter(pos + i + e.utf8_error().valid_up_to())));
+    let (i, size) = try!(field(header, b"size").ok_or(BadCharacter(pos)));
+    let size = try!(number(size, pos + i, 10));
+    let mut output = Vec::new();
+    let (pos, trailer) = loop {
+        let (pos, line) = try!(lines.next().ok_or(BadLength));
+        check!(BadCharacter(pos), !line.starts_with(b"=ypart "));
+        if line.starts_with(b"=yend ") {
+            break (pos, line);
+        }
+        let len = output.len();
+        output.resize(len + line.len(), 0);
+        let n = try!(decode_mut(line, &mut output[len ..]).map_err(|e| e.shift(pos)));
+        output.truncate(len + n);
+    };
+    check!(BadLength, size == output.len() as u64);
+    let (i, value) = try!(field(trailer, b"size").ok_or(BadCharacter(pos)));
+    check!(BadLength, try!(number(value, pos + i, 10)) == size);
+    if let Some((i, value)) = field(trailer, b"crc32") {
+        check!(BadChecksum, try!(number(value, pos + i, 16)) == crc32(&output) as u64);
+    }
+    Ok((name, output))
+}
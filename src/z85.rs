@@ -0,0 +1,122 @@
+//! Z85 Encoding.
+//!
+//! Symbols are
+//! `0-9a-zA-Z.-:+=^!/*?&<>()[]{}@%$#`. Each group of 4 bytes is
+//! encoded as a big-endian number with 5 symbols. There is no
+//! padding: the input length must be a multiple of 4 when encoding
+//! and a multiple of 5 when decoding.
+//!
+//! See the generic [`radix`](../radix/index.html) chunked functions
+//! for details about this module functions.
+//!
+//! # Conformance
+//!
+//! [ZeroMQ RFC 32](https://rfc.zeromq.org/spec/32/) compliant.
+
+use decode::Error;
+use decode::Error::*;
+use radix::Opt;
+
+const X_: u8 = 128;
+
+/// Force static dispatch.
+pub enum Static {}
+
+static RADIX: Opt<Static> = Opt {
+    val: ascii!(
+        X_, 68, X_, 84, 83, 82, 72, X_, 75, 76, 70, 65, X_, 63, 62, 69,
+        0_, 1_, 2_, 3_, 4_, 5_, 6_, 7_, 8_, 9_, 64, X_, 73, 66, 74, 71,
+        81, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50,
+        51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 77, X_, 78, 67, X_,
+        X_, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+        25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 79, X_, 80, X_, X_),
+    sym: b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#",
+    _phantom: ::std::marker::PhantomData
+};
+
+/// The block size in bytes.
+pub const BLOCK: usize = 4;
+
+/// Gives access to the radix.
+pub fn radix() -> &'static Opt<Static> {
+    &RADIX
+}
+
+/// Converts an input length to its output length.
+///
+/// # Panics
+///
+/// Panics if `len` is not a multiple of 4.
+pub fn encode_len(len: usize) -> usize {
+    assert_eq!(len % BLOCK, 0);
+    ::radix::encode_chunked_len(&RADIX, BLOCK, len)
+}
+
+/// Converts an input length to its output length.
+///
+/// # Failures
+///
+/// Returns `Error::BadLength` if `len` is not a multiple of 5.
+pub fn decode_len(len: usize) -> Result<usize, Error> {
+    check!(BadLength, len % 5 == 0);
+    ::radix::decode_chunked_len(&RADIX, BLOCK, len)
+}
+
+/// Encodes without allocation.
+///
+/// # Panics
+///
+/// Panics if `input.len()` is not a multiple of 4 or if
+/// `output.len() != encode_len(input.len())`.
+pub fn encode_mut(input: &[u8], output: &mut [u8]) {
+    assert_eq!(output.len(), encode_len(input.len()));
+    ::radix::encode_chunked_mut(&RADIX, BLOCK, input, output)
+}
+
+/// Decodes without allocation.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`Error`](../decode/enum.Error.html). It returns
+/// `Error::BadLength` if `input.len()` is not a multiple of 5.
+///
+/// # Panics
+///
+/// Panics if `output.len() != decode_len(input.len()).unwrap()`.
+pub fn decode_mut(input: &[u8], output: &mut [u8]) -> Result<(), Error> {
+    check!(BadLength, input.len() % 5 == 0);
+    ::radix::decode_chunked_mut(&RADIX, BLOCK, input, output)
+}
+
+/// Encodes with allocation.
+///
+/// # Panics
+///
+/// Panics if `input.len()` is not a multiple of 4.
+pub fn encode(input: &[u8]) -> String {
+    assert_eq!(input.len() % BLOCK, 0);
+    ::radix::encode_chunked(&RADIX, BLOCK, input)
+}
+
+/// Decodes with allocation.
+///
+/// # Failures
+///
+/// Decoding may fail in the circumstances defined by
+/// [`Error`](../decode/enum.Error.html). It returns
+/// `Error::BadLength` if `input.len()` is not a multiple of 5.
+pub fn decode(input: &[u8]) -> Result<Vec<u8>, Error> {
+    check!(BadLength, input.len() % 5 == 0);
+    ::radix::decode_chunked(&RADIX, BLOCK, input)
+}
+
+#[test]
+fn check() {
+    use radix::Radix;
+    assert_eq!(RADIX.val.len(), 256);
+    assert_eq!(RADIX.radix(), 85);
+    for v in 0 .. 85 {
+        assert_eq!(RADIX.val(RADIX.sym(v)), Some(v));
+    }
+}
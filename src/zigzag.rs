@@ -0,0 +1,41 @@
+//! ZigZag Encoding.
+//!
+//! Signed integers are mapped to unsigned integers such that integers
+//! of small magnitude are mapped to small integers: 0 is mapped to 0,
+//! -1 to 1, 1 to 2, -2 to 3, and so on. This is meant to be combined
+//! with the [`leb128`](../leb128/index.html) unsigned encoding.
+//!
+//! # Conformance
+//!
+//! [Protocol Buffers](https://developers.google.com/protocol-buffers/docs/encoding#signed-integers)
+//! compliant.
+
+/// Encodes a 32-bit integer.
+pub fn encode_i32(x: i32) -> u32 {
+    (x << 1 ^ x >> 31) as u32
+}
+
+/// Decodes a 32-bit integer.
+pub fn decode_i32(x: u32) -> i32 {
+    (x >> 1) as i32 ^ -((x & 1) as i32)
+}
+
+/// Encodes a 64-bit integer.
+pub fn encode_i64(x: i64) -> u64 {
+    (x << 1 ^ x >> 63) as u64
+}
+
+/// Decodes a 64-bit integer.
+pub fn decode_i64(x: u64) -> i64 {
+    (x >> 1) as i64 ^ -((x & 1) as i64)
+}
+
+/// Encodes a 128-bit integer.
+pub fn encode_i128(x: i128) -> u128 {
+    (x << 1 ^ x >> 127) as u128
+}
+
+/// Decodes a 128-bit integer.
+pub fn decode_i128(x: u128) -> i128 {
+    (x >> 1) as i128 ^ -((x & 1) as i128)
+}
@@ -1,3 +1,4 @@
+#[macro_use]
 extern crate data_encoding;
 
 macro_rules! test {
@@ -95,6 +96,2675 @@ test!{
     test(b"foobar", b"Zm9vYmFy");
 }
 
+test!{
+    fn zbase32;
+    test(b"", b"");
+    test(b"f", b"ca======");
+    test(b"foobar", b"c3zs6aubqe======");
+    assert_eq!(encode_nopad(b"f"), "ca");
+    assert_eq!(encode_nopad(b"fo"), "c3zo");
+    assert_eq!(encode_nopad(b"foo"), "c3zs6");
+    assert_eq!(encode_nopad(b"foob"), "c3zs6ao");
+    assert_eq!(encode_nopad(b"fooba"), "c3zs6aub");
+    assert_eq!(encode_nopad(b"foobar"), "c3zs6aubqe");
+    assert_eq!(decode_nopad(b"c3zs6aubqe").unwrap(), b"foobar");
+    assert_eq!(decode_nopad(b"C3ZS6AUBQE"), Err(BadCharacter(0)));
+}
+
+test!{
+    fn geohash;
+    test(b"", b"");
+    test(b"f", b"ds======");
+    test(b"fo", b"dtrh====");
+    test(b"foo", b"dtrqy===");
+    test(b"foob", b"dtrqysh=");
+    test(b"fooba", b"dtrqysm1");
+    test(b"foobar", b"dtrqysm1f8======");
+    assert_eq!(decode_nopad(b"dtrqysm1f8").unwrap(), b"foobar");
+    assert_eq!(decode_nopad(b"u4pruydqqvj"), Err(BadLength));
+}
+
+test!{
+    fn base32wordsafe;
+    test(b"", b"");
+    test(b"f", b"Jj======");
+    test(b"fo", b"JmhR====");
+    test(b"foo", b"Jmhgw===");
+    test(b"foob", b"JmhgwjR=");
+    test(b"fooba", b"JmhgwjX3");
+    test(b"foobar", b"JmhgwjX3PC======");
+    assert_eq!(encode_nopad(b"foobar"), "JmhgwjX3PC");
+    assert_eq!(decode_nopad(b"JmhgwjX3PC").unwrap(), b"foobar");
+    assert_eq!(decode(b"JmhgwjA3"), Err(BadCharacter(6)));
+}
+
+test!{
+    fn base64wordsafe;
+    test(b"", b"");
+    test(b"f", b"HQ==");
+    test(b"fo", b"HXx=");
+    test(b"foo", b"HXyg");
+    test(b"foob", b"HXygGQ==");
+    test(b"fooba", b"HXygGX&=");
+    test(b"foobar", b"HXygGX*k");
+    assert_eq!(encode_nopad(b"foob"), "HXygGQ");
+    assert_eq!(decode_nopad(b"HXygGQ").unwrap(), b"foob");
+    assert_eq!(decode(b"HXygGXak"), Err(BadCharacter(6)));
+}
+
+test!{
+    fn dnscurve;
+    test(b"", b"");
+    test(b"f", b"63======");
+    test(b"fo", b"6vv0====");
+    test(b"foo", b"6vvy6===");
+    test(b"foob", b"6vvy6k1=");
+    test(b"fooba", b"6vvy6k5d");
+    test(b"foobar", b"6vvy6k5dl3======");
+    assert_eq!(encode_nopad(b"\x64\x88"), "4321");
+    assert_eq!(decode_nopad(b"4321").unwrap(), b"\x64\x88");
+    assert_eq!(decode_nopad(b"4322"), Err(BadPadding));
+    assert_eq!(decode(b"6z======"), Err(BadPadding));
+    assert_eq!(decode(b"6vvy6k5dl3"), Err(BadLength));
+}
+
+test!{
+    fn base32lower;
+    test(b"", b"");
+    test(b"f", b"my======");
+    test(b"fo", b"mzxq====");
+    test(b"foo", b"mzxw6===");
+    test(b"foob", b"mzxw6yq=");
+    test(b"fooba", b"mzxw6ytb");
+    test(b"foobar", b"mzxw6ytboi======");
+    assert_eq!(encode_nopad(b"foobar"), "mzxw6ytboi");
+    assert_eq!(decode_nopad(b"mzxw6ytboi").unwrap(), b"foobar");
+    assert_eq!(decode(b"MY======"), Err(BadCharacter(0)));
+}
+
+test!{
+    fn hexlower;
+    test(b"", b"");
+    test(b"f", b"66");
+    test(b"fo", b"666f");
+    test(b"foo", b"666f6f");
+    test(b"foob", b"666f6f62");
+    test(b"fooba", b"666f6f6261");
+    test(b"foobar", b"666f6f626172");
+    assert_eq!(decode(b"666F6F626172").unwrap(), b"foobar");
+    assert_eq!(decode(b"666f6F626172").unwrap(), b"foobar");
+    assert_eq!(decode(b"66g6"), Err(BadCharacter(2)));
+    assert_eq!(decode(b"666"), Err(BadLength));
+}
+
+test!{
+    fn modhex;
+    test(b"", b"");
+    test(b"f", b"hh");
+    test(b"fo", b"hhhv");
+    test(b"foobar", b"hhhvhvhdhbid");
+    test(b"\x00\xff", b"ccvv");
+    assert_eq!(decode(b"hhha"), Err(BadCharacter(3)));
+    assert_eq!(decode(b"HH"), Err(BadCharacter(0)));
+    assert_eq!(decode(b"hhh"), Err(BadLength));
+}
+
+test!{
+    fn dna;
+    test(b"", b"");
+    test(b"f", b"CGCG");
+    test(b"foobar", b"CGCGCGTTCGTTCGAGCGACCTAG");
+    test(b"\x00\xff", b"AAAATTTT");
+    assert_eq!(decode(b"cgcgCGTT").unwrap(), b"fo");
+    assert_eq!(decode_nopad(b"acgt").unwrap(), b"\x1b");
+    assert!(is_valid(b"AcGt"));
+    assert_eq!(decode(b"CGCN"), Err(BadCharacter(3)));
+    assert_eq!(decode(b"CGC"), Err(BadLength));
+    assert_eq!(encode(&reverse_complement(b"fo")), "AACGCGCG");
+    assert_eq!(reverse_complement(&reverse_complement(b"foobar")), b"foobar");
+}
+
+test!{
+    fn nixbase32;
+    test(b"", b"");
+    test(b"f", b"36");
+    test(b"fo", b"0vv6");
+    test(b"foo", b"6yvv6");
+    test(b"foob", b"1i6yvv6");
+    test(b"fooba", b"c5i6yvv6");
+    test(b"foobar", b"3jc5i6yvv6");
+    let hash = data_encoding::hex::decode(
+        b"E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855").unwrap();
+    test(&hash, b"0mdqa9w1p6cmli6976v4wi0sw9r4p5prkj7lzfd1877wk11c9c73");
+    assert_eq!(decode(b"3jc5i6yve6"), Err(BadCharacter(8)));
+    assert_eq!(decode(b"7z").unwrap(), b"\xff");
+    assert_eq!(decode(b"8z"), Err(BadPadding));
+}
+
+test!{
+    fn crypt;
+    test(b"", b"");
+    test(b"f", b"a/==");
+    test(b"fo", b"ax4=");
+    test(b"foo", b"axqP");
+    test(b"foob", b"axqPW/==");
+    test(b"fooba", b"axqPW34=");
+    test(b"foobar", b"axqPW3aQ");
+    assert_eq!(encode_nopad(b"fooba"), "axqPW34");
+    assert_eq!(decode_nopad(b"axqPW34").unwrap(), b"fooba");
+    assert_eq!(decode_nopad(b"axqPW3z"), Err(BadPadding));
+    assert_eq!(decode_nopad(b"axqP+/"), Err(BadCharacter(4)));
+}
+
+test!{
+    fn bcrypt;
+    test(b"", b"");
+    test(b"f", b"Xe==");
+    test(b"fo", b"Xk6=");
+    test(b"foo", b"Xk7t");
+    test(b"foob", b"Xk7tWe==");
+    test(b"fooba", b"Xk7tWkC=");
+    test(b"foobar", b"Xk7tWkDw");
+    let hash = b"$2a$10$N9qo8uLOickgx2ZMRZoMyeIjZAgcfl7p92ldGxad68LJZdL17lhWy";
+    assert_eq!(decode_nopad(&hash[7 .. 29]).unwrap(),
+               b"\x3f\xfb\x2a\xfb\x03\x50\x91\xe9\xa2\xcf\x86\xce\x4d\xba\x8e\xd2");
+    let digest = decode_nopad(&hash[29 ..]).unwrap();
+    assert_eq!(digest.len(), 23);
+    assert_eq!(encode_nopad(&digest).as_bytes(), &hash[29 ..]);
+    assert_eq!(decode_nopad(b"Xk7tWkD"), Err(BadPadding));
+    assert_eq!(decode_nopad(b"Xk7t+/"), Err(BadCharacter(4)));
+}
+
+test!{
+    fn base32crockford;
+    test(b"", b"");
+    test(b"f", b"CR");
+    test(b"fo", b"CSQG");
+    test(b"foo", b"CSQPY");
+    test(b"foob", b"CSQPYRG");
+    test(b"fooba", b"CSQPYRK1");
+    test(b"foobar", b"CSQPYRK1E8");
+    assert_eq!(decode(b"csqp-yrk1-e8").unwrap(), b"foobar");
+    assert_eq!(decode(b"oo0g").unwrap(), b"\x00\x01");
+    assert_eq!(decode(b"iL00").unwrap(), decode(b"1100").unwrap());
+    assert_eq!(decode(b"CS-UG"), Err(BadCharacter(3)));
+    assert_eq!(decode(b"CS"), Err(BadPadding));
+    assert_eq!(decode(b"CSQ"), Err(BadLength));
+    assert_eq!(encode_check(b""), "0");
+    assert_eq!(encode_check(b"f"), "CRW");
+    assert_eq!(encode_check(b"fooba"), "CSQPYRK1U");
+    assert_eq!(encode_check(b"foobar"), "CSQPYRK1E86");
+    assert_eq!(decode_check(b"0").unwrap(), b"");
+    assert_eq!(decode_check(b"csqp-yrk1-u").unwrap(), b"fooba");
+    assert_eq!(decode_check(b"CSQPYRK1E87"), Err(BadChecksum));
+    assert_eq!(decode_check(b"CSQPYRK1E8!"), Err(BadCharacter(10)));
+    assert_eq!(decode_check(b"-"), Err(BadLength));
+}
+
+test!{
+    fn base10;
+    test(b"", b"");
+    test(b"\x00", b"0");
+    test(b"\x00\x00\x01", b"001");
+    test(b"\x01\x00", b"256");
+    test(b"f", b"102");
+    test(b"fo", b"26223");
+    test(b"foo", b"6713199");
+    test(b"foobar", b"112628796121458");
+    test(b"\xff\xff\xff\xff\xff\xff\xff\xff", b"18446744073709551615");
+    assert_eq!(decode(b"12a"), Err(BadCharacter(2)));
+    assert_eq!(decode(b"-1"), Err(BadCharacter(0)));
+}
+
+test!{
+    fn base36;
+    test(b"", b"");
+    test(b"\x00", b"0");
+    test(b"\x00\x00\x01", b"001");
+    test(b"f", b"2u");
+    test(b"fo", b"k8f");
+    test(b"foo", b"3zvxr");
+    test(b"foobar", b"13x8yd7ywi");
+    test(b"Hello World", b"azw5bz2xp56m4qyck");
+    test(b"\xff\xff\xff\xff\xff\xff\xff\xff", b"3w5e11264sgsf");
+    assert_eq!(decode(b"0A"), Err(BadCharacter(1)));
+    assert_eq!(decode(b"-"), Err(BadCharacter(0)));
+}
+
+test!{
+    fn base62;
+    test(b"", b"");
+    test(b"\x00", b"0");
+    test(b"\x00\x00\x01", b"001");
+    test(b"f", b"1e");
+    test(b"foobar", b"VytN8Wjy");
+    test(b"Hello World", b"73XpUgyMwkGr29M");
+    assert_eq!(decode(b"73Xp-UgyM"), Err(BadCharacter(4)));
+    assert_eq!(encode_chunked(b""), "");
+    assert_eq!(encode_chunked(b"\x00"), "00");
+    assert_eq!(encode_chunked(b"f"), "1e");
+    assert_eq!(encode_chunked(b"foobar"), "0VytN8Wjy");
+    assert_eq!(encode_chunked(b"Hello World"), "6DMW88LpRol0VSnE");
+    assert_eq!(encode_chunked(&[255; 9]), "LygHa16AHYF47");
+    assert_eq!(encode_chunked_len(17), 24);
+    assert_eq!(decode_chunked(b"6DMW88LpRol0VSnE").unwrap(), b"Hello World");
+    assert_eq!(decode_chunked(b"LygHa16AHYF47").unwrap(), &[255; 9]);
+    assert_eq!(decode_chunked(b"L"), Err(BadLength));
+    assert_eq!(decode_chunked(b"LygH"), Err(BadLength));
+    assert_eq!(decode_chunked(b"zz"), Err(BadCharacter(0)));
+    assert_eq!(decode_chunked(b"zzzzzzzzzzz"), Err(BadCharacter(0)));
+    assert_eq!(decode_chunked(b"LygHa16AHYF48"), Err(BadCharacter(11)));
+    assert_eq!(decode_chunked(b"LygHa16AHYF4-"), Err(BadCharacter(12)));
+}
+
+test!{
+    fn base58;
+    test(b"", b"");
+    test(b"\x00", b"1");
+    test(b"\x00\x00\x28\x7f\xb4\xcd", b"11233QC4");
+    test(b"f", b"2m");
+    test(b"foobar", b"t1Zv2yaZ");
+    test(b"Hello World", b"JxF12TrwUP45BMd");
+    test(b"\x00\xeb\x15\x23\x1d\xfc\xeb\x60\x92\x58\x86\xb6\x7d\x06\x52\x99\x92\x59\x15\xae\xb1\x72\xc0\x66\x47",
+         b"1NS17iag9jJgTHD1VXjvLCEnZuQ3rJDE9L");
+    assert_eq!(decode(b"1O"), Err(BadCharacter(1)));
+    assert_eq!(decode(b"0"), Err(BadCharacter(0)));
+}
+
+test!{
+    fn base58flickr;
+    test(b"", b"");
+    test(b"\x00", b"1");
+    test(b"\x00\x00\x28\x7f\xb4\xcd", b"11233pc4");
+    test(b"Hello World", b"iXf12sRWto45bmC");
+    assert_eq!(decode(b"1l"), Err(BadCharacter(1)));
+}
+
+test!{
+    fn base58ripple;
+    test(b"", b"");
+    test(b"\x00", b"r");
+    test(b"\x00\x00\x28\x7f\xb4\xcd", b"rrpssQUh");
+    test(b"Hello World", b"JxErpTiA7PhnBMd");
+    assert_eq!(decode(b"r0"), Err(BadCharacter(1)));
+}
+
+test!{
+    fn base94;
+    test(b"", b"");
+    test(b"\x00", b"!");
+    test(b"\x00\x00\x01", b"!!\"");
+    test(b"f", b"\")");
+    test(b"foobar", b"\"f9Rh7x1");
+    test(b"Hello World", b"\"zuW#Trd*6SEzg");
+    assert_eq!(decode(b"\"zuW Trd"), Err(BadCharacter(4)));
+    assert_eq!(encode_chunked(b""), "");
+    assert_eq!(encode_chunked(b"\x00"), "!!");
+    assert_eq!(encode_chunked(b"foobar"), "\"f9Rh7x1");
+    assert_eq!(encode_chunked(b"Hello World"), "9ka{voR\\|pm$.3");
+    assert_eq!(encode_chunked(&[255; 9]), "x`Ttp}n.;m(");
+    assert_eq!(decode_chunked(b"9ka{voR\\|pm$.3").unwrap(), b"Hello World");
+    assert_eq!(encode_chunked_len(18), 22);
+}
+
+#[test]
+fn base58check() {
+    use data_encoding::base58check::{encode, decode};
+    use data_encoding::decode::Error::*;
+    fn test(v: u8, x: &[u8], y: &[u8]) {
+        assert_eq!(&encode(v, x).into_bytes() as &[u8], y);
+        assert_eq!(decode(y).unwrap(), (v, x.to_vec()));
+    }
+    test(0, b"", b"1Wh4bh");
+    test(5, b"foobar", b"2LfjEAJBHrLhHyU");
+    test(0x80, &[0; 32], b"5HpHagT65TZzG1PH3CSu63k8DbpvD8s5ip4nEB3kEsreAbuatmU");
+    assert_eq!(decode(b"1Wh4bi"), Err(BadChecksum));
+    assert_eq!(decode(b"2LfjEAJBHrLhHyV"), Err(BadChecksum));
+    assert_eq!(decode(b"1Wh4b"), Err(BadLength));
+    assert_eq!(decode(b"1Wh4b0"), Err(BadCharacter(5)));
+}
+
+test!{
+    fn base58monero;
+    test(b"", b"");
+    test(b"\x00", b"11");
+    test(b"\x39", b"1z");
+    test(b"\xff", b"5Q");
+    test(b"\x00\x00", b"111");
+    test(b"\xff\xff", b"LUv");
+    test(b"\x00\x00\x00\x00\x00\x00\x00\x00", b"11111111111");
+    test(b"\xff\xff\xff\xff\xff\xff\xff\xff", b"jpXCZedGfVQ");
+    test(b"\xff\xff\xff\xff\xff\xff\xff\xff\xff", b"jpXCZedGfVQ5Q");
+    test(b"\x06\x15\x60\x13\x76\x28\x79\xf7\xff\xff\xff\xff\xff",
+         b"22222222222VtB5VXc");
+    assert_eq!(decode(b"1"), Err(BadLength));
+    assert_eq!(decode(b"111111111111"), Err(BadLength));
+    assert_eq!(decode(b"5R"), Err(BadCharacter(0)));
+    assert_eq!(decode(b"jpXCZedGfVR"), Err(BadCharacter(0)));
+    assert_eq!(decode(b"1O"), Err(BadCharacter(1)));
+}
+
+test!{
+    fn ascii85;
+    test(b"", b"");
+    test(b"\x00", b"!!");
+    test(b"\x00\x00\x00\x00", b"z");
+    test(b"\x00\x00\x00\x00\x00", b"z!!");
+    test(b"\x00\x00\x00\x00\xff", b"zrr");
+    test(b"\xff\xff\xff\xff", b"s8W-!");
+    test(b"f", b"Ac");
+    test(b"foobar", b"AoDTs@<)");
+    test(b"Man is distinguished", b"9jqo^BlbD-BleB1DJ+*+F(f,q");
+    assert_eq!(encode_delimited(b""), "<~~>");
+    assert_eq!(encode_delimited(b"foobar"), "<~AoDTs@<)~>");
+    assert_eq!(decode(b"<~AoDTs@<)~>").unwrap(), b"foobar");
+    assert_eq!(decode(b"AoDTs@<)~>").unwrap(), b"foobar");
+    assert_eq!(decode(b"<~AoD Ts\n@<)\r\n~>").unwrap(), b"foobar");
+    assert_eq!(decode(b"!!!!!").unwrap(), b"\x00\x00\x00\x00");
+    assert_eq!(decode(b"!z"), Err(BadCharacter(1)));
+    assert_eq!(decode(b"<~Ac~"), Err(BadCharacter(4)));
+    assert_eq!(decode(b"Av"), Err(BadCharacter(1)));
+    assert_eq!(decode(b"z s8W-\""), Err(BadCharacter(2)));
+    assert_eq!(decode(b"uu"), Err(BadCharacter(0)));
+    assert_eq!(decode(b"<~A~>"), Err(BadLength));
+}
+
+test!{
+    fn z85;
+    test(b"", b"");
+    test(b"\x00\x00\x00\x00", b"00000");
+    test(b"\xff\xff\xff\xff", b"%nSc0");
+    test(b"\x86\x4f\xd2\x6f\xb5\x59\xf7\x5b", b"HelloWorld");
+    assert_eq!(decode_len(10), Ok(8));
+    assert_eq!(decode_len(9), Err(BadLength));
+    assert_eq!(decode(b"Hello"), Ok(vec![0x86, 0x4f, 0xd2, 0x6f]));
+    assert_eq!(decode(b"HelloWorl"), Err(BadLength));
+    assert_eq!(decode(b"Hello\"orld"), Err(BadCharacter(5)));
+    assert_eq!(decode(b"%nSc1"), Err(BadCharacter(0)));
+}
+
+#[test]
+#[should_panic]
+fn z85_encode_length() {
+    data_encoding::z85::encode(b"foo");
+}
+
+#[test]
+fn base85rfc1924() {
+    use data_encoding::base85rfc1924::*;
+    use data_encoding::decode::Error::*;
+    let addr = [0x10, 0x80, 0, 0, 0, 0, 0, 0, 0, 0x08, 0x08, 0, 0x20, 0x0c, 0x41, 0x7a];
+    assert_eq!(encode(&addr), "4)+k&C#VzJ4br>0wv%Yp");
+    assert_eq!(decode(b"4)+k&C#VzJ4br>0wv%Yp"), Ok(addr));
+    assert_eq!(encode(&[0; 16]), "00000000000000000000");
+    assert_eq!(encode_u128(!0), "=r54lj&NUUO~Hi%c2ym0");
+    assert_eq!(decode_u128(b"=r54lj&NUUO~Hi%c2ym0"), Ok(!0));
+    assert_eq!(decode_u128(b"00000000000000000001"), Ok(1));
+    assert_eq!(decode(b"=r54lj&NUUO~Hi%c2ym1"), Err(BadCharacter(0)));
+    assert_eq!(decode(b"4)+k&C#VzJ4br>0wv%Y"), Err(BadLength));
+    assert_eq!(decode(b"4)+k&C#VzJ4br>0wv%Y\""), Err(BadCharacter(19)));
+}
+
+test!{
+    fn base85git;
+    test(b"", b"");
+    test(b"f", b"AW&i*H\n");
+    test(b"foobar", b"FW^Zp|VR8Tf\n");
+    test(&(0 .. 60).collect::<Vec<u8>>(),
+         b"z009C61O)~M2nh-c3=Iws5D^j+6crX17#SKH9337XAR!_nBqb&%C@Cr{EG;fCFflSS\nHG&MFiI5|2y\n");
+    assert_eq!(encode_len(60), 67 + 12);
+    assert_eq!(decode(b"AW&i*H"), Err(BadLength));
+    assert_eq!(decode(b"AW&i*Hx"), Err(BadCharacter(6)));
+    assert_eq!(decode(b"BW&i*H\nAW&i*H"), Err(BadLength));
+    assert_eq!(decode(b"0W&i*H\n"), Err(BadCharacter(0)));
+    assert_eq!(decode(b"AW&i\"H\n"), Err(BadCharacter(4)));
+    assert_eq!(decode(b"A|NsC1\n"), Err(BadCharacter(1)));
+    assert_eq!(decode(b"AW&i*I\n"), Err(BadPadding));
+}
+
+test!{
+    fn base91;
+    test(b"", b"");
+    test(b"\x00", b"AA");
+    test(b"\xff", b"/C");
+    test(b"\xff\xff", b"B\"H");
+    test(b"f", b"LB");
+    test(b"fo", b"drD");
+    test(b"foo", b"dr.J");
+    test(b"foobar", b"dr/2s)uC");
+    test(b"Hello, World!", b">OwJh>}AQ;r@@Y?F");
+    assert_eq!(decode(b"dr.'"), Err(BadCharacter(3)));
+    assert_eq!(decode(b"A"), Err(BadPadding));
+    assert_eq!(decode(b"A~"), Err(BadPadding));
+}
+
+#[test]
+fn base91_random() {
+    use data_encoding::base91::{encode, decode};
+    let mut x = 1u32;
+    let mut data = Vec::new();
+    for _ in 0 .. 1000 {
+        x = x.wrapping_mul(1103515245).wrapping_add(12345);
+        data.push((x >> 16) as u8);
+        assert_eq!(decode(encode(&data).as_bytes()).unwrap(), data);
+        assert!(encode(&data).len() <= data_encoding::base91::encode_len(data.len()));
+    }
+}
+
+test!{
+    fn base45;
+    test(b"", b"");
+    test(b"AB", b"BB8");
+    test(b"Hello!!", b"%69 VD92EX0");
+    test(b"base-45", b"UJCLQE7W581");
+    test(b"ietf!", b"QED8WEX0");
+    test(b"\xff\xff", b"FGW");
+    test(b"\xff", b"U5");
+    assert_eq!(decode(b"GGW"), Err(BadCharacter(0)));
+    assert_eq!(decode(b"BB8V5"), Err(BadCharacter(3)));
+    assert_eq!(decode(b"BB8B"), Err(BadLength));
+    assert_eq!(decode(b"BB8b5"), Err(BadCharacter(3)));
+    assert_eq!(decode(b"BBa"), Err(BadCharacter(2)));
+}
+
+test!{
+    fn base24;
+    test(b"", b"");
+    test(b"\x00\x00\x00\x00", b"ZZZZZZZ");
+    test(b"\x00\x00\x00\x01", b"ZZZZZZA");
+    test(b"\x00\x00\x00\x30", b"ZZZZZCZ");
+    test(b"\x88\x55\x33\x88", b"5YEAK2Z");
+    test(b"\xff\xff\xff\xff", b"X5GGBH7");
+    test(b"\x25\x89\x69\x84\x12\x54\x78\x54\x65\x98\x56\x32\x51\x45\x26\x58",
+         b"2FC28KTA66WRST4XAHRRCF237S8Z");
+    assert_eq!(encode_grouped(b"\x25\x89\x69\x84\x12\x54\x78\x54\x65\x98\x56\x32", 5),
+               "2FC28-KTA66-WRST4-XAHRR-C");
+    assert_eq!(decode(b"2fc28-kta66-wrst4-xahrr-c").unwrap(),
+               b"\x25\x89\x69\x84\x12\x54\x78\x54\x65\x98\x56\x32");
+    assert_eq!(decode(b"ZZZZZZ"), Err(BadLength));
+    assert_eq!(decode(b"ZZ-ZZZZ-Z-Z"), Err(BadLength));
+    assert_eq!(decode(b"ZZ-ZZZZ1"), Err(BadCharacter(7)));
+    assert_eq!(decode(b"YYYYYYY"), Err(BadCharacter(0)));
+}
+
+#[test]
+fn bech32() {
+    use data_encoding::bech32::*;
+    use data_encoding::bech32::Variant::*;
+    use data_encoding::decode::Error::*;
+    fn test(hrp: &str, x: &[u8], v: Variant, y: &str) {
+        assert_eq!(encode(hrp, x, v), y);
+        assert_eq!(decode(y.as_bytes()).unwrap(), (hrp.to_string(), x.to_vec(), v));
+    }
+    fn test_values(hrp: &str, x: &[u8], v: Variant, y: &str) {
+        assert_eq!(encode_values(hrp, x, v), y);
+        assert_eq!(decode_values(y.as_bytes()).unwrap(), (hrp.to_string(), x.to_vec(), v));
+    }
+    let all: Vec<u8> = (0 .. 32).collect();
+    let rev: Vec<u8> = (0 .. 32).rev().collect();
+    test_values("a", &[], Bech32, "a12uel5l");
+    test_values("a", &[], Bech32m, "a1lqfn3a");
+    test_values("abcdef", &all, Bech32, "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw");
+    test_values("abcdef", &rev, Bech32m, "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx");
+    test("foo", b"", Bech32, "foo197peaq");
+    test("foo", b"foobar", Bech32, "foo1vehk7cnpwgry9h96");
+    test("foo", b"foobar", Bech32m, "foo1vehk7cnpwgkc4mqc");
+    assert_eq!(decode_values(b"A12UEL5L").unwrap(), ("a".to_string(), vec![], Bech32));
+    assert_eq!(encode_values("A", &[], Bech32), "a12uel5l");
+    let (hrp, values, variant) = decode_values(b"BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4").unwrap();
+    assert_eq!((&hrp as &str, values[0], variant), ("bc", 0, Bech32));
+    assert_eq!(decode(&encode_values("bc", &values[1 ..], Bech32).into_bytes()).unwrap().1,
+               b"\x75\x1e\x76\xe8\x19\x91\x96\xd4\x54\x94\x1c\x45\xd1\xb3\xa3\x23\xf1\x43\x3b\xd6");
+    assert_eq!(decode_values(b"a12uel5m"), Err(BadChecksum));
+    assert_eq!(decode_values(b"A12uEL5L"), Err(BadCharacter(3)));
+    assert_eq!(decode_values(b"a12ueb5l"), Err(BadCharacter(5)));
+    assert_eq!(decode_values(b"a 12uel5l"), Err(BadCharacter(1)));
+    assert_eq!(decode_values(b"12uel5l"), Err(BadCharacter(0)));
+    assert_eq!(decode_values(b"a1uel5l"), Err(BadLength));
+    assert_eq!(decode_values(b"auel5l"), Err(BadLength));
+    let long = format!("a1{}", ::std::iter::repeat('q').take(89).collect::<String>());
+    assert_eq!(decode_values(long.as_bytes()), Err(BadLength));
+    let padding = encode_values("a", &[1], Bech32);
+    assert_eq!(decode(padding.as_bytes()), Err(BadLength));
+    let padding = encode_values("a", &[0, 1], Bech32);
+    assert_eq!(decode(padding.as_bytes()), Err(BadPadding));
+    let values = vec![7; MAX_LEN - 8];
+    let long = encode_values("a", &values, Bech32m);
+    assert_eq!(long.len(), MAX_LEN);
+    assert_eq!(decode_values(long.as_bytes()).unwrap(), ("a".to_string(), values, Bech32m));
+    let data = vec![0xa5; 51];
+    let long = encode("a", &data, Bech32);
+    assert_eq!(long.len(), MAX_LEN);
+    assert_eq!(decode(long.as_bytes()).unwrap(), ("a".to_string(), data, Bech32));
+}
+
+#[test]
+#[should_panic]
+fn bech32_encode_length() {
+    data_encoding::bech32::encode_values("a", &[0; 83], data_encoding::bech32::Variant::Bech32);
+}
+
+#[test]
+fn onion() {
+    use data_encoding::onion::{encode, decode};
+    use data_encoding::decode::Error::*;
+    let mut key = [0u8; 32];
+    assert_eq!(encode(&key), "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaam2dqd.onion");
+    for i in 0 .. 32 {
+        key[i] = i as u8;
+    }
+    let addr = "aaaqeayeaudaocajbifqydiob4ibceqtcqkrmfyydenbwha5dyp3kead.onion";
+    assert_eq!(encode(&key), addr);
+    assert_eq!(decode(addr.as_bytes()), Ok(key));
+    assert_eq!(decode(&addr.as_bytes()[.. 56]), Ok(key));
+    assert_eq!(decode(b"aaaqeayeaudaocajbifqydiob4ibceqtcqkrmfyydenbwha5dyp3keae.onion"),
+               Err(BadChecksum));
+    assert_eq!(decode(b"aaaqeayeaudaocajbifqydiob4ibceqtcqkrmfyydenbwha5dyp2sxye"),
+               Err(BadChecksum));
+    assert_eq!(decode(b"aaaqeayeaudaocajbifqydiob4ibceqtcqkrmfyydenbwha5dyp3kea.onion"),
+               Err(BadLength));
+    assert_eq!(decode(b"aaaqeayeaudaocajbifqydiob4ibceqtcqkrmfyydenbwha5dyp3keaD"),
+               Err(BadCharacter(55)));
+}
+
+test!{
+    fn proquint;
+    test(b"", b"");
+    test(b"\x00\x00", b"babab");
+    test(b"\xff\xff", b"zuzuz");
+    test(b"\x7f\x00\x00\x01", b"lusab-babad");
+    test(b"\x3f\x54\xdc\xc1", b"gutih-tugad");
+    test(b"\xc0\xa8\x01\x01", b"safom-bahad");
+    assert_eq!(encode_len(4), 11);
+    assert_eq!(decode(b"lusab-baba"), Err(BadLength));
+    assert_eq!(decode(b"lusab_babad"), Err(BadCharacter(5)));
+    assert_eq!(decode(b"lusab-baaad"), Err(BadCharacter(8)));
+    assert_eq!(decode(b"lusab-bobed"), Err(BadCharacter(9)));
+    assert_eq!(decode(b"Lusab"), Err(BadCharacter(0)));
+}
+
+#[test]
+fn bip39() {
+    use data_encoding::bip39::{encode, decode};
+    use data_encoding::decode::Error::*;
+    let list: Vec<String> = (0 .. 2048).map(|i| format!("w{}", i)).collect();
+    let list = &list[..];
+    fn words(x: &[usize]) -> String {
+        x.iter().map(|i| format!("w{}", i)).collect::<Vec<_>>().join(" ")
+    }
+    let test = |x: &[u8], y: &[usize]| {
+        assert_eq!(encode(list, x), words(y));
+        assert_eq!(decode(list, &words(y)).unwrap(), x);
+    };
+    test(&[0; 16], &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3]);
+    test(&[0x7f; 16], &[1019, 2015, 1790, 2039, 1983, 1533, 2031, 1919, 1019, 2015, 1790, 2040]);
+    test(b"\x9e\x88\x5d\x95\x2a\xd3\x62\xca\xeb\x4e\xfe\x34\xa8\xe9\x1b\xd2",
+         &[1268, 535, 810, 685, 433, 811, 1385, 1790, 421, 570, 567, 1313]);
+    test(&[0x80; 20], &[1028, 32, 257, 8, 64, 514, 16, 128, 1028, 32, 257, 8, 64, 514, 11]);
+    let mut zoo = vec![2047; 23];
+    zoo.push(1967);
+    test(&[0xff; 32], &zoo);
+    let static_list: Vec<&str> = list.iter().map(|x| x as &str).collect();
+    assert_eq!(encode(&static_list[..], &[0; 16]), words(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3]));
+    assert_eq!(decode(list, &words(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4])), Err(BadChecksum));
+    assert_eq!(decode(list, &words(&[0; 11])), Err(BadLength));
+    assert_eq!(decode(list, "w0 w0 w0 w0 w0 w0 w0 w0 w0 w0 x0 w3"), Err(BadCharacter(30)));
+    assert_eq!(decode(list, "w0 w0 w0 w0 w0 w0 w0 w0 w0 w0 w0  w3"), Err(BadLength));
+    assert_eq!(decode(list, "w0 w0 w0 w0 w0 w0 w0 w0 w0 w0  w3"), Err(BadCharacter(30)));
+}
+
+#[test]
+fn pgpwords() {
+    use data_encoding::pgpwords::{encode, decode, EVEN, ODD};
+    use data_encoding::decode::Error::*;
+    let even: Vec<String> = (0 .. 256).map(|i| format!("e{}", i)).collect();
+    let odd: Vec<String> = (0 .. 256).map(|i| format!("o{}", i)).collect();
+    let (even, odd) = (&even[..], &odd[..]);
+    let test = |x: &[u8], y: &str| {
+        assert_eq!(encode(even, odd, x), y);
+        assert_eq!(decode(even, odd, y).unwrap(), x);
+    };
+    test(b"", "");
+    test(b"\x00", "e0");
+    test(b"\xe5\x82\x94\xf2", "e229 o130 e148 o242");
+    assert_eq!(decode(even, odd, "e229 e130"), Err(BadCharacter(5)));
+    assert_eq!(decode(even, odd, "o229 o130"), Err(BadCharacter(0)));
+    assert_eq!(decode(even, odd, "e229 o130 e256"), Err(BadCharacter(10)));
+    assert_eq!(decode(even, odd, "e229  o130"), Err(BadCharacter(5)));
+    let (even, odd) = (&EVEN[..], &ODD[..]);
+    let x = b"\xe5\x82\x94\xf2\xe9\xa2\x27\x48\x6e\x8b\x06\x1b\x31\xcc\x52\x8f\xd7\xfa\x3f\x19";
+    let y = "topmost Istanbul Pluto vagabond treadmill Pacific brackish dictator goldfish \
+             Medusa afflict bravado chatter revolver Dupont midsummer stopwatch whimsical \
+             cowbell bottomless";
+    assert_eq!(encode(even, odd, x), y);
+    assert_eq!(decode(even, odd, y).unwrap(), &x[..]);
+    assert_eq!(decode(even, odd, "aardvark adroitness Zulu Yucatan"), Ok(vec![0, 0, 255, 255]));
+    assert_eq!(decode(even, odd, "aardvark aardvark"), Err(BadCharacter(9)));
+}
+
+#[test]
+fn skey() {
+    use data_encoding::skey::*;
+    use data_encoding::decode::Error::*;
+    let list: Vec<String> = (0 .. 2048).map(|i| format!("w{}", i)).collect();
+    let list = &list[..];
+    fn words(x: &[usize]) -> String {
+        x.iter().map(|i| format!("w{}", i)).collect::<Vec<_>>().join(" ")
+    }
+    let test = |x: u64, y: &[usize]| {
+        assert_eq!(encode_u64(list, x), words(y));
+        assert_eq!(decode_u64(list, &words(y)), Ok(x));
+        assert_eq!(encode(list, &x.to_be_bytes()), words(y));
+        assert_eq!(decode(list, &words(y)), Ok(x.to_be_bytes()));
+    };
+    test(0, &[0, 0, 0, 0, 0, 0]);
+    test(0x9e876134d90499dd, &[1268, 472, 617, 1424, 588, 1908]);
+    test(0xffffffffffffffff, &[2047, 2047, 2047, 2047, 2047, 2044]);
+    test(0x0123456789abcdef, &[9, 209, 719, 154, 1510, 1980]);
+    assert_eq!(decode_u64(list, &words(&[0, 0, 0, 0, 0, 1])), Err(BadChecksum));
+    assert_eq!(decode_u64(list, &words(&[0, 0, 0, 0, 0])), Err(BadLength));
+    assert_eq!(decode_u64(list, "w0 w0 w0 x0 w0 w0"), Err(BadCharacter(9)));
+    let list = &DICTIONARY[..];
+    let test = |x: u64, y: &str| {
+        assert_eq!(encode_u64(list, x), y);
+        assert_eq!(decode_u64(list, y), Ok(x));
+        assert_eq!(decode_u64(list, &y.to_lowercase()), Ok(x));
+    };
+    test(0x9e876134d90499dd, "INCH SEA ANNE LONG AHEM TOUR");
+    test(0x7965e05436f5029f, "EASE OIL FUM CURE AWRY AVIS");
+    test(0x50fe1962c4965880, "BAIL TUFT BITS GANG CHEF THY");
+    test(0x87066dd9644bf206, "FULL PEW DOWN ONCE MORT ARC");
+    test(0x7cd34c1040add14b, "FACT HOOF AT FIST SITE KENT");
+    test(0x5aa37a81f212146c, "BODE HOP JAKE STOW JUT RAP");
+    test(0xf205753943de4cf9, "ULAN NEW ARMY FUSE SUIT EYED");
+    test(0xddcdac956f234937, "SKIM CULT LOB SLAM POE HOWL");
+    test(0xb203e28fa525be47, "LONG IVY JULY AJAR BOND LEE");
+    test(0xd1854218ebbb0b51, "ROME MUG FRED SCAN LIVE LACE");
+    test(0x63473ef01cd0b444, "CARD SAD MINI RYE COL KIN");
+    test(0xc5e612776e6c237a, "NOTE OUT IBIS SINK NAVE MODE");
+    test(0xbb9e6ae1979d8ff4, "MILT VARY MAST OK SEES WENT");
+    test(0x63d936639734385b, "CART OTTO HIVE ODE VAT NUT");
+    test(0x4f296a74fe1567ec, "AURA ALOE HURL WING BERG WAIT");
+    assert_eq!(decode_u64(list, "Inch Sea Anne Long Ahem Tour"), Ok(0x9e876134d90499dd));
+    assert_eq!(decode_u64(list, " INCH  SEA\tANNE\r\nLONG AHEM\nTOUR\n"), Ok(0x9e876134d90499dd));
+    assert_eq!(decode_u64(list, "INCH\t\tSEA ANNE LONG AHEM"), Err(BadLength));
+    assert_eq!(decode_u64(list, "INCH  SEA\tANNE\nLOMG AHEM TOUR"), Err(BadCharacter(15)));
+    assert_eq!(decode_u64(list, "INCH SEA ANNE LONG AHEM TOAD"), Err(BadChecksum));
+    assert_eq!(decode_u64(list, "INCH SEA ANNE LOMG AHEM TOUR"), Err(BadCharacter(14)));
+}
+
+test!{
+    fn bubblebabble;
+    test(b"", b"xexax");
+    test(b"\x00", b"xebax");
+    test(b"\xff\xff", b"xuzoz-zyxux");
+    test(b"1234567890", b"xesef-disof-gytuf-katof-movif-baxux");
+    test(b"Pineapple", b"xigak-nyryk-humil-bosek-sonax");
+    assert_eq!(decode(b"xexa"), Err(BadLength));
+    assert_eq!(decode(b"yexax"), Err(BadCharacter(0)));
+    assert_eq!(decode(b"xexay"), Err(BadCharacter(4)));
+    assert_eq!(decode(b"xexux"), Err(BadChecksum));
+    assert_eq!(decode(b"xesef-disof-gytuf-katof-movif-baxix"), Err(BadChecksum));
+    assert_eq!(decode(b"xesef-disof+gytuf-katof-movif-baxux"), Err(BadCharacter(11)));
+    assert_eq!(decode(b"xesex-disof-gytuf-katof-movif-baxux"), Err(BadCharacter(4)));
+    assert_eq!(decode(b"xasef-disof-gytuf-katof-movif-baxux"), Err(BadChecksum));
+}
+
+#[test]
+fn uuencode() {
+    use data_encoding::uuencode::*;
+    use data_encoding::decode::Error::*;
+    fn test(x: &[u8], y: &[u8]) {
+        assert_eq!(&encode_line(x).into_bytes() as &[u8], y);
+        assert_eq!(&decode_line(y).unwrap() as &[u8], x);
+    }
+    test(b"", b"`\n");
+    test(b"\x00", b"!````\n");
+    test(b"Cat", b"#0V%T\n");
+    test(b"foobar", b"&9F]O8F%R\n");
+    test(&[b'a'; 45], b"M86%A86%A86%A86%A86%A86%A86%A86%A86%A86%A86%A86%A86%A86%A86%A\n");
+    assert_eq!(decode_line(b"!    ").unwrap(), b"\x00");
+    assert_eq!(decode_line(b"#0V%T\r\n").unwrap(), b"Cat");
+    assert_eq!(decode_line(b"#0V%"), Err(BadLength));
+    assert_eq!(decode_line(b"#0V%a"), Err(BadCharacter(4)));
+    assert_eq!(decode_line(b""), Err(BadLength));
+    let file = "begin 644 cat.txt\n#0V%T\n`\nend\n";
+    assert_eq!(encode(0o644, "cat.txt", b"Cat"), file);
+    assert_eq!(decode(file.as_bytes()).unwrap(), (0o644, "cat.txt".to_string(), b"Cat".to_vec()));
+    let data: Vec<u8> = (0 .. 100).collect();
+    let file = encode(0o600, "my data", &data);
+    assert_eq!(file.lines().count(), 6);
+    assert_eq!(decode(file.as_bytes()).unwrap(), (0o600, "my data".to_string(), data));
+    assert_eq!(decode(b"begin 644 x\r\n#0V%T\r\n \r\nend\r\n").unwrap().2, b"Cat");
+    assert_eq!(decode(b"begin 648 x\n`\nend\n"), Err(BadCharacter(8)));
+    assert_eq!(decode(b"begin 644\n`\nend\n"), Err(BadCharacter(9)));
+    assert_eq!(decode(b"begun 644 x\n`\nend\n"), Err(BadCharacter(0)));
+    assert_eq!(decode(b"begin 644 x\n#0V%T\n"), Err(BadLength));
+    assert_eq!(decode(b"begin 644 x\n`\nstart\n"), Err(BadCharacter(14)));
+}
+
+#[test]
+fn xxencode() {
+    use data_encoding::xxencode::*;
+    use data_encoding::decode::Error::*;
+    fn test(x: &[u8], y: &[u8]) {
+        assert_eq!(&encode_line(x).into_bytes() as &[u8], y);
+        assert_eq!(&decode_line(y).unwrap() as &[u8], x);
+    }
+    test(b"", b"+\n");
+    test(b"\x00", b"-++++\n");
+    test(b"Cat", b"1Eq3o\n");
+    test(b"foobar", b"4NaxjMa3m\n");
+    test(&[b'a'; 45], b"hMK3VMK3VMK3VMK3VMK3VMK3VMK3VMK3VMK3VMK3VMK3VMK3VMK3VMK3VMK3V\n");
+    assert_eq!(decode_line(b"1Eq3"), Err(BadLength));
+    assert_eq!(decode_line(b"1Eq3`"), Err(BadCharacter(4)));
+    let file = "begin 644 cat.txt\n1Eq3o\n+\nend\n";
+    assert_eq!(encode(0o644, "cat.txt", b"Cat"), file);
+    assert_eq!(decode(file.as_bytes()).unwrap(), (0o644, "cat.txt".to_string(), b"Cat".to_vec()));
+    let data: Vec<u8> = (0 .. 100).collect();
+    let file = encode(0o600, "my data", &data);
+    assert_eq!(decode(file.as_bytes()).unwrap(), (0o600, "my data".to_string(), data));
+    assert_eq!(decode(b"begin 644 x\n1Eq3o\n"), Err(BadLength));
+}
+
+#[test]
+fn yenc() {
+    use data_encoding::yenc::*;
+    use data_encoding::decode::Error::*;
+    fn test(x: &[u8], y: &[u8], line: usize) {
+        let mut output = vec![0u8; encode_len(x.len(), line)];
+        let len = encode_mut(x, line, &mut output);
+        assert_eq!(&output[.. len], y);
+        let mut output = vec![0u8; y.len()];
+        let len = decode_mut(y, &mut output).unwrap();
+        assert_eq!(&output[.. len], x);
+    }
+    test(b"", b"", LINE);
+    test(b"\x00", b"*", LINE);
+    test(b"Cat", b"m\x8b\x9e", LINE);
+    test(b"\xd6\xe0\xf6\xe3\x13\x04", b"=@=J =M=}.", LINE);
+    test(b"\x04\x04\x04", b"=n..", LINE);
+    test(b"\xf6\x00\xf6", b"=`*=`", LINE);
+    let data: Vec<u8> = (0 .. 10).collect();
+    test(&data, b"*+,-\r\n=n/0\r\n123", 4);
+    let mut output = [0u8; 8];
+    assert_eq!(decode_mut(b"ab=", &mut output), Err(BadCharacter(2)));
+    assert_eq!(decode_mut(b"a=\r\nb", &mut output), Err(BadCharacter(2)));
+    let file = b"=ybegin line=128 size=3 name=cat.txt\r\nm\x8b\x9e\r\n=yend size=3 crc32=a6130548\r\n";
+    assert_eq!(&encode("cat.txt", b"Cat", LINE) as &[u8], &file[..]);
+    assert_eq!(decode(file).unwrap(), ("cat.txt".to_string(), b"Cat".to_vec()));
+    let data: Vec<u8> = (0 .. 1000).map(|x| (x * 7) as u8).collect();
+    let file = encode("my data", &data, 64);
+    assert_eq!(decode(&file).unwrap(), ("my data".to_string(), data));
+    assert_eq!(decode(b"=ybegin line=128 size=3 name=x\nm\x8b\x9e\n=yend size=3\n").unwrap(),
+               ("x".to_string(), b"Cat".to_vec()));
+    assert_eq!(decode(b"=ybegin line=128 size=3 name=x\nm\x8b\x9e\n"), Err(BadLength));
+    assert_eq!(decode(b"=ybegin line=128 size=4 name=x\nm\x8b\x9e\n=yend size=4\n"),
+               Err(BadLength));
+    assert_eq!(decode(b"=ybegin line=128 size=3 name=x\nm\x8b\x9e\n=yend size=3 crc32=A6130549\n"),
+               Err(BadChecksum));
+    assert_eq!(decode(b"=ybegin line=128 size=3 name=x\nm\x8b\x9e\n=yend size=3 crc32=A6130548\n")
+               .unwrap(), ("x".to_string(), b"Cat".to_vec()));
+    assert_eq!(decode(b"=ybegin line=128 size=3x name=x\n"), Err(BadCharacter(23)));
+    assert_eq!(decode(b"=ybegin line=128 size=2 name=x\nm=\n=yend size=2\n"), Err(BadCharacter(32)));
+}
+
+#[test]
+fn binhex() {
+    use data_encoding::binhex::*;
+    use data_encoding::decode::Error::*;
+    let file = decode(b"(This file must be converted with BinHex 4.0)\r\r\
+                        :\"f0KG#jdH(3!9%9B9(4dH(3!N!8$!*!%,NK$BA4#p3!!:\r").unwrap();
+    assert_eq!(file, File {
+        name: b"cat.txt".to_vec(), kind: *b"TEXT", creator: *b"ttxt", flags: 0,
+        data: b"Cat".to_vec(), resource: vec![],
+    });
+    let input = b"(This file must be converted with BinHex 4.0)\r\r\
+                  :\"(*eER-!9%9B9(4dH(3!N!8F!!!!\"04dBC!8N!\"LBT!!N!#3!*!!N!!Tk9*68N1\r2Y`:\r";
+    let mut data = vec![b'a'; 20];
+    data.extend_from_slice(b"\x90bb\x90\x90\x90\x90\x90");
+    assert_eq!(decode(input).unwrap(), File {
+        name: b"runs".to_vec(), kind: *b"TEXT", creator: *b"ttxt", flags: 0,
+        data: data, resource: b"RSRC".to_vec(),
+    });
+    assert_eq!(decode(b"\"f0KG#jdH(3!9%9B9(4dH(3!N!8$!*!%,NK$BA4#p3!!"), Err(BadLength));
+    assert_eq!(decode(b":\"f0KG#jdH(3!9%9B9(4dH(3!N!8$!*!%,NK$BA4#p3!!"), Err(BadLength));
+    assert_eq!(decode(b":\"f0KG#jdH(3!9%9B9(4dH(3!N!8$!*!%,NK$BA4#p3!:"), Err(BadLength));
+    assert_eq!(decode(b":\"f0KG#jdH(3!9%9B9(4dH(3!N!8$!*!%,NK$BA4#p4!!:"), Err(BadChecksum));
+    assert_eq!(decode(b":\"f0KG#jdH(4!9%9B9(4dH(3!N!8$!*!%,NK$BA4#p3!!:"), Err(BadChecksum));
+    assert_eq!(decode(b":\"f0KG#jdH(7!9%9B9(4dH(3!N!8$!*!%,NK$BA4#p3!!:"), Err(BadCharacter(11)));
+    assert_eq!(decode(b":N!!:"), Err(BadLength));
+}
+
+#[test]
+fn quotedprintable() {
+    use data_encoding::quotedprintable::*;
+    use data_encoding::quotedprintable::Mode::*;
+    use data_encoding::quotedprintable::Whitespace::*;
+    use data_encoding::decode::Error::*;
+    fn test(x: &[u8], y: &str, binary: bool) {
+        assert_eq!(encode(x, binary), y);
+        assert_eq!(decode(y.as_bytes(), Strict, Strip).unwrap(), x);
+    }
+    test(b"", "", false);
+    test(b"hello world", "hello world", false);
+    test(b"caf\xc3\xa9 = ok", "caf=C3=A9 =3D ok", false);
+    test(b"hello \r\nworld\t", "hello=20\r\nworld=09", false);
+    test(b"a\r\n\r\nb\r\n", "a\r\n\r\nb\r\n", false);
+    test(b"a\r\nb", "a=0D=0Ab", true);
+    let a = "a".repeat(75);
+    test(&[b'a'; 76], &format!("{}a", a), false);
+    test(&[b'a'; 80], &format!("{}=\r\naaaaa", a), false);
+    test(format!("{}=", a).as_bytes(), &format!("{}=\r\n=3D", a), false);
+    test(format!("{}a ", a).as_bytes(), &format!("{}=\r\na=20", a), false);
+    assert_eq!(encode(b"a\nb\n", false), "a\r\nb\r\n");
+    assert_eq!(decode(b"a=\r\nb", Strict, Strip).unwrap(), b"ab");
+    assert_eq!(decode(b"a  \r\nb\t", Strict, Strip).unwrap(), b"a\r\nb");
+    assert_eq!(decode(b"a  \r\nb\t", Strict, Keep).unwrap(), b"a  \r\nb\t");
+    assert_eq!(decode(b"a= \r\nb", Strict, Strip).unwrap(), b"ab");
+    assert_eq!(decode(b"a= \r\nb", Strict, Keep), Err(BadCharacter(1)));
+    assert_eq!(decode(b"a= \r\nb", Lenient, Keep).unwrap(), b"a= \r\nb");
+    assert_eq!(decode(b"=3d", Strict, Strip), Err(BadCharacter(0)));
+    assert_eq!(decode(b"=3d", Lenient, Strip).unwrap(), b"=");
+    assert_eq!(decode(b"=3", Strict, Strip), Err(BadCharacter(0)));
+    assert_eq!(decode(b"=G0", Lenient, Strip).unwrap(), b"=G0");
+    assert_eq!(decode(b"a\nb", Strict, Strip), Err(BadCharacter(1)));
+    assert_eq!(decode(b"a\nb", Lenient, Strip).unwrap(), b"a\r\nb");
+    assert_eq!(decode(b"a\xe9", Strict, Strip), Err(BadCharacter(1)));
+    assert_eq!(decode(b"a\xe9", Lenient, Strip).unwrap(), b"a\xe9");
+    assert_eq!(decode(&[b'a'; 77], Strict, Strip), Err(BadLength));
+    assert_eq!(decode(&[b'a'; 77], Lenient, Strip).unwrap(), &[b'a'; 77] as &[u8]);
+    let data: Vec<u8> = (0 .. 1000).map(|x| (x * 7) as u8).collect();
+    assert_eq!(decode(encode(&data, true).as_bytes(), Strict, Strip).unwrap(), data);
+}
+
+#[test]
+fn encodedword() {
+    use data_encoding::encodedword::*;
+    use data_encoding::encodedword::Variant::*;
+    use data_encoding::decode::Error::*;
+    fn test(charset: &str, variant: Variant, x: &[u8], y: &str) {
+        assert_eq!(encode(charset, variant, x), y);
+        assert_eq!(decode(y.as_bytes()).unwrap(), vec![(Some(charset.to_string()), x.to_vec())]);
+    }
+    test("utf-8", B, b"", "=?utf-8?B??=");
+    test("utf-8", Q, b"", "=?utf-8?Q??=");
+    test("utf-8", B, "Hello, wörld".as_bytes(), "=?utf-8?B?SGVsbG8sIHfDtnJsZA==?=");
+    test("utf-8", Q, "Hello, wörld".as_bytes(), "=?utf-8?Q?Hello=2C_w=C3=B6rld?=");
+    test("iso-8859-1", Q, b"a=b?c_d", "=?iso-8859-1?Q?a=3Db=3Fc=5Fd?=");
+    let text = "é".repeat(30);
+    test("utf-8", B, text.as_bytes(),
+         "=?utf-8?B?w6nDqcOpw6nDqcOpw6nDqcOpw6nDqcOpw6nDqcOpw6nDqcOpw6nDqcOpw6k=?=\r\n \
+          =?utf-8?B?w6nDqcOpw6nDqcOpw6nDqQ==?=");
+    let word = format!("=?utf-8?Q?{}?=", "=C3=A9".repeat(10));
+    test("utf-8", Q, text.as_bytes(), &format!("{}\r\n {}\r\n {}", word, word, word));
+    let data: Vec<u8> = (0 .. 200).map(|x| (x * 7) as u8).collect();
+    for line in encode("x-binary", Q, &data).split("\r\n ") {
+        assert!(line.len() <= MAX_LEN);
+    }
+    test("x-binary", B, &data, &encode("x-binary", B, &data));
+    test("x-binary", Q, &data, &encode("x-binary", Q, &data));
+    let mut data = vec![0xe0];
+    data.extend_from_slice(&[0x80; 30]);
+    for &variant in &[B, Q] {
+        let output = encode("utf-8", variant, &data);
+        for line in output.split("\r\n ") {
+            assert!(line.len() <= MAX_LEN);
+        }
+        test("utf-8", variant, &data, &output);
+    }
+    assert_eq!(decode_word(b"=?UTF-8?q?caf=c3=a9?=").unwrap(),
+               ("UTF-8".to_string(), "café".as_bytes().to_vec()));
+    assert_eq!(decode_word(b"=?utf-8?b?Y2Fmw6k=?=").unwrap(),
+               ("utf-8".to_string(), "café".as_bytes().to_vec()));
+    assert_eq!(decode_word(b"=?utf-8?X?abc?="), Err(BadCharacter(8)));
+    assert_eq!(decode_word(b"=?utf-8?Q?a=3?="), Err(BadCharacter(11)));
+    assert_eq!(decode_word(b"=?utf-8?Q?a b?="), Err(BadCharacter(11)));
+    assert_eq!(decode_word(b"=?utf-8?B?Y2Fmw6k?="), Err(BadLength));
+    assert_eq!(decode_word(b"=?utf-8?B?Y2Fmw6k*?="), Err(BadCharacter(17)));
+    assert_eq!(decode_word(b"=?utf-8?Q?abc"), Err(BadLength));
+    assert_eq!(decode_word(b"=?utf-8?=?="), Err(BadLength));
+    assert_eq!(decode_word(b"=?utf-8?="), Err(BadLength));
+    assert_eq!(decode(b"Re: =?utf-8?Q?caf=C3=A9?= =?utf-8?Q?_noir?= ok\r\n =?iso-8859-1?Q?caf=E9?=")
+               .unwrap(),
+               vec![(None, b"Re: ".to_vec()),
+                    (Some("utf-8".to_string()), "café noir".as_bytes().to_vec()),
+                    (None, b" ok ".to_vec()),
+                    (Some("iso-8859-1".to_string()), b"caf\xe9".to_vec())]);
+    assert_eq!(decode(b"a =?x?Q?b?= =?y?Q?c?= d").unwrap(),
+               vec![(None, b"a ".to_vec()), (Some("x".to_string()), b"b".to_vec()),
+                    (Some("y".to_string()), b"c".to_vec()), (None, b" d".to_vec())]);
+    assert_eq!(decode(b"a\r\n\tb ").unwrap(), vec![(None, b"a\tb ".to_vec())]);
+    assert_eq!(decode(b"a =?x?Q?=?= b"), Err(BadCharacter(8)));
+}
+
+#[test]
+fn percent() {
+    use data_encoding::percent::*;
+    use data_encoding::decode::Error::*;
+    fn test(x: &[u8], y: &str, set: &AsciiSet) {
+        assert_eq!(encode(x, set), y);
+        assert_eq!(decode(y.as_bytes()).unwrap(), x);
+        let mut output = vec![0u8; encode_len(x, set)];
+        encode_mut(x, set, &mut output);
+        assert_eq!(output, y.as_bytes());
+    }
+    test(b"", "", &COMPONENT);
+    test(b"a b", "a%20b", &FRAGMENT);
+    test(b"100%", "100%25", &QUERY);
+    test(b"a/b?c#d", "a/b%3Fc%23d", &PATH);
+    test(b"a/b?c#d", "a/b?c%23d", &QUERY);
+    test(b"a/b?c#d", "a%2Fb%3Fc%23d", &COMPONENT);
+    test(b"user:pass@host", "user%3Apass%40host", &USERINFO);
+    test(b"x=1&y=2+3", "x%3D1%26y%3D2%2B3", &COMPONENT);
+    test("café\n".as_bytes(), "caf%C3%A9%0A", &FRAGMENT);
+    test(b"~-._!*'()", "~-._!*'()", &COMPONENT);
+    test(b"abc", "%61b%63", &AsciiSet::new(b"ac"));
+    assert!(COMPONENT.contains(b'%'));
+    assert!(COMPONENT.contains(0x80));
+    assert!(!COMPONENT.contains(b'a'));
+    assert_eq!(decode(b"%c3%a9").unwrap(), "é".as_bytes());
+    assert_eq!(decode(b"a+b").unwrap(), b"a+b");
+    assert_eq!(decode(b"100%"), Err(BadCharacter(3)));
+    assert_eq!(decode(b"%2"), Err(BadCharacter(0)));
+    assert_eq!(decode(b"a%G0"), Err(BadCharacter(1)));
+    let mut output = [0u8; 6];
+    assert_eq!(decode_mut(b"%41%42", &mut output), Ok(2));
+    assert_eq!(&output[.. 2], b"AB");
+}
+
+#[test]
+fn formurlencoded() {
+    use data_encoding::formurlencoded::*;
+    fn test(x: &[u8], y: &str) {
+        assert_eq!(encode(x), y);
+        assert_eq!(decode(y.as_bytes()), x);
+    }
+    test(b"", "");
+    test(b"a b+c", "a+b%2Bc");
+    test(b"x=1&y=2", "x%3D1%26y%3D2");
+    test(b"~!'()*-._", "%7E%21%27%28%29*-._");
+    test("café".as_bytes(), "caf%C3%A9");
+    assert_eq!(decode(b"a%20b"), b"a b");
+    assert_eq!(decode(b"a%2"), b"a%2");
+    assert_eq!(decode(b"%zz%"), b"%zz%");
+    assert_eq!(decode(b"%%41+%4"), b"%A %4");
+    let pairs = vec![(b"name".to_vec(), b"J. Doe".to_vec()), (b"q".to_vec(), b"a&b=c".to_vec()),
+                     (b"".to_vec(), b"".to_vec())];
+    assert_eq!(encode_pairs(&pairs), "name=J.+Doe&q=a%26b%3Dc&=");
+    assert_eq!(decode_pairs(b"name=J.+Doe&q=a%26b%3Dc&="), pairs);
+    assert_eq!(encode_pairs(&[("a", "1"), ("b", "2")]), "a=1&b=2");
+    assert_eq!(decode_pairs(b"&a&&b=1=2&"),
+               vec![(b"a".to_vec(), vec![]), (b"b".to_vec(), b"1=2".to_vec())]);
+    assert_eq!(decode_pairs(b""), vec![]);
+    assert_eq!(decode_pairs(b"a=1&b%=2"),
+               vec![(b"a".to_vec(), b"1".to_vec()), (b"b%".to_vec(), b"2".to_vec())]);
+    assert_eq!(decode_pairs(b"a=1&b=%"),
+               vec![(b"a".to_vec(), b"1".to_vec()), (b"b".to_vec(), b"%".to_vec())]);
+}
+
+#[test]
+fn punycode() {
+    use data_encoding::punycode::*;
+    use data_encoding::decode::Error::*;
+    fn test(x: &str, y: &str) {
+        assert_eq!(encode(x), y);
+        assert_eq!(decode(y.as_bytes()).unwrap(), x);
+    }
+    test("", "");
+    test("Hello", "Hello-");
+    test("ü", "tda");
+    test("bücher", "bcher-kva");
+    test("München", "Mnchen-3ya");
+    test("a-b-ü", "a-b--3ra");
+    test("-> $1.00 <-", "-> $1.00 <--");
+    test("ليهمابتكلموشعربي؟", "egbpdaj6bu4bxfgehfvwxn");
+    test("他们为什么不说中文", "ihqwcrb4cv8a8dqg056pqjye");
+    test("3年B組金八先生", "3B-ww4c5e180e575a65lsy2b");
+    assert_eq!(decode(b"bcher-KVA").unwrap(), "bücher");
+    assert_eq!(decode(b"-3ra").unwrap(), "\u{2f0}");
+    assert_eq!(decode(b"b\xffcher-kva"), Err(BadCharacter(1)));
+    assert_eq!(decode(b"bcher-k_a"), Err(BadCharacter(7)));
+    assert_eq!(decode(b"bcher-kv"), Err(BadLength));
+    assert_eq!(decode(b"99999a"), Err(BadCharacter(0)));
+    assert_eq!(decode(b"a-99999a"), Err(BadCharacter(2)));
+    assert_eq!(encode_label("bücher"), "xn--bcher-kva");
+    assert_eq!(encode_label("example"), "example");
+    assert_eq!(decode_label(b"xn--bcher-kva").unwrap(), "bücher");
+    assert_eq!(decode_label(b"XN--bcher-kva").unwrap(), "bücher");
+    assert_eq!(decode_label(b"example").unwrap(), "example");
+    assert_eq!(decode_label(b"xn--bcher-k_a"), Err(BadCharacter(11)));
+    assert_eq!(decode_label(b"b\xfccher"), Err(BadCharacter(1)));
+}
+
+#[test]
+fn imaputf7() {
+    use data_encoding::imaputf7::*;
+    use data_encoding::decode::Error::*;
+    fn test(x: &str, y: &str) {
+        assert_eq!(encode(x), y);
+        assert_eq!(decode(y.as_bytes()).unwrap(), x);
+    }
+    test("", "");
+    test("INBOX", "INBOX");
+    test("&", "&-");
+    test("Tom & Jerry", "Tom &- Jerry");
+    test("Entwürfe", "Entw&APw-rfe");
+    test("~peter/mail/台北/日本語", "~peter/mail/&U,BTFw-/&ZeVnLIqe-");
+    test("\u{1f600}", "&2D3eAA-");
+    test("a\tb", "a&AAk-b");
+    assert_eq!(decode(b"a\xc3\xa9"), Err(BadCharacter(1)));
+    assert_eq!(decode(b"a\nb"), Err(BadCharacter(1)));
+    assert_eq!(decode(b"&APw"), Err(BadLength));
+    assert_eq!(decode(b"&AA-"), Err(BadLength));
+    assert_eq!(decode(b"&APx-"), Err(BadPadding));
+    assert_eq!(decode(b"&AP/-"), Err(BadCharacter(3)));
+    assert_eq!(decode(b"x&AGE-"), Err(BadCharacter(1)));
+    assert_eq!(decode(b"&APw-&APw-"), Err(BadCharacter(5)));
+    assert_eq!(decode(b"&APw-&--&APw-").unwrap(), "ü&-ü");
+    assert_eq!(decode(b"&2D0-"), Err(BadCharacter(0)));
+}
+
+test!{
+    fn base64mime;
+    test(b"", b"");
+    test(b"foobar", b"Zm9vYmFy");
+    test(&[0; 57], &[b'A'; 76]);
+    let x: Vec<u8> = (0 .. 60).collect();
+    test(&x, b"AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8gISIjJCUmJygpKissLS4vMDEyMzQ1Njc4\r\nOTo7");
+    assert_eq!(encode_len(57), 76);
+    assert_eq!(encode_len(58), 82);
+    assert_eq!(decode(b"Zm9v\nYmFy\r\n").unwrap(), b"foobar");
+    assert_eq!(decode(b"Zm9v\r\nYm.y"), Err(BadCharacter(8)));
+    assert_eq!(decode(b"Zm9v\nYmF"), Err(BadLength));
+}
+
+#[test]
+fn pem() {
+    use data_encoding::decode::Error::*;
+    use data_encoding::pem::*;
+    use data_encoding::pem::Mode::*;
+    let x: Vec<u8> = (0 .. 60).collect();
+    let y = "-----BEGIN TEST DATA-----\n\
+             AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8gISIjJCUmJygpKissLS4v\n\
+             MDEyMzQ1Njc4OTo7\n\
+             -----END TEST DATA-----\n";
+    assert_eq!(encode("TEST DATA", &x), y);
+    assert_eq!(decode(y.as_bytes(), Strict).unwrap(), ("TEST DATA".to_string(), x.clone()));
+    assert_eq!(decode(y.as_bytes(), Lax).unwrap(), ("TEST DATA".to_string(), x.clone()));
+    assert_eq!(encode("X", b""), "-----BEGIN X-----\n-----END X-----\n");
+    assert_eq!(decode(b"-----BEGIN X-----\r\n-----END X-----", Strict).unwrap(),
+               ("X".to_string(), vec![]));
+    let lax = b"Subject: test\n  -----BEGIN X-----  \nZm9v\tYm\n Fy\n-----END Y-----\ntrailer\n";
+    assert_eq!(decode(lax, Lax).unwrap(), ("X".to_string(), b"foobar".to_vec()));
+    assert_eq!(decode(lax, Strict), Err(BadCharacter(0)));
+    assert_eq!(decode(b"-----BEGIN X-----\nZm9v\n-----END Y-----\n", Strict),
+               Err(BadCharacter(23)));
+    assert_eq!(decode(b"-----BEGIN X-----\nZm9v\nYmFy\n-----END X-----\n", Strict),
+               Err(BadCharacter(23)));
+    assert_eq!(decode(b"-----BEGIN X-----\nZm9v\n-----END X-----\nZ", Strict),
+               Err(BadCharacter(39)));
+    assert_eq!(decode(b"-----BEGIN X-----\nZm9v YmFy\n-----END X-----\n", Strict),
+               Err(BadCharacter(22)));
+    assert_eq!(decode(b"-----BEGIN X-----\nZm9v.mFy\n-----END X-----\n", Lax),
+               Err(BadCharacter(22)));
+    assert_eq!(decode(b"-----BEGIN X-----\nZm9v\n", Lax), Err(BadLength));
+    assert_eq!(decode(b"-----BEGIN  X-----\n-----END  X-----\n", Lax), Err(BadLength));
+}
+
+#[test]
+fn age() {
+    use data_encoding::age::*;
+    use data_encoding::decode::Error::*;
+    fn wrap(x: &str) -> String {
+        format!("-----BEGIN AGE ENCRYPTED FILE-----\n{}-----END AGE ENCRYPTED FILE-----\n", x)
+    }
+    let x: Vec<u8> = (0 .. 50).collect();
+    let y = wrap("AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8gISIjJCUmJygpKissLS4v\nMDE=\n");
+    assert_eq!(encode(&x), y);
+    assert_eq!(decode(y.as_bytes()).unwrap(), x);
+    assert_eq!(decode(y.replace("\n", "\r\n").as_bytes()).unwrap(), x);
+    assert_eq!(decode(wrap("Zm9v\n").trim_right().as_bytes()).unwrap(), b"foo");
+    assert_eq!(decode(b"-----BEGIN X-----\nZm9v\n-----END X-----\n"), Err(BadCharacter(0)));
+    assert_eq!(decode(format!(" {}", wrap("Zm9v\n")).as_bytes()), Err(BadCharacter(0)));
+    assert_eq!(decode(wrap("Zm8\n").as_bytes()), Err(BadLength));
+    assert_eq!(decode(wrap("Zm9=\n").as_bytes()), Err(BadPadding));
+    assert_eq!(decode(wrap("\nZm9v\n").as_bytes()), Err(BadCharacter(35)));
+    assert_eq!(decode(wrap("Zm 9v\n").as_bytes()), Err(BadCharacter(37)));
+    assert_eq!(decode(format!("{}\n", wrap("Zm9v\n")).as_bytes()), Err(BadCharacter(73)));
+}
+
+#[test]
+fn armor() {
+    use data_encoding::armor::*;
+    use data_encoding::decode::Error::*;
+    let armor = Armor {
+        kind: "PGP MESSAGE".to_string(),
+        headers: vec![("Version".to_string(), "1.0".to_string())],
+        data: (0 .. 60).collect(),
+    };
+    let y = "-----BEGIN PGP MESSAGE-----\n\
+             Version: 1.0\n\
+             \n\
+             AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8gISIjJCUmJygpKissLS4v\n\
+             MDEyMzQ1Njc4OTo7\n\
+             =RlBJ\n\
+             -----END PGP MESSAGE-----\n";
+    assert_eq!(encode(&armor), y);
+    assert_eq!(decode(y.as_bytes()).unwrap(), armor);
+    let foobar = Armor { kind: "X".to_string(), headers: vec![], data: b"foobar".to_vec() };
+    assert_eq!(decode(b"text\r\n-----BEGIN X-----\r\n\r\nZm9v\r\nYmFy\r\n=czTe\r\n-----END X-----")
+               .unwrap(), foobar);
+    assert_eq!(decode(b"-----BEGIN X-----\n\nZm9vYmFy\n-----END X-----\n").unwrap(), foobar);
+    assert_eq!(decode(b"-----BEGIN X-----\n\nZm9vYmFy\n=czTf\n-----END X-----\n"),
+               Err(BadChecksum));
+    assert_eq!(decode(b"-----BEGIN X-----\n\nZm9vYmFy\n=czT\n-----END X-----\n"),
+               Err(BadCharacter(28)));
+    assert_eq!(decode(b"-----BEGIN X-----\n\nZm9vYmFy\n=czTe\nZg==\n-----END X-----\n"),
+               Err(BadCharacter(34)));
+    assert_eq!(decode(b"-----BEGIN X-----\n\nZm9vYmFy\n-----END Y-----\n"),
+               Err(BadCharacter(28)));
+    assert_eq!(decode(b"-----BEGIN X-----\nVersion 1.0\n\nZm9vYmFy\n-----END X-----\n"),
+               Err(BadCharacter(18)));
+    assert_eq!(decode(b"-----BEGIN X-----\n\nZm9v.mFy\n-----END X-----\n"),
+               Err(BadCharacter(23)));
+    assert_eq!(decode(b"-----BEGIN X-----\n\nZm9vYmFy\n"), Err(BadLength));
+    assert_eq!(decode(b"Zm9vYmFy\n"), Err(BadLength));
+}
+
+#[test]
+fn datauri() {
+    use data_encoding::datauri::*;
+    use data_encoding::decode::Error::*;
+    fn text(x: &str) -> String {
+        x.to_string()
+    }
+    assert_eq!(encode("image/png", b"foobar"), "data:image/png;base64,Zm9vYmFy");
+    assert_eq!(encode_percent("text/plain", b"a b#c,"), "data:text/plain,a%20b%23c,");
+    assert_eq!(decode(b"data:image/png;base64,Zm9vYmFy").unwrap(),
+               (text("image/png"), b"foobar".to_vec()));
+    assert_eq!(decode(b"DATA:;BASE64,Zm9vYmFy").unwrap(), (text(DEFAULT), b"foobar".to_vec()));
+    assert_eq!(decode(b"data:,a%20b%23c,").unwrap(), (text(DEFAULT), b"a b#c,".to_vec()));
+    assert_eq!(decode(b"data:;charset=utf-8,%C3%A9").unwrap(),
+               (text("text/plain;charset=utf-8"), "\u{e9}".as_bytes().to_vec()));
+    assert_eq!(decode(b"http:,"), Err(BadCharacter(0)));
+    assert_eq!(decode(b"data:text/plain"), Err(BadLength));
+    assert_eq!(decode(b"data:\xe9,"), Err(BadCharacter(5)));
+    assert_eq!(decode(b"data:;base64,Zm9.YmFy"), Err(BadCharacter(16)));
+    assert_eq!(decode(b"data:,a%2"), Err(BadCharacter(7)));
+}
+
+#[test]
+fn multibase() {
+    use data_encoding::decode::Error::*;
+    use data_encoding::multibase::*;
+    use data_encoding::multibase::Code::*;
+    fn test(code: Code, y: &str) {
+        let x = b"yes mani !";
+        assert_eq!(encode(code, x), y);
+        assert_eq!(decode(y.as_bytes()).unwrap(), (code, x.to_vec()));
+    }
+    test(Base16Lower, "f796573206d616e692021");
+    test(Base16Upper, "F796573206D616E692021");
+    test(Base32Lower, "bpfsxgidnmfxgsibb");
+    test(Base32Upper, "BPFSXGIDNMFXGSIBB");
+    test(Base32PadLower, "cpfsxgidnmfxgsibb");
+    test(Base32PadUpper, "CPFSXGIDNMFXGSIBB");
+    test(Base58Btc, "z7paNL19xttacUY");
+    test(Base64, "meWVzIG1hbmkgIQ");
+    test(Base64Pad, "MeWVzIG1hbmkgIQ==");
+    test(Base64Url, "ueWVzIG1hbmkgIQ");
+    test(Base64UrlPad, "UeWVzIG1hbmkgIQ==");
+    assert_eq!(decode(b""), Err(BadLength));
+    assert_eq!(decode(b"x1234"), Err(BadCharacter(0)));
+    assert_eq!(decode(b"f79657"), Err(BadLength));
+    assert_eq!(decode(b"F79x5"), Err(BadCharacter(3)));
+}
+
+#[test]
+fn ihex() {
+    use data_encoding::decode::Error::*;
+    use data_encoding::ihex::*;
+    let x: Vec<u8> = (0 .. 20).collect();
+    let y = ":020000040001F9\n\
+             :08FFF8000001020304050607E5\n\
+             :020000040002F8\n\
+             :0C00000008090A0B0C0D0E0F1011121352\n\
+             :00000001FF\n";
+    assert_eq!(encode(0x1fff8, &x), y);
+    assert_eq!(decode(y.as_bytes()).unwrap(), vec![(0x1fff8, x.clone())]);
+    assert_eq!(to_records(0, b"")[..], [Record::EndOfFile]);
+    let records: Result<Vec<_>, _> = records(b":020000021200EA\r\n\r\n:0400000300003800c1\r\n\
+                                              :0400000500000100F6\r\n").collect();
+    assert_eq!(records.unwrap(), vec![Record::ExtendedSegmentAddress(0x1200),
+                                      Record::StartSegmentAddress { cs: 0, ip: 0x3800 },
+                                      Record::StartLinearAddress(0x100)]);
+    assert_eq!(decode(b":020000021200EA\n:020010000102EB\n:0100120003EA\n\
+                        :0100000004FB\n:00000001FF\n").unwrap(),
+               vec![(0x12010, vec![1, 2, 3]), (0x12000, vec![4])]);
+    assert_eq!(decode_record(b"00000001FF"), Err(BadCharacter(0)));
+    assert_eq!(decode_record(b":00000001F"), Err(BadLength));
+    assert_eq!(decode_record(b":01000001FF"), Err(BadLength));
+    assert_eq!(decode_record(b":00000001FE"), Err(BadChecksum));
+    assert_eq!(decode_record(b":00000006FA"), Err(BadCharacter(7)));
+    assert_eq!(decode_record(b":010000010AF4"), Err(BadLength));
+    assert_eq!(decode_record(b":00000001FG"), Err(BadCharacter(10)));
+    assert_eq!(decode(b":00000001FF\n:00000001FF\n"), Err(BadCharacter(12)));
+    assert_eq!(decode(b":0100000004FB\n"), Err(BadLength));
+    assert_eq!(decode(b":0100000004FB\n:00000001FE\n"), Err(BadChecksum));
+    assert_eq!(decode(b":0100000004FB\n:0000000GFF\n"), Err(BadCharacter(22)));
+}
+
+#[test]
+fn srec() {
+    use data_encoding::decode::Error::*;
+    use data_encoding::srec::*;
+    use data_encoding::srec::Width::*;
+    let x: Vec<u8> = (0 .. 20).collect();
+    let y = "S00600004844521B\n\
+             S214010000000102030405060708090A0B0C0D0E0F72\n\
+             S20801001010111213A0\n\
+             S5030002FA\n\
+             S804010000FA\n";
+    assert_eq!(encode(b"HDR", Bits24, 0x10000, &x), y);
+    assert_eq!(decode(y.as_bytes()).unwrap(),
+               Image { header: b"HDR".to_vec(), segments: vec![(0x10000, x.clone())],
+                       start: 0x10000 });
+    let records: Result<Vec<_>, _> = records(b"S10510000102e7\r\n\r\nS30612345678AA3B\r\n\
+                                              S70512345678E6\r\nS604000001FA\r\n").collect();
+    assert_eq!(records.unwrap(),
+               vec![Record::Data { width: Bits16, address: 0x1000, data: vec![1, 2] },
+                    Record::Data { width: Bits32, address: 0x12345678, data: vec![0xaa] },
+                    Record::Start { width: Bits32, address: 0x12345678 },
+                    Record::Count { width: Bits24, count: 1 }]);
+    assert_eq!(decode(b"S10510000102E7\nS9030000FC\n").unwrap(),
+               Image { header: vec![], segments: vec![(0x1000, vec![1, 2])], start: 0 });
+    assert_eq!(decode_record(b"X9030000FC"), Err(BadCharacter(0)));
+    assert_eq!(decode_record(b"S4030000FC"), Err(BadCharacter(1)));
+    assert_eq!(decode_record(b"S9030000F"), Err(BadLength));
+    assert_eq!(decode_record(b"S9040000FC"), Err(BadLength));
+    assert_eq!(decode_record(b"S9030000FD"), Err(BadChecksum));
+    assert_eq!(decode_record(b"S904000001FA"), Err(BadLength));
+    assert_eq!(decode_record(b"S0030001FB"), Err(BadCharacter(4)));
+    assert_eq!(decode_record(b"S9030000FG"), Err(BadCharacter(9)));
+    assert_eq!(decode(b"S10510000102E7\nS5030002FA\nS9030000FC\n"), Err(BadLength));
+    assert_eq!(decode(b"S10510000102E7\n"), Err(BadLength));
+    assert_eq!(decode(b"S9030000FC\nS9030000FC\n"), Err(BadCharacter(11)));
+}
+
+#[test]
+fn leb128() {
+    use data_encoding::decode::Error::*;
+    use data_encoding::leb128::*;
+    fn test(x: u64, y: &[u8]) {
+        assert_eq!(encode(x), y);
+        assert_eq!(decode(y).unwrap(), (x, y.len()));
+    }
+    fn test_signed(x: i64, y: &[u8]) {
+        assert_eq!(encode_signed(x), y);
+        assert_eq!(decode_signed(y).unwrap(), (x, y.len()));
+    }
+    test(0, &[0x00]);
+    test(2, &[0x02]);
+    test(127, &[0x7f]);
+    test(128, &[0x80, 0x01]);
+    test(129, &[0x81, 0x01]);
+    test(12857, &[0xb9, 0x64]);
+    test(!0, &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01]);
+    test_signed(0, &[0x00]);
+    test_signed(2, &[0x02]);
+    test_signed(-2, &[0x7e]);
+    test_signed(127, &[0xff, 0x00]);
+    test_signed(-127, &[0x81, 0x7f]);
+    test_signed(128, &[0x80, 0x01]);
+    test_signed(-128, &[0x80, 0x7f]);
+    test_signed(-129, &[0xff, 0x7e]);
+    test_signed(::std::i64::MAX, &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00]);
+    test_signed(::std::i64::MIN, &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7f]);
+    assert_eq!(decode(&[0x80, 0x01, 0xff]), Ok((128, 2)));
+    assert_eq!(decode(&[]), Err(BadLength));
+    assert_eq!(decode(&[0x80, 0x80]), Err(BadLength));
+    assert_eq!(decode(&[0x81, 0x00]), Err(BadCharacter(1)));
+    assert_eq!(decode(&[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02]),
+               Err(BadCharacter(9)));
+    assert_eq!(decode_signed(&[0x81, 0x00]), Err(BadCharacter(1)));
+    assert_eq!(decode_signed(&[0xc1, 0x7f]), Err(BadCharacter(1)));
+    assert_eq!(decode_signed(&[0xc1]), Err(BadLength));
+    assert_eq!(decode_signed(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x01]),
+               Err(BadCharacter(9)));
+    assert_eq!(encode_len(1 << 63), MAX_LEN);
+    assert_eq!(encode_signed_len(-1), 1);
+}
+
+#[test]
+fn zigzag() {
+    use data_encoding::zigzag::*;
+    use std::{i32, i64, i128};
+    for &(x, y) in &[(0, 0), (-1, 1), (1, 2), (-2, 3), (i32::MAX, !1), (i32::MIN, !0)] {
+        assert_eq!(encode_i32(x), y);
+        assert_eq!(decode_i32(y), x);
+    }
+    for &(x, y) in &[(0, 0), (-1, 1), (1, 2), (-2, 3), (i64::MAX, !1), (i64::MIN, !0)] {
+        assert_eq!(encode_i64(x), y);
+        assert_eq!(decode_i64(y), x);
+    }
+    for &(x, y) in &[(0, 0), (-1, 1), (1, 2), (-2, 3), (i128::MAX, !1), (i128::MIN, !0)] {
+        assert_eq!(encode_i128(x), y);
+        assert_eq!(decode_i128(y), x);
+    }
+    let x = -300i64;
+    assert_eq!(data_encoding::leb128::encode(encode_i64(x)), [0xd7, 0x04]);
+}
+
+#[test]
+fn vlq() {
+    use data_encoding::decode::Error::*;
+    use data_encoding::vlq::*;
+    use std::i64;
+    fn test(x: i64, y: &[u8]) {
+        assert_eq!(encode(x).into_bytes(), y);
+        assert_eq!(decode(y).unwrap(), (x, y.len()));
+    }
+    test(0, b"A");
+    test(1, b"C");
+    test(-1, b"D");
+    test(15, b"e");
+    test(16, b"gB");
+    test(-16, b"hB");
+    test(1000, b"w+B");
+    test(i64::MAX, b"+///////////P");
+    test(i64::MIN, b"hgggggggggggQ");
+    assert_eq!(decode(b"gBA"), Ok((16, 2)));
+    assert_eq!(decode(b""), Err(BadLength));
+    assert_eq!(decode(b"g"), Err(BadLength));
+    assert_eq!(decode(b"B"), Err(BadCharacter(0)));
+    assert_eq!(decode(b"gA"), Err(BadCharacter(1)));
+    assert_eq!(decode(b"g."), Err(BadCharacter(1)));
+    assert_eq!(decode(b"ggggggggggggQ"), Err(BadCharacter(12)));
+    assert_eq!(decode(b"gggggggggggggB"), Err(BadCharacter(13)));
+    assert_eq!(encode_segment(&[0, 0, 16, 1]), "AAgBC");
+    assert_eq!(decode_segment(b"AAgBC").unwrap(), vec![0, 0, 16, 1]);
+    let mappings = vec![vec![vec![0, 0, 0, 0], vec![4, 0, 0, 4, 1]], vec![], vec![vec![-1]]];
+    assert_eq!(encode_mappings(&mappings), "AAAA,IAAIC;;D");
+    assert_eq!(decode_mappings(b"AAAA,IAAIC;;D").unwrap(), mappings);
+    let empty: Vec<Vec<Vec<i64>>> = vec![];
+    assert_eq!(encode_mappings(&empty), "");
+    assert_eq!(decode_mappings(b"").unwrap(), empty);
+    assert_eq!(decode_mappings(b"AAAA,,IAAIC;").unwrap(),
+               vec![vec![vec![0, 0, 0, 0], vec![4, 0, 0, 4, 1]], vec![]]);
+    assert_eq!(decode_mappings(b"AAAA;AA.A"), Err(BadCharacter(7)));
+}
+
+#[test]
+fn ulid() {
+    use data_encoding::decode::Error::*;
+    use data_encoding::ulid::*;
+    let x: Vec<u8> = (0 .. 16).collect();
+    let mut a = [0u8; 16];
+    a.copy_from_slice(&x);
+    assert_eq!(encode(&a), "00041061050R3GG28A1C60T3GF");
+    assert_eq!(decode(b"00041061050r3gg28a1c60t3gf").unwrap(), a);
+    assert_eq!(encode(&[0xff; 16]), "7ZZZZZZZZZZZZZZZZZZZZZZZZZ");
+    assert_eq!(decode(b"7ZZZZZZZZZZZZZZZZZZZZZZZZZ").unwrap(), [0xff; 16]);
+    let y = decode(b"01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap();
+    assert_eq!(encode(&y), "01ARZ3NDEKTSV4RRFFQ69G5FAV");
+    assert_eq!(decode(b"8ZZZZZZZZZZZZZZZZZZZZZZZZZ"), Err(BadCharacter(0)));
+    assert_eq!(decode(b"7ZZZZZZZZZZZZZZZZZZZZZZZZU"), Err(BadCharacter(25)));
+    assert_eq!(decode(b"0ZZZZZZZZZZZZZZZZZZZZZZZZL"), Err(BadCharacter(25)));
+    assert_eq!(decode(b"7ZZZZZZZZZZZZZZZZZZZZZZZZ"), Err(BadLength));
+}
+
+#[test]
+fn base64forgiving() {
+    use data_encoding::base64forgiving::decode;
+    use data_encoding::decode::Error::*;
+    fn test(x: &[u8], y: &[u8]) {
+        assert_eq!(&decode(y).unwrap() as &[u8], x);
+    }
+    test(b"", b"");
+    test(b"", b" \t\n");
+    test(b"foobar", b"Zm9vYmFy");
+    test(b"fo", b"Zm8=");
+    test(b"fo", b"Zm8");
+    test(b"fo", b"Zm9");
+    test(b"foob", b" Zm9v\r\nYg= = ");
+    test(b"f", b"Zh");
+    assert_eq!(decode(b"Zm9vY"), Err(BadLength));
+    assert_eq!(decode(b"Zm8=="), Err(BadLength));
+    assert_eq!(decode(b"Zm8=Zm8="), Err(BadCharacter(3)));
+    assert_eq!(decode(b"Zg="), Err(BadCharacter(2)));
+    assert_eq!(decode(b"Z===="), Err(BadLength));
+    assert_eq!(decode(b"Zm 9-"), Err(BadCharacter(4)));
+    assert_eq!(decode(b"Z-9v"), Err(BadCharacter(1)));
+}
+
+#[test]
+fn hashids() {
+    use data_encoding::decode::Error::*;
+    use data_encoding::hashids::*;
+    fn test(h: &Hashids, x: &[u64], y: &str) {
+        assert_eq!(h.encode(x), y);
+        assert_eq!(h.decode(y.as_bytes()).unwrap(), x);
+    }
+    let salt = b"this is my salt";
+    let h = Hashids::new(salt, 0, ALPHABET.as_bytes()).unwrap();
+    test(&h, &[], "");
+    test(&h, &[0], "5x");
+    test(&h, &[12345], "NkK9");
+    test(&h, &[1, 2, 3], "laHquq");
+    test(&h, &[123, 456, 789], "Z8gi1DIx6");
+    test(&h, &[!0], "zXVjmzBamYlqX");
+    test(&Hashids::new(b"", 0, ALPHABET.as_bytes()).unwrap(), &[1, 2, 3], "o2fXhV");
+    test(&Hashids::new(salt, 8, ALPHABET.as_bytes()).unwrap(), &[1], "gB0NV05e");
+    test(&Hashids::new(salt, 16, ALPHABET.as_bytes()).unwrap(), &[1], "JEDngB0NV05ev1Ww");
+    test(&Hashids::new(salt, 30, ALPHABET.as_bytes()).unwrap(), &[1, 2, 3],
+         "ZPVgxzNb59LGlaHquq06DmlyMX3okO");
+    test(&Hashids::new(b"salt", 0, b"0123456789abcdef").unwrap(), &[1, 2, 3], "38ca0e");
+    assert_eq!(h.decode(b"NkK8"), Err(BadChecksum));
+    assert_eq!(h.decode(b"NkK-"), Err(BadCharacter(3)));
+    assert_eq!(Hashids::new(b"", 0, b"0123456789abcde"), Err(BuildError::Short));
+    assert_eq!(Hashids::new(b"", 0, b"0123456789abcdee"), Err(BuildError::Duplicate(b'e')));
+    assert_eq!(Hashids::new(b"", 0, b"0123456789abcde f"), Err(BuildError::Space));
+    assert_eq!(Hashids::new(b"", 0, b"0123456789abcde\xff"), Err(BuildError::NotAscii(0xff)));
+    assert_eq!(Hashids::new(b"", 0, b"0123456789abcde\0"), Err(BuildError::NotPrintable(0)));
+    assert_eq!(Hashids::new(b"", 0, b"0123456789abcde\x7f"), Err(BuildError::NotPrintable(0x7f)));
+}
+
+#[test]
+fn keyed() {
+    use data_encoding::base::{Base, valid};
+    use data_encoding::decode::decode;
+    use data_encoding::encode::encode;
+    use data_encoding::keyed::Keyed;
+    let base = data_encoding::base64::base();
+    let keyed = Keyed::new(base, b"secret");
+    valid(&keyed).unwrap();
+    assert_eq!(keyed, Keyed::new(base, b"secret"));
+    assert!(keyed != Keyed::new(base, b"other"));
+    assert_eq!(keyed.pad(), b'=');
+    let mut sym: Vec<u8> = (0 .. 64).map(|v| keyed.sym(v)).collect();
+    assert_eq!(encode(&keyed, b"f"), "iL==");
+    assert_eq!(encode(&keyed, b"foobar"), "ifTj3fM+");
+    sym.sort();
+    let mut orig: Vec<u8> = (0 .. 64).map(|v| base.sym(v)).collect();
+    orig.sort();
+    assert_eq!(sym, orig);
+    for i in 0 .. 8 {
+        let x: Vec<u8> = (0 .. i).collect();
+        assert_eq!(decode(&keyed, encode(&keyed, &x).as_bytes()).unwrap(), x);
+    }
+}
+
+#[test]
+fn qralphanumeric() {
+    use data_encoding::decode::Error::*;
+    use data_encoding::qralphanumeric::*;
+    assert_eq!(encode(b"").unwrap(), (vec![], 0));
+    assert_eq!(encode(b"AC-42").unwrap(), (vec![0x39, 0xdc, 0xe4, 0x20], 28));
+    assert_eq!(encode(b"HELLO WORLD").unwrap(),
+               (b"ao\x1a.[\x89\xa8h".to_vec(), encode_len(11)));
+    assert_eq!(encode(b"AC-4a"), Err(BadCharacter(4)));
+    assert_eq!(decode(&[0x39, 0xdc, 0xe4, 0x20], 5).unwrap(), "AC-42");
+    assert_eq!(decode(b"ao\x1a.[\x89\xa8h", 11).unwrap(), "HELLO WORLD");
+    assert_eq!(decode(&[0xff, 0xe0], 2), Err(BadCharacter(0)));
+    assert_eq!(decode(&[0x39, 0xdc, 0xe7, 0x20], 5), Err(BadCharacter(22)));
+    assert_eq!(decode(&[0x39, 0xdc, 0xe4], 5), Err(BadLength));
+    assert_eq!(decode(&[0x39, 0xdc, 0xe4], ::std::usize::MAX), Err(BadLength));
+    assert_eq!(count_bits(1), 9);
+    assert_eq!(count_bits(10), 11);
+    assert_eq!(count_bits(40), 13);
+    let segment = vec![0x20, 0x29, 0xce, 0xe7, 0x21, 0x00];
+    assert_eq!(encode_segment(1, b"AC-42").unwrap(), (segment.clone(), 41));
+    assert_eq!(decode_segment(1, &segment).unwrap(), ("AC-42".to_string(), 41));
+    assert_eq!(decode_segment(1, &[0x40, 0x29]), Err(BadCharacter(0)));
+    assert_eq!(decode_segment(10, &segment), Err(BadLength));
+}
+
+#[test]
+#[should_panic]
+fn qralphanumeric_version() {
+    data_encoding::qralphanumeric::count_bits(41);
+}
+
+#[test]
+fn specification() {
+    use data_encoding::base::{BitOrder, equal, valid};
+    use data_encoding::decode::decode;
+    use data_encoding::decode::Error::*;
+    use data_encoding::encode::{encode, encode_nopad};
+    use data_encoding::specification::*;
+    use data_encoding::specification::SpecificationError::*;
+    let mut spec = Specification::new();
+    spec.symbols.push_str("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/");
+    let base64 = spec.encoding().unwrap();
+    valid(&base64).unwrap();
+    equal(&base64, data_encoding::base64::base()).unwrap();
+    assert_eq!(base64.specification(), spec);
+    assert_eq!(encode(&base64, b"foobar"), "Zm9vYmFy");
+    assert_eq!(encode(&base64, b"f"), "Zg==");
+    assert_eq!(encode_nopad(&base64, b"f"), "Zg");
+    assert_eq!(decode(&base64, b"Zm9vYg==").unwrap(), b"foob");
+    assert_eq!(decode(&base64, b"Zm9v*g=="), Err(BadCharacter(4)));
+    spec.symbols = "0123456789bcdfghjklmnpqrstuvwxyz".to_string();
+    spec.padding = '*';
+    spec.bit_order = BitOrder::LeastSignificantFirst;
+    let dnscurve = spec.encoding().unwrap();
+    assert_eq!(encode(&dnscurve, b"f"), "63******");
+    assert_eq!(decode(&dnscurve, b"63******").unwrap(), b"f");
+    assert_eq!(format!("{:?}", dnscurve), format!("Encoding({:?})", spec));
+    let error = |symbols: &str, padding: char| {
+        Specification { symbols: symbols.to_string(), padding: padding, ..Specification::new() }
+            .encoding().unwrap_err()
+    };
+    assert_eq!(error("", '='), BadSize);
+    assert_eq!(error("012", '='), BadSize);
+    let ascii: String = (0 .. 128u8).map(|x| x as char).collect();
+    assert_eq!(error(&ascii, '='), BadSize);
+    assert_eq!(error("0\u{e9}", '='), NotAscii('\u{e9}'));
+    assert_eq!(error("0120", '='), Duplicate('0'));
+    assert_eq!(error("01", '\u{e9}'), PadNotAscii);
+    assert_eq!(error("01", '0'), PadSymbol);
+}
+
+#[test]
+fn new_encoding() {
+    use data_encoding::base::equal;
+    use data_encoding::encode::encode;
+    use data_encoding::specification::{Encoding, Specification};
+    const BASE32: Encoding = new_encoding!{
+        symbols: "ABCDEFGHIJKLMNOPQRSTUVWXYZ234567",
+    };
+    const DNSCURVE: Encoding = new_encoding!{
+        symbols: "0123456789bcdfghjklmnpqrstuvwxyz",
+        padding: '*',
+        bit_order: LeastSignificantFirst,
+    };
+    equal(&BASE32, data_encoding::base32::base()).unwrap();
+    assert_eq!(encode(&BASE32, b"f"), "MY======");
+    assert_eq!(encode(&DNSCURVE, b"f"), "63******");
+    let spec = Specification { symbols: "01".to_string(), padding: '-', ..Specification::new() };
+    assert_eq!(new_encoding!{ symbols: "01", padding: '-' }.specification(), spec);
+}
+
+#[test]
+fn encoding_const() {
+    use data_encoding::base::BitOrder::*;
+    use data_encoding::encode::encode;
+    use data_encoding::specification::{Encoding, SpecificationError};
+    static BASE4: Encoding = match Encoding::new("ACGT", '=', MostSignificantFirst) {
+        Ok(e) => e,
+        Err(e) => e.panic(),
+    };
+    const BAD: Option<SpecificationError> =
+        match Encoding::new("A\u{e9}", '=', MostSignificantFirst) {
+            Ok(_) => None,
+            Err(e) => Some(e),
+        };
+    assert_eq!(encode(&BASE4, b"f"), "CGCG");
+    assert_eq!(BAD, Some(SpecificationError::NotAscii('\u{e9}')));
+    assert_eq!(Encoding::new("AB", 'A', LeastSignificantFirst).err(),
+               Some(SpecificationError::PadSymbol));
+}
+
+#[test]
+fn dyn_base() {
+    use std::collections::HashMap;
+    use data_encoding::base::Base;
+    use data_encoding::specification::Specification;
+    let mut spec = Specification::new();
+    spec.symbols.push_str("01");
+    let mut bases: HashMap<String, Box<dyn Base>> = HashMap::new();
+    bases.insert("base16".to_string(), Box::new(data_encoding::base16::base()));
+    bases.insert("base64".to_string(), Box::new(data_encoding::base64::base()));
+    bases.insert("base2".to_string(), Box::new(spec.encoding().unwrap()));
+    let base: &dyn Base = &*bases["base64"];
+    assert_eq!(data_encoding::encode::encode(base, b"foo"), "Zm9v");
+    assert_eq!(data_encoding::decode::decode(base, b"Zm9v").unwrap(), b"foo");
+    assert_eq!(data_encoding::encode::encode(&bases["base16"], b"\x01\xab"), "01AB");
+    assert_eq!(data_encoding::encode::encode(&bases["base2"], b"\x05"), "00000101");
+    data_encoding::base::valid(base).unwrap();
+    data_encoding::base::equal(base, data_encoding::base64::base()).unwrap();
+}
+
+#[test]
+fn encoding_methods() {
+    use data_encoding::specification::{BASE32, BASE64, BASE64URL};
+    assert_eq!(BASE64.encode(b"foob"), "Zm9vYg==");
+    assert_eq!(BASE64.encode_nopad(b"foob"), "Zm9vYg");
+    assert_eq!(BASE64URL.encode(b"\xfb\xff"), "-_8=");
+    assert_eq!(BASE32.decode(b"MZXW6YQ=").unwrap(), b"foob");
+    assert_eq!(BASE32.decode_nopad(b"MZXW6YQ").unwrap(), b"foob");
+    assert_eq!(BASE32.decode(b"MZXW6YQ").err().unwrap(),
+               data_encoding::decode::Error::BadLength);
+    let mut output = vec![0u8; BASE64.encode_len(3)];
+    BASE64.encode_mut(b"foo", &mut output);
+    assert_eq!(output, b"Zm9v");
+    let mut output = vec![0u8; BASE64.decode_len(4)];
+    assert_eq!(BASE64.decode_mut(b"Zm8=", &mut output), Ok(2));
+    assert_eq!(&output[.. 2], b"fo");
+}
+
+#[test]
+fn encode_ext() {
+    use data_encoding::ext::EncodeExt;
+    let input = b"foob".to_vec();
+    assert_eq!(input.to_hex(), "666f6f62");
+    assert_eq!(input.to_base16(), "666F6F62");
+    assert_eq!(input.to_base32(), "MZXW6YQ=");
+    assert_eq!(input.to_base32_nopad(), "MZXW6YQ");
+    assert_eq!(input.to_base32hex(), "CPNMUOG=");
+    assert_eq!(input.to_base64(), "Zm9vYg==");
+    assert_eq!(input.to_base64_nopad(), "Zm9vYg");
+    assert_eq!(b"\xfb\xff".to_base64url(), "-_8=");
+    assert_eq!(b"\xfb\xff"[..].to_base64url_nopad(), "-_8");
+}
+
+#[test]
+fn decode_ext() {
+    use data_encoding::ext::DecodeExt;
+    use data_encoding::decode::Error::*;
+    let input = "Zm9vYg==".to_string();
+    assert_eq!(input.from_base64().unwrap(), b"foob");
+    assert_eq!("Zm9vYg".from_base64_nopad().unwrap(), b"foob");
+    assert_eq!("-_8=".from_base64url().unwrap(), b"\xfb\xff");
+    assert_eq!("-_8".from_base64url_nopad().unwrap(), b"\xfb\xff");
+    assert_eq!("MZXW6YQ=".from_base32().unwrap(), b"foob");
+    assert_eq!("MZXW6YQ".from_base32_nopad().unwrap(), b"foob");
+    assert_eq!("CPNMUOG=".from_base32hex().unwrap(), b"foob");
+    assert_eq!("666f6f62".from_hex().unwrap(), b"foob");
+    assert_eq!("666F6F62".from_base16().unwrap(), b"foob");
+    assert_eq!("666f6f6g".from_hex(), Err(BadCharacter(7)));
+    assert_eq!("Zm9vYg".from_base64(), Err(BadLength));
+}
+
+#[test]
+fn prelude() {
+    use data_encoding::prelude::*;
+    fn encode(base: &dyn Base, input: &[u8]) -> String {
+        data_encoding::encode::encode(base, input)
+    }
+    let input = BASE32.decode(b"MZXW6===").unwrap();
+    assert_eq!(input.to_base64(), "Zm9v");
+    assert_eq!(encode(&BASE16, &input), "666F6F");
+    assert_eq!(BASE64URL.decode(b"Zm9v+"), Err(DecodeError::BadLength));
+    assert_eq!("Zm9v+A==".from_base64url(), Err(DecodeError::BadCharacter(4)));
+    let _: &Encoding = &BASE32HEX;
+}
+
+#[test]
+fn custom_padding() {
+    use data_encoding::specification::Specification;
+    use data_encoding::decode::Error::*;
+    let mut spec = Specification::new();
+    spec.symbols.push_str("ABCDEFGHIJKLMNOPQRSTUVWXYZ234567");
+    spec.padding = '-';
+    let base = spec.encoding().unwrap();
+    assert_eq!(base.encode(b"f"), "MY------");
+    assert_eq!(base.decode(b"MY------").unwrap(), b"f");
+    assert_eq!(base.decode(b"MY======"), Err(BadCharacter(2)));
+    assert_eq!(base.decode(b"MY-----="), Err(BadCharacter(7)));
+    assert_eq!(base.decode_nopad(b"MY-A"), Err(BadCharacter(2)));
+    spec.padding = '*';
+    spec.symbols.clear();
+    spec.symbols.push_str("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/");
+    let base = spec.encoding().unwrap();
+    assert_eq!(base.encode(b"fo"), "Zm8*");
+    assert_eq!(base.decode(b"Zm8*").unwrap(), b"fo");
+    assert_eq!(base.decode(b"Zm8="), Err(BadCharacter(3)));
+    spec.padding = 'A';
+    assert!(spec.encoding().is_err());
+}
+
+#[test]
+fn decode_opt_pad() {
+    use data_encoding::{base32, base64};
+    use data_encoding::decode::Error::*;
+    use data_encoding::specification::BASE64URL;
+    assert_eq!(base64::decode_opt_pad(b"").unwrap(), b"");
+    assert_eq!(base64::decode_opt_pad(b"Zm9v").unwrap(), b"foo");
+    assert_eq!(base64::decode_opt_pad(b"Zm9vYg==").unwrap(), b"foob");
+    assert_eq!(base64::decode_opt_pad(b"Zm9vYg").unwrap(), b"foob");
+    assert_eq!(base64::decode_opt_pad(b"Zm9vYmE=").unwrap(), b"fooba");
+    assert_eq!(base64::decode_opt_pad(b"Zm9vYmE").unwrap(), b"fooba");
+    assert_eq!(base64::decode_opt_pad(b"Zm9vY"), Err(BadLength));
+    assert_eq!(base64::decode_opt_pad(b"Zm9vYh=="), Err(BadPadding));
+    assert_eq!(base64::decode_opt_pad(b"Zm9vYh"), Err(BadPadding));
+    assert_eq!(base64::decode_opt_pad(b"Zm9vYg="), Err(BadCharacter(6)));
+    assert_eq!(base32::decode_opt_pad(b"MZXW6YQ").unwrap(), b"foob");
+    assert_eq!(base32::decode_opt_pad(b"MZXW6YQ=").unwrap(), b"foob");
+    assert_eq!(base64::decode_opt_pad_len(6), Ok(4));
+    assert_eq!(base64::decode_opt_pad_len(8), Ok(6));
+    let mut output = vec![0u8; 6];
+    assert_eq!(base64::decode_opt_pad_mut(b"Zm9vYg==", &mut output), Ok(4));
+    assert_eq!(&output[.. 4], b"foob");
+    assert_eq!(BASE64URL.decode_opt_pad(b"-_8").unwrap(), b"\xfb\xff");
+}
+
+#[test]
+fn decode_ignore() {
+    use data_encoding::{base32, base64};
+    use data_encoding::decode::Error::*;
+    use data_encoding::specification::BASE32;
+    const WS: &'static [u8] = b" \t\r\n";
+    assert_eq!(base64::decode_ignore(WS, b"").unwrap(), b"");
+    assert_eq!(base64::decode_ignore(WS, b"Zm9vYg==").unwrap(), b"foob");
+    assert_eq!(base64::decode_ignore(WS, b"Zm9v\r\nYg==\r\n").unwrap(), b"foob");
+    assert_eq!(base64::decode_ignore(WS, b" Z m 9 v Y g = = ").unwrap(), b"foob");
+    assert_eq!(base64::decode_ignore(WS, b"Zm9v\nY*==").err().unwrap(), BadCharacter(6));
+    assert_eq!(base64::decode_ignore(WS, b"Zm*v\nYg==").err().unwrap(), BadCharacter(2));
+    assert_eq!(base64::decode_ignore(WS, b"Zm9v\nYg=").err().unwrap(), BadLength);
+    assert_eq!(base64::decode_ignore(WS, b"Zm9v\nYh==").err().unwrap(), BadPadding);
+    assert_eq!(base64::decode_ignore(b"", b"Zm9v\n").err().unwrap(), BadLength);
+    assert_eq!(base64::decode_ignore(WS, b"Zm9\nvYmFy\nYm\nF6").unwrap(), b"foobarbaz");
+    assert_eq!(base64::decode_ignore(WS, b"Zm9\nvYmFy\nY*\nF6"), Err(BadCharacter(11)));
+    assert_eq!(base64::decode_ignore(WS, b"Zm9\nvYm*y\nYm\nF6"), Err(BadCharacter(7)));
+    assert_eq!(base64::decode_ignore(WS, b"\nZg==\nZg=="), Err(BadCharacter(3)));
+    assert_eq!(base32::decode_ignore(b"-", b"MZXW-6YQ=").unwrap(), b"foob");
+    assert_eq!(BASE32.decode_ignore(b"-", b"MZXW-6YQ=").unwrap(), b"foob");
+}
+
+#[test]
+fn translate() {
+    use data_encoding::specification::{BASE64, Specification, SpecificationError, Translate};
+    use data_encoding::decode::Error::*;
+    let mut spec = Specification::new();
+    spec.symbols.push_str("0123456789ABCDEFGHJKMNPQRSTVWXYZ");
+    spec.translate = Translate { from: "ILOilo".to_string(), to: "110110".to_string() };
+    let base = spec.encoding().unwrap();
+    assert_eq!(base.specification(), spec);
+    assert_eq!(base.decode_nopad(b"CO").unwrap(), b"`");
+    assert_eq!(base.decode_nopad(b"Co").unwrap(), b"`");
+    assert_eq!(base.decode_nopad(b"l0").unwrap(), b"\x08");
+    assert_eq!(base.decode_nopad(b"io").unwrap(), b"\x08");
+    assert_eq!(base.decode_nopad(b"Ci"), Err(BadPadding));
+    assert_eq!(base.decode_nopad(b"Cu"), Err(BadCharacter(1)));
+    assert_eq!(data_encoding::decode::decode_nopad(&base, b"Co"), Err(BadCharacter(1)));
+    assert_eq!(base.encode_nopad(b"a"), "C4");
+    let base64 = BASE64.translate("-_", "+/").unwrap();
+    assert_eq!(base64.decode(b"-_8=").unwrap(), b"\xfb\xff");
+    assert_eq!(base64.decode(b"+/8=").unwrap(), b"\xfb\xff");
+    assert_eq!(base64.decode_ignore(b"\n", b"-_\n8=").unwrap(), b"\xfb\xff");
+    assert_eq!(base64.encode(b"\xfb\xff"), "+/8=");
+    assert_eq!(BASE64.translate("-", "+/").err(), Some(SpecificationError::BadTranslate));
+    assert_eq!(BASE64.translate("-", "*").err(), Some(SpecificationError::NotSymbol('*')));
+    assert_eq!(BASE64.translate("a", "b").err(), Some(SpecificationError::Duplicate('a')));
+    assert_eq!(BASE64.translate("--", "++").err(), Some(SpecificationError::Duplicate('-')));
+    assert_eq!(BASE64.translate("=", "A").err(), Some(SpecificationError::PadSymbol));
+    assert_eq!(BASE64.translate("\u{e9}", "A").err(), Some(SpecificationError::NotAscii('\u{e9}')));
+}
+
+#[test]
+fn encode_wrap() {
+    use data_encoding::{base32, base64};
+    use data_encoding::specification::BASE64;
+    assert_eq!(base64::encode_wrap(4, "\n", b""), "");
+    assert_eq!(base64::encode_wrap(4, "\n", b"f"), "Zg==\n");
+    assert_eq!(base64::encode_wrap(4, "\n", b"foo"), "Zm9v\n");
+    assert_eq!(base64::encode_wrap(4, "\n", b"foob"), "Zm9v\nYg==\n");
+    assert_eq!(base64::encode_wrap(8, "\r\n", b"foobarbaz"), "Zm9vYmFy\r\nYmF6\r\n");
+    assert_eq!(base64::encode_wrap(76, "\r\n", &[0; 57]).len(), 78);
+    assert_eq!(base64::encode_wrap(4, "", b"foob"), base64::encode(b"foob"));
+    assert_eq!(base32::encode_wrap(8, " ", b"foobar"), "MZXW6YTB OI====== ");
+    assert_eq!(base64::encode_wrap_len(4, b"\r\n", 4), 12);
+    let mut output = vec![0u8; base64::encode_wrap_len(4, b"|", 4)];
+    base64::encode_wrap_mut(4, b"|", b"foob", &mut output);
+    assert_eq!(output, b"Zm9v|Yg==|");
+    assert_eq!(BASE64.encode_wrap(4, "\n", b"foob"), "Zm9v\nYg==\n");
+}
+
+#[test]
+#[should_panic]
+fn encode_wrap_width() {
+    data_encoding::base64::encode_wrap(6, "\n", b"foobar");
+}
+
+#[test]
+fn bit_order() {
+    use data_encoding::base::BitOrder::*;
+    use data_encoding::specification::Encoding;
+    for &symbols in &["01", "0123", "0123456789abcdef"] {
+        let msb = Encoding::new(symbols, '=', MostSignificantFirst).unwrap();
+        let lsb = Encoding::new(symbols, '=', LeastSignificantFirst).unwrap();
+        for x in 0 .. 256 {
+            let input = [x as u8];
+            let reversed: String = msb.encode(&input).chars().rev().collect();
+            assert_eq!(lsb.encode(&input), reversed);
+            assert_eq!(lsb.decode(reversed.as_bytes()).unwrap(), input);
+        }
+    }
+    let lsb = Encoding::new("01234567", '=', LeastSignificantFirst).unwrap();
+    assert_eq!(lsb.encode(b"\x01\x02\x03"), "10010600");
+    assert_eq!(lsb.decode(b"10010600").unwrap(), b"\x01\x02\x03");
+    assert_eq!(lsb.encode(b"\xc7"), "703=====");
+    assert_eq!(lsb.decode(b"704====="), Err(data_encoding::decode::Error::BadPadding));
+}
+
+#[test]
+fn trailing_bits() {
+    use data_encoding::base::BitOrder::*;
+    use data_encoding::decode::Error::*;
+    use data_encoding::specification::{BASE32, BASE64, Encoding, Specification};
+    let lenient = BASE64.trailing_bits(false);
+    assert_eq!(BASE64.decode(b"Zh=="), Err(BadPadding));
+    assert_eq!(lenient.decode(b"Zh==").unwrap(), b"f");
+    assert_eq!(lenient.decode(b"Zm9=").unwrap(), b"fo");
+    assert_eq!(lenient.decode_nopad(b"Zm9").unwrap(), b"fo");
+    assert_eq!(lenient.decode_opt_pad(b"Zh").unwrap(), b"f");
+    assert_eq!(lenient.decode(b"Zh="), Err(BadLength));
+    assert_eq!(lenient.decode(b"Z==="), Err(BadCharacter(1)));
+    assert_eq!(lenient.encode(b"f"), "Zg==");
+    assert!(!lenient.specification().check_trailing_bits);
+    assert_eq!(BASE32.trailing_bits(false).decode(b"MZ======").unwrap(), b"f");
+    let dnscurve = Encoding::new("0123456789bcdfghjklmnpqrstuvwxyz", '=', LeastSignificantFirst);
+    let dnscurve = dnscurve.unwrap().trailing_bits(false);
+    assert_eq!(dnscurve.decode_nopad(b"6c").unwrap(), b"f");
+    let mut spec = Specification::new();
+    spec.symbols.push_str("0123456789abcdef");
+    spec.check_trailing_bits = false;
+    assert_eq!(spec.encoding().unwrap().specification(), spec);
+}
+
+#[test]
+fn check_base() {
+    use data_encoding::base::{Base, Spec, ValidError, check, valid};
+    use data_encoding::base::ValidError::*;
+    struct Bad;
+    impl Base for Bad {
+        fn pad(&self) -> u8 { b'a' }
+        fn val(&self, x: u8) -> Option<u8> {
+            match x {
+                b'a' => Some(0),
+                b'b' | b'c' => Some(1),
+                0xff => Some(2),
+                _ => None,
+            }
+        }
+        fn bit(&self) -> usize { 1 }
+        fn sym(&self, x: u8) -> u8 { b'a' + x }
+    }
+    let errors = check(&Bad).unwrap_err();
+    assert_eq!(errors.0, [PadSymbol, NotInj(b'c'), SymNotAscii(0xff), NotValue(0xff), NotSurj]);
+    assert_eq!(valid(&Bad), Err(errors.0[0]));
+    assert_eq!(errors.to_string(),
+               "Padding is a symbol. Symbol 'c' is not uniquely mapped to its value. \
+                Symbol '\u{ff}' is not ascii. Symbol '\u{ff}' is not mapped to a value. \
+                All values do not have an associated symbol.");
+    let spec = Spec { val: &[(b'0', b'1'), (b'3', b'3')], pad: b'=' };
+    assert_eq!(check(&spec).unwrap_err().0, [NotValue(b'3'), NotSurj]);
+    let spec = Spec { val: &[(b'0', b'0')], pad: b'=' };
+    assert_eq!(check(&spec).unwrap_err().0, [ValidError::BadBit]);
+    check(data_encoding::base64::base()).unwrap();
+    check(&data_encoding::specification::BASE32).unwrap();
+}
+
+#[test]
+fn encoding_eq_hash() {
+    use std::collections::HashSet;
+    use data_encoding::specification::{BASE16, BASE64, Specification};
+    let mut spec = Specification::new();
+    spec.symbols.push_str("0123456789ABCDEF");
+    assert_eq!(spec.encoding().unwrap(), BASE16);
+    assert!(BASE16 != BASE16.trailing_bits(false));
+    assert!(BASE16 != BASE16.translate("abcdef", "ABCDEF").unwrap());
+    spec.padding = '-';
+    assert!(spec.encoding().unwrap() != BASE16);
+    let mut set = HashSet::new();
+    assert!(set.insert(BASE16));
+    assert!(set.insert(BASE64));
+    assert!(!set.insert(BASE64.clone()));
+    let mut specs = HashSet::new();
+    assert!(specs.insert(BASE64.specification()));
+    assert!(!specs.insert(BASE64.specification()));
+}
+
+#[test]
+fn ignore_wrap() {
+    use data_encoding::decode::Error::*;
+    use data_encoding::specification::{BASE32, BASE64, Specification, SpecificationError, Wrap};
+    let mime = BASE64.ignore(" \r\n").unwrap().wrap(4, "\r\n").unwrap();
+    assert_eq!(mime.encode(b"foob"), "Zm9v\r\nYg==\r\n");
+    assert_eq!(mime.encode_len(4), 12);
+    let mut output = vec![0u8; mime.encode_len(3)];
+    mime.encode_mut(b"foo", &mut output);
+    assert_eq!(output, b"Zm9v\r\n");
+    assert_eq!(mime.encode_nopad(b"foob"), "Zm9vYg");
+    assert_eq!(mime.decode(b"Zm9v\r\nYg==\r\n").unwrap(), b"foob");
+    assert_eq!(mime.decode(b"Zm9v Yg==").unwrap(), b"foob");
+    assert_eq!(mime.decode(b"Zm9v\tYg="), Err(BadCharacter(4)));
+    assert!(mime.is_valid(b"Zm9v\r\nYg==\r\n"));
+    assert!(mime.is_valid(b" Z m 9 v "));
+    assert!(!mime.is_valid(b"Zm9v\r\nYg="));
+    assert!(!mime.is_valid(b"Zm9v\r\nY*=="));
+    assert!(!mime.is_valid(b"Zg==\r\nZg=="));
+    assert!(!mime.is_valid(b"Zm9v\r\nYh=="));
+    assert_eq!(mime.decode_nopad(b"Zm9v\r\nYg").unwrap(), b"foob");
+    assert_eq!(mime.decode_nopad(b"Zm9v\r\nY*"), Err(BadCharacter(7)));
+    assert_eq!(mime.decode_opt_pad(b"Zm9v Yg").unwrap(), b"foob");
+    assert_eq!(mime.decode_opt_pad(b"Zm9v Yg==").unwrap(), b"foob");
+    assert_eq!(mime.decode_opt_pad(b"Zm9v Y*=="), Err(BadCharacter(6)));
+    let mut buffer = b"Zm9v\r\nYg==\r\n".to_vec();
+    assert_eq!(mime.decode_in_place(&mut buffer), Ok(4));
+    assert_eq!(&buffer[.. 4], b"foob");
+    assert_eq!(mime.decode_in_place(&mut b"Zm9v\r\nY*==\r\n".to_vec()), Err(BadCharacter(7)));
+    assert_eq!(mime.decode_in_place(&mut b"Zm\r\n*vYg==".to_vec()), Err(BadCharacter(4)));
+    assert_eq!(mime.decode_in_place(&mut b"Zg==\r\nZg==".to_vec()), Err(BadCharacter(2)));
+    assert_eq!(mime.decode_in_place(&mut b"Zm9v\r\nYg=".to_vec()), Err(BadLength));
+    assert_eq!(mime.decode_in_place(&mut b"\r\n".to_vec()), Ok(0));
+    let mut append = b"x".to_vec();
+    assert_eq!(mime.decode_append(b"Zm9v Yg==", &mut append), Ok(4));
+    assert_eq!(append, b"xfoob");
+    let mut output = [0u8; 9];
+    assert_eq!(mime.decode_mut(b"Zm9v Yg==", &mut output), Err(BadLength));
+    assert_eq!(mime.decode_mut(b"Zm9v Yg=", &mut output[.. 6]), Err(BadCharacter(4)));
+    assert_eq!(mime.decode_opt_pad_mut(b"Zm9v Yg", &mut output[.. 5]), Err(BadCharacter(4)));
+    let spec = mime.specification();
+    assert_eq!(spec.ignore, "\n\r ");
+    assert_eq!(spec.wrap, Wrap { width: 4, separator: "\r\n".to_string() });
+    assert_eq!(spec.encoding().unwrap(), mime);
+    assert_eq!(BASE64.wrap(6, "\n").err(), Some(SpecificationError::BadWrap));
+    assert_eq!(BASE64.wrap(4, "123456789").err(), Some(SpecificationError::BadWrap));
+    assert_eq!(BASE32.wrap(8, "\u{e9}").err(), Some(SpecificationError::BadWrap));
+    assert_eq!(BASE64.ignore("A").err(), Some(SpecificationError::Duplicate('A')));
+    assert_eq!(BASE64.ignore("==").err(), Some(SpecificationError::PadSymbol));
+    assert_eq!(BASE64.ignore("  ").err(), Some(SpecificationError::Duplicate(' ')));
+    let mut spec = Specification::new();
+    spec.symbols.push_str("01");
+    spec.ignore.push_str("_");
+    assert_eq!(spec.encoding().unwrap().decode(b"0110_0110").unwrap(), b"f");
+}
+
+#[test]
+fn descriptor() {
+    use data_encoding::base::BitOrder::*;
+    use data_encoding::specification::{BASE64, Encoding, Specification};
+    use data_encoding::specification::SpecificationError::*;
+    let spec: Specification = "symbols=0123456789abcdef".parse().unwrap();
+    assert_eq!(spec.encoding().unwrap().encode(b"\x01\xab"), "01ab");
+    let mime = BASE64.ignore("\r\n").unwrap().wrap(76, "\r\n").unwrap();
+    let descriptor = "symbols=ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/; \
+                      padding='='; ignore=\"\\r\\n\"; wrap=76:\\r\\n";
+    assert_eq!(descriptor.parse::<Encoding>().unwrap(), mime);
+    let spec: Specification = " symbols = \"0123456789bcdfghjklmnpqrstuvwxyz\" ; bit_order = lsb ;\
+                               translate = BCD:bcd ; check_trailing_bits=false; padding=\\;;"
+        .parse().unwrap();
+    assert_eq!(spec.bit_order, LeastSignificantFirst);
+    assert_eq!(spec.translate.from, "BCD");
+    assert_eq!(spec.translate.to, "bcd");
+    assert!(!spec.check_trailing_bits);
+    assert_eq!(spec.padding, ';');
+    assert_eq!(spec.to_string().parse::<Specification>().unwrap(), spec);
+    assert_eq!(mime.specification().to_string(),
+               "symbols=\"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/\"; \
+                padding=\"=\"; ignore=\"\\n\\r\"; wrap=76:\"\\r\\n\"");
+    assert_eq!(mime.specification().to_string().parse::<Encoding>().unwrap(), mime);
+    let x: Specification = "symbols=01; translate=ab:01; ignore=\"_-\"".parse().unwrap();
+    let y: Specification = "symbols=01; translate=ba:10; ignore=\"-_\"".parse().unwrap();
+    assert_ne!(x, y);
+    assert_eq!(x.encoding().unwrap(), y.encoding().unwrap());
+    assert_eq!(x.encoding().unwrap().specification(), y.encoding().unwrap().specification());
+    assert_eq!("".parse::<Specification>().unwrap(), Specification::new());
+    assert_eq!("symbols=01; foo=1".parse::<Specification>().err(), Some(Syntax(12)));
+    assert_eq!("symbols=01 padding=-".parse::<Specification>().err(), Some(Syntax(11)));
+    assert_eq!("symbols=\"01".parse::<Specification>().err(), Some(Syntax(11)));
+    assert_eq!("symbols=01; padding=ab".parse::<Specification>().err(), Some(Syntax(20)));
+    assert_eq!("symbols=01; bit_order=x".parse::<Specification>().err(), Some(Syntax(22)));
+    assert_eq!("symbols=01; wrap=x:y".parse::<Specification>().err(), Some(Syntax(17)));
+    assert_eq!("symbols=01; wrap=8".parse::<Specification>().err(), Some(Syntax(18)));
+    assert_eq!("symbols=".parse::<Specification>().err(), Some(Syntax(8)));
+    assert_eq!("symbols=012".parse::<Encoding>().err(), Some(BadSize));
+}
+
+#[test]
+fn registry() {
+    use data_encoding::decode::Error::*;
+    use data_encoding::registry::Registry;
+    use data_encoding::specification::BASE64;
+    let mut registry = Registry::standard();
+    assert_eq!(registry.names(),
+               ["base16", "base2", "base32", "base32-nopad", "base32hex", "base32hex-nopad",
+                "base4", "base64", "base64-nopad", "base64url", "base64url-nopad", "base8",
+                "dnscurve", "hex", "zbase32"]);
+    let encode = |name: &str, input: &[u8]| registry.get(name).unwrap().encode(input);
+    assert_eq!(encode("base2", b"f"), "01100110");
+    assert_eq!(encode("base8", b"f"), "314");
+    assert_eq!(encode("base16", b"\xab"), "AB");
+    assert_eq!(encode("hex", b"\xab"), "ab");
+    assert_eq!(encode("base32", b"f"), "MY======");
+    assert_eq!(encode("base32-nopad", b"f"), "MY");
+    assert_eq!(encode("base32hex-nopad", b"f"), "CO");
+    assert_eq!(encode("zbase32", b"f"), "ca");
+    assert_eq!(encode("dnscurve", b"f"), "63");
+    assert_eq!(encode("base64", b"\xfb"), "+w==");
+    assert_eq!(encode("base64url-nopad", b"\xfb"), "-w");
+    let decode = |name: &str, input: &[u8]| registry.get(name).unwrap().decode(input);
+    assert_eq!(decode("hex", b"aB").unwrap(), b"\xab");
+    assert_eq!(decode("base64-nopad", b"Zg").unwrap(), b"f");
+    assert_eq!(decode("base64-nopad", b"Zg=="), Err(BadCharacter(2)));
+    assert_eq!(decode("base64", b"Zg"), Err(BadLength));
+    assert!(registry.get("base65").is_none());
+    let mime = BASE64.ignore("\r\n").unwrap().wrap(76, "\r\n").unwrap();
+    assert!(registry.register("mime", mime.clone(), true).is_none());
+    assert_eq!(registry.get("mime").unwrap().encode(b"f"), "Zg==\r\n");
+    assert_eq!(registry.register("mime", BASE64, true).unwrap().encoding, mime);
+    assert_eq!(registry.unregister("mime").unwrap().encoding, BASE64);
+    assert!(registry.get("mime").is_none());
+    assert!(Registry::new().names().is_empty());
+}
+
+#[test]
+fn encode_append() {
+    use data_encoding::{base32, base64, encode};
+    use data_encoding::specification::BASE64;
+    let mut output = String::with_capacity(16);
+    base64::encode_append(b"", &mut output);
+    assert_eq!(output, "");
+    output.push_str("sha256-");
+    base64::encode_append(b"foob", &mut output);
+    assert_eq!(output, "sha256-Zm9vYg==");
+    output.push(' ');
+    base32::encode_append(b"f", &mut output);
+    assert_eq!(output, "sha256-Zm9vYg== MY======");
+    let base128 = data_encoding::base::Spec { val: &[(128, 255)], pad: b'=' };
+    let mut output = "\u{e9}".to_string();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        encode::encode_append(&base128, b"\x00", &mut output)
+    }));
+    assert!(result.is_err());
+    assert_eq!(output, "\u{e9}");
+    let mut output = "x".to_string();
+    BASE64.wrap(4, "\n").unwrap().encode_append(b"foob", &mut output);
+    assert_eq!(output, "xZm9v\nYg==\n");
+}
+
+#[test]
+fn decode_append() {
+    use data_encoding::{base32, base64};
+    use data_encoding::decode::Error::*;
+    use data_encoding::specification::BASE64;
+    let mut output = Vec::with_capacity(16);
+    assert_eq!(base64::decode_append(b"", &mut output), Ok(0));
+    assert_eq!(base64::decode_append(b"Zm9vYg==", &mut output), Ok(4));
+    assert_eq!(output, b"foob");
+    assert_eq!(output.capacity(), 16);
+    assert_eq!(base32::decode_append(b"MY======", &mut output), Ok(1));
+    assert_eq!(output, b"foobf");
+    assert_eq!(base64::decode_append(b"Zm9vYh==", &mut output), Err(BadPadding));
+    assert_eq!(base64::decode_append(b"Zm9", &mut output), Err(BadLength));
+    assert_eq!(output, b"foobf");
+    let mime = BASE64.ignore("\n").unwrap();
+    assert_eq!(mime.decode_append(b"Zm9v\nYg==", &mut output), Ok(4));
+    assert_eq!(BASE64.decode_append(b"YmFy", &mut output), Ok(3));
+    assert_eq!(mime.decode_append(b"Zm9v\nY*==", &mut output), Err(BadCharacter(6)));
+    assert_eq!(output, b"foobffoobbar");
+}
+
+#[test]
+fn encode_write() {
+    use std::fmt::Write;
+    use data_encoding::{base32, base64, encode};
+    use data_encoding::specification::BASE64;
+    let mut output = String::new();
+    base64::encode_write(b"", &mut output).unwrap();
+    assert_eq!(output, "");
+    base64::encode_write(b"foob", &mut output).unwrap();
+    write!(output, " ").unwrap();
+    base32::encode_write(b"f", &mut output).unwrap();
+    assert_eq!(output, "Zm9vYg== MY======");
+    let input: Vec<u8> = (0 .. 3000).map(|i| i as u8).collect();
+    for &len in &[767, 768, 769, 1536, 3000] {
+        let mut output = String::new();
+        base64::encode_write(&input[.. len], &mut output).unwrap();
+        assert_eq!(output, base64::encode(&input[.. len]));
+        let mut output = String::new();
+        base32::encode_write(&input[.. len], &mut output).unwrap();
+        assert_eq!(output, base32::encode(&input[.. len]));
+    }
+    let mime = BASE64.wrap(8, "\r\n").unwrap();
+    let mut output = String::new();
+    mime.encode_write(&input, &mut output).unwrap();
+    assert_eq!(output, mime.encode(&input));
+    let mut output = String::new();
+    let base = data_encoding::base::Spec { val: &[(0, 127)], pad: 128 };
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        encode::encode_write(&base, b"\xff\x00\x00\x00\x00\x00\x00", &mut output)
+    }));
+    assert!(result.is_err());
+    assert_eq!(output, "");
+}
+
+#[test]
+fn encode_extend() {
+    use std::collections::VecDeque;
+    use data_encoding::{base32, base64};
+    use data_encoding::specification::BASE64;
+    let mut output = b"x".to_vec();
+    base64::encode_extend(b"", &mut output);
+    base64::encode_extend(b"foob", &mut output);
+    base32::encode_extend(b"f", &mut output);
+    assert_eq!(output, b"xZm9vYg==MY======");
+    let input: Vec<u8> = (0 .. 3000).map(|i| i as u8).collect();
+    for &len in &[767, 768, 769, 1536, 3000] {
+        let mut output = VecDeque::new();
+        base64::encode_extend(&input[.. len], &mut output);
+        assert_eq!(output, base64::encode(&input[.. len]).into_bytes());
+    }
+    let mime = BASE64.wrap(8, "\r\n").unwrap();
+    let mut output = Vec::new();
+    mime.encode_extend(&input, &mut output);
+    assert_eq!(output, mime.encode(&input).into_bytes());
+}
+
+#[test]
+fn decode_in_place() {
+    use data_encoding::{base2, base32, base64, hexlower};
+    use data_encoding::decode::Error::*;
+    use data_encoding::specification::BASE16;
+    let input: Vec<u8> = (0 .. 300).map(|i| (i * 7) as u8).collect();
+    for len in 0 .. 20 {
+        let input = &input[.. len];
+        let mut buffer = base64::encode(input).into_bytes();
+        assert_eq!(base64::decode_in_place(&mut buffer), Ok(len));
+        assert_eq!(&buffer[.. len], input);
+        let mut buffer = base32::encode(input).into_bytes();
+        assert_eq!(base32::decode_in_place(&mut buffer), Ok(len));
+        assert_eq!(&buffer[.. len], input);
+        let mut buffer = base2::encode(input).into_bytes();
+        assert_eq!(base2::decode_in_place(&mut buffer), Ok(len));
+        assert_eq!(&buffer[.. len], input);
+    }
+    assert_eq!(base64::decode_in_place(&mut b"Zm9vYg=".to_vec()), Err(BadLength));
+    assert_eq!(base64::decode_in_place(&mut b"Zm9vYh==".to_vec()), Err(BadPadding));
+    assert_eq!(base64::decode_in_place(&mut b"Zm9vY*==".to_vec()), Err(BadCharacter(5)));
+    assert_eq!(base64::decode_in_place(&mut b"Zm*vYg==".to_vec()), Err(BadCharacter(2)));
+    let mut buffer = b"aBcD".to_vec();
+    assert_eq!(hexlower::decode_in_place(&mut buffer), Ok(2));
+    assert_eq!(&buffer[.. 2], b"\xab\xcd");
+    let mut buffer = b"aBcD".to_vec();
+    assert_eq!(BASE16.translate("abcdef", "ABCDEF").unwrap().decode_in_place(&mut buffer), Ok(2));
+    assert_eq!(&buffer[.. 2], b"\xab\xcd");
+}
+
+#[test]
+fn encode_in_place() {
+    use data_encoding::{base2, base32, base64, dnscurve};
+    use data_encoding::specification::BASE64;
+    let input: Vec<u8> = (0 .. 300).map(|i| (i * 7) as u8).collect();
+    for len in 0 .. 20 {
+        let input = &input[.. len];
+        let mut buffer = input.to_vec();
+        buffer.resize(base64::encode_len(len), 0);
+        base64::encode_in_place(&mut buffer, len);
+        assert_eq!(buffer, base64::encode(input).into_bytes());
+        let mut buffer = input.to_vec();
+        buffer.resize(base32::encode_len(len), 0);
+        base32::encode_in_place(&mut buffer, len);
+        assert_eq!(buffer, base32::encode(input).into_bytes());
+        let mut buffer = input.to_vec();
+        buffer.resize(base2::encode_len(len), 0);
+        base2::encode_in_place(&mut buffer, len);
+        assert_eq!(buffer, base2::encode(input).into_bytes());
+        let mut buffer = input.to_vec();
+        buffer.resize(dnscurve::encode_len(len), 0);
+        dnscurve::encode_in_place(&mut buffer, len);
+        assert_eq!(buffer, dnscurve::encode(input).into_bytes());
+    }
+    let mut buffer = b"fo\0\0".to_vec();
+    BASE64.encode_in_place(&mut buffer, 2);
+    assert_eq!(buffer, b"Zm8=");
+}
+
+#[test]
+#[should_panic]
+fn encode_in_place_len() {
+    data_encoding::base64::encode_in_place(&mut [0; 7], 4);
+}
+
+#[test]
+fn decode_iter() {
+    use std::collections::VecDeque;
+    use std::io::Read;
+    use data_encoding::{base32, base64, hexlower};
+    use data_encoding::decode::Error::*;
+    for e in &["", "Zg==", "Zm8=", "Zm9v", "Zm9vYg==", "Zm9vYmE=", "Zm9vYmFy"] {
+        let d = base64::decode(e.as_bytes()).unwrap();
+        assert_eq!(base64::decode_iter(e.bytes()).unwrap(), d);
+    }
+    let mut ring: VecDeque<u8> = b"YTBOI======".iter().cloned().collect();
+    for &x in b"MZXW6".iter().rev() {
+        ring.push_front(x);
+    }
+    assert_eq!(base32::decode_iter(ring.iter().cloned()).unwrap(), b"foobar");
+    let bytes = (&b"2A2a"[..]).bytes().map(|x| x.unwrap());
+    assert_eq!(hexlower::decode_iter(bytes).unwrap(), b"**");
+    assert_eq!(base64::decode_iter(b"Zm9".iter().cloned()), Err(BadLength));
+    assert_eq!(base64::decode_iter(b"Zm9vY.==".iter().cloned()), Err(BadCharacter(5)));
+    assert_eq!(base64::decode_iter(b"Zm9vYh==".iter().cloned()), Err(BadPadding));
+    assert_eq!(base64::decode_iter(b"Zg==Zg==".iter().cloned()), Err(BadCharacter(2)));
+    assert_eq!(base64::decode_iter(b"Zm.vYg".iter().cloned()), Err(BadCharacter(2)));
+}
+
+#[test]
+fn partial() {
+    use data_encoding::base64;
+    use data_encoding::decode::Error::*;
+    let input = b"Hello world, this is a partial test";
+    let encoded = base64::encode(input);
+    for &size in &[4, 5, 8, 12, 64] {
+        let mut output = Vec::new();
+        let mut buffer = vec![0u8; size];
+        let mut rest = &input[..];
+        loop {
+            let (i, o) = base64::encode_partial(rest, &mut buffer);
+            output.extend_from_slice(&buffer[.. o]);
+            rest = &rest[i ..];
+            if i == 0 { break; }
+        }
+        let mut last = vec![0u8; base64::encode_len(rest.len())];
+        base64::encode_mut(rest, &mut last);
+        output.extend_from_slice(&last);
+        assert_eq!(output, encoded.as_bytes());
+    }
+    for &size in &[3, 4, 7, 64] {
+        let mut output = Vec::new();
+        let mut buffer = vec![0u8; size];
+        let mut rest = encoded.as_bytes();
+        while !rest.is_empty() {
+            let (i, o) = base64::decode_partial(rest, &mut buffer).unwrap();
+            output.extend_from_slice(&buffer[.. o]);
+            rest = &rest[i ..];
+        }
+        assert_eq!(output, &input[..]);
+    }
+    let mut buffer = [0u8; 8];
+    assert_eq!(base64::encode_partial(b"fo", &mut buffer), (0, 0));
+    assert_eq!(base64::encode_partial(b"foobar", &mut buffer[.. 3]), (0, 0));
+    assert_eq!(base64::decode_partial(b"Zm9", &mut buffer), Ok((0, 0)));
+    assert_eq!(base64::decode_partial(b"Zm9vYg", &mut buffer), Ok((4, 3)));
+    assert_eq!(base64::decode_partial(b"Zm9vYg==Zm9v", &mut buffer), Ok((8, 4)));
+    assert_eq!(base64::decode_partial(b"Zm9vYg==", &mut buffer[.. 3]), Ok((4, 3)));
+    assert_eq!(base64::decode_partial(b"Zm9vYg==", &mut buffer[.. 2]), Ok((0, 0)));
+    assert_eq!(base64::decode_partial(b"Zm9vY.==", &mut buffer), Err(BadCharacter(5)));
+    assert_eq!(base64::decode_partial(b"Zm9vY=g=", &mut buffer), Err(BadCharacter(5)));
+    assert_eq!(base64::decode_partial(b"Zm9vYh==", &mut buffer), Err(BadPadding));
+}
+
+#[test]
+fn buffer() {
+    use data_encoding::{base32, base64, hexlower};
+    use data_encoding::buffer::{Buffer, CAPACITY};
+    use data_encoding::specification::BASE64;
+    let mut buffer = Buffer::new();
+    assert_eq!(buffer.format(hexlower::base(), &[0xab; 64]), "ab".repeat(64));
+    assert_eq!(buffer.format(base64::base(), b"foob"), "Zm9vYg==");
+    assert_eq!(buffer.format_nopad(base64::base(), b"foob"), "Zm9vYg");
+    assert_eq!(buffer.format(&BASE64, b""), "");
+    assert_eq!(buffer.format(base32::base(), &[0; 80]).len(), CAPACITY);
+    let mut buffer = Buffer::default();
+    assert_eq!(buffer.format(&BASE64, b"fo"), "Zm8=");
+}
+
+#[test]
+#[should_panic]
+fn buffer_capacity() {
+    let mut buffer = data_encoding::buffer::Buffer::new();
+    buffer.format(data_encoding::hexlower::base(), &[0; 65]);
+}
+
+#[test]
+fn encode_array() {
+    use data_encoding::{base2, base32, base64, encode, hexlower};
+    use data_encoding::encode::{encode_array_len, encode_nopad_array_len};
+    const VAL: [&'static [(u8, u8)]; 7] =
+        [&[(0, 1)], &[(0, 3)], &[(0, 7)], &[(0, 15)], &[(0, 31)], &[(0, 63)], &[(0, 127)]];
+    for bit in 1 .. 8 {
+        let base = data_encoding::base::Spec { val: VAL[bit - 1], pad: 128 };
+        for len in 0 .. 64 {
+            assert_eq!(encode_array_len(bit, len), encode::encode_len(&base, len));
+            assert_eq!(encode_nopad_array_len(bit, len), encode::encode_nopad_len(&base, len));
+        }
+    }
+    let output: [u8; 8] = base64::encode_array(b"foob");
+    assert_eq!(&output, b"Zm9vYg==");
+    let output: [u8; 6] = base64::encode_nopad_array(b"foob");
+    assert_eq!(&output, b"Zm9vYg");
+    let output: [u8; 40] = hexlower::encode_array(&[0xab; 20]);
+    assert_eq!(&output[..], "ab".repeat(20).as_bytes());
+    let output: [u8; 16] = base2::encode_array(b"*a");
+    assert_eq!(&output, b"0010101001100001");
+    let output: [u8; 0] = base32::encode_array(b"");
+    assert_eq!(output.len(), 0);
+    let output: [u8; 16] = encode::encode_array(base32::base(), b"foobar");
+    assert_eq!(&output, b"MZXW6YTBOI======");
+    let output: [u8; encode_nopad_array_len(5, 6)] = encode::encode_nopad_array(base32::base(), b"foobar");
+    assert_eq!(&output, b"MZXW6YTBOI");
+}
+
+#[test]
+#[should_panic]
+fn encode_array_len_mismatch() {
+    let _: [u8; 7] = data_encoding::encode::encode_array(data_encoding::base64::base(), b"foob");
+}
+
+#[test]
+fn normalize() {
+    use data_encoding::{base64, decode, hexlower};
+    use data_encoding::decode::Error::*;
+    use data_encoding::specification::{BASE32, BASE64};
+    assert_eq!(base64::normalize(b"").unwrap(), "");
+    assert_eq!(base64::normalize(b"Zm9vYg==").unwrap(), "Zm9vYg==");
+    assert_eq!(base64::normalize(b"Zm9vYg").unwrap(), "Zm9vYg==");
+    assert_eq!(base64::normalize(b"Zm9vYmE").unwrap(), "Zm9vYmE=");
+    assert_eq!(base64::normalize(b"Zm9vYh"), Err(BadPadding));
+    assert_eq!(base64::normalize(b"Zm9vY*"), Err(BadCharacter(5)));
+    assert_eq!(base64::normalize(b"Zm9vY"), Err(BadLength));
+    assert_eq!(base64::normalize(b"Zm9vYg="), Err(BadCharacter(6)));
+    assert_eq!(BASE64.normalize(b"Zm9vYg").unwrap(), "Zm9vYg==");
+    assert_eq!(base64::normalize(b"Zm9v.g=="), Err(BadCharacter(4)));
+    assert_eq!(hexlower::normalize(b"2A").unwrap(), "2a");
+    let mut spec = BASE64.specification();
+    spec.check_trailing_bits = false;
+    let lenient = spec.encoding().unwrap();
+    assert_eq!(decode::normalize(&lenient, b"Zm9vYh==").unwrap(), "Zm9vYg==");
+    assert_eq!(lenient.normalize(b"Zh==").unwrap(), "Zg==");
+    let lower = BASE32.translate("abcdefghijklmnopqrstuvwxyz", "ABCDEFGHIJKLMNOPQRSTUVWXYZ")
+        .unwrap().ignore(" ").unwrap();
+    assert_eq!(lower.normalize(b"my======").unwrap(), "MY======");
+    assert_eq!(lower.normalize(b"mzxw 6ytb oi== ====").unwrap(), "MZXW6YTBOI======");
+    assert_eq!(lower.normalize(b"mzxw 6ytb oi= ===="), Err(BadCharacter(12)));
+    assert_eq!(lower.normalize(b"mzxw 6ytb oi").unwrap(), "MZXW6YTBOI======");
+    assert_eq!(decode::normalize(&lenient, b"Zm9vYh").unwrap(), "Zm9vYg==");
+    let mime = BASE64.wrap(8, "\r\n").unwrap().ignore("\r\n").unwrap();
+    let output = mime.normalize(b"Zm9v\r\nYmFyZm9v").unwrap();
+    assert_eq!(output, "Zm9vYmFy\r\nZm9v\r\n");
+    assert!(mime.is_canonical(output.as_bytes()));
+}
+
+#[test]
+fn validation() {
+    use data_encoding::{base64, decode, hexlower};
+    use data_encoding::decode::Error::*;
+    use data_encoding::specification::BASE64;
+    for e in &["", "Zg==", "Zm8=", "Zm9v", "Zm9vYg==", "Zm9vYmE=", "Zm9vYmFy"] {
+        let d = base64::decode(e.as_bytes()).unwrap();
+        assert_eq!(decode::validate(base64::base(), e.as_bytes()), Ok(d.len()));
+        assert!(base64::is_valid(e.as_bytes()));
+        assert!(base64::is_canonical(e.as_bytes()));
+    }
+    assert_eq!(decode::validate(base64::base(), b"Zm9"), Err(BadLength));
+    assert_eq!(decode::validate(base64::base(), b"Zm9v.g=="), Err(BadCharacter(4)));
+    assert_eq!(decode::validate(base64::base(), b"Zh=="), Err(BadPadding));
+    assert!(!base64::is_valid(b"Zm9vY==="));
+    assert!(!base64::is_canonical(b"Zh=="));
+    assert!(hexlower::is_valid(b"2a") && hexlower::is_valid(b"2A"));
+    assert!(hexlower::is_canonical(b"2a") && !hexlower::is_canonical(b"2A"));
+    let mime = BASE64.wrap(8, "\r\n").unwrap().ignore("\r\n").unwrap();
+    assert!(mime.is_valid(b"Zm9vYmFy\r\nZm9v\r\n"));
+    assert!(mime.is_valid(b"Zm9v\r\nYmFyZm9v"));
+    assert!(!mime.is_valid(b"Zm9v\r\nYmFyZm9"));
+    assert!(mime.is_canonical(b""));
+    assert!(mime.is_canonical(b"Zm9vYmFy\r\nZm9v\r\n"));
+    assert!(mime.is_canonical(b"Zm9vYmFy\r\n"));
+    assert!(mime.is_canonical(b"Zg==\r\n"));
+    assert!(!mime.is_canonical(b"Zm9vYmFy\r\nZm9v"));
+    assert!(!mime.is_canonical(b"Zm9v\r\nYmFyZm9v\r\n"));
+    assert!(!mime.is_canonical(b"Zm9vYmFyZm9v\r\n"));
+    assert!(!mime.is_canonical(b"Zg==Zg==\r\nZg==\r\n"));
+    assert!(!mime.is_canonical(b"\r\n"));
+    let lower = BASE64.translate("-_", "+/").unwrap();
+    assert!(lower.is_valid(b"-_-_") && !lower.is_canonical(b"-_-_"));
+    assert!(lower.is_canonical(b"+/+/"));
+}
+
+#[test]
+fn decode_exact_len() {
+    use data_encoding::{base32, base64};
+    use data_encoding::decode::Error::*;
+    for &(d, e) in &[("", ""), ("f", "Zg=="), ("fo", "Zm8="), ("foo", "Zm9v"),
+                     ("foob", "Zm9vYg=="), ("fooba", "Zm9vYmE="), ("foobar", "Zm9vYmFy")] {
+        assert_eq!(base64::decode_exact_len(e.as_bytes()), Ok(d.len()));
+    }
+    for e in &["", "MY======", "MZXQ====", "MZXW6===", "MZXW6YQ=", "MZXW6YTB"] {
+        let d = base32::decode(e.as_bytes()).unwrap();
+        assert_eq!(base32::decode_exact_len(e.as_bytes()), Ok(d.len()));
+    }
+    assert_eq!(base64::decode_exact_len(b"Zm9vY"), Err(BadLength));
+    assert_eq!(base64::decode_exact_len(b"Zm9vY==="), Err(BadCharacter(5)));
+    assert_eq!(base64::decode_exact_len(b"Zm9v===="), Err(BadCharacter(4)));
+    assert_eq!(base64::decode_exact_len(b"Zm9vY=g="), Err(BadCharacter(6)));
+    assert_eq!(base32::decode_exact_len(b"MZX====="), Err(BadCharacter(3)));
+}
+
+#[test]
+fn checked_len() {
+    use std::usize::MAX;
+    use data_encoding::{base32, base64, decode, encode};
+    assert_eq!(encode::checked_encode_len(base64::base(), 4), Some(8));
+    assert_eq!(encode::checked_encode_len(base64::base(), MAX / 4 * 3), Some(MAX / 4 * 4));
+    assert_eq!(encode::checked_encode_len(base64::base(), MAX / 4 * 3 + 1), None);
+    assert_eq!(encode::checked_encode_len(base32::base(), MAX), None);
+    assert_eq!(encode::checked_encode_nopad_len(base64::base(), 4), Some(6));
+    assert_eq!(encode::checked_encode_nopad_len(base64::base(), MAX / 4 * 3), Some(MAX / 4 * 4));
+    assert_eq!(encode::checked_encode_nopad_len(base64::base(), MAX / 4 * 3 + 2), Some(MAX));
+    assert_eq!(encode::checked_encode_nopad_len(base64::base(), MAX / 4 * 3 + 3), None);
+    assert_eq!(decode::checked_decode_len(base64::base(), 8), Some(6));
+    assert_eq!(decode::checked_decode_len(base64::base(), MAX), Some(MAX / 4 * 3 + 3));
+    assert_eq!(decode::decode_nopad_len(base64::base(), MAX / 4 * 4), Ok(MAX / 4 * 3));
+    assert_eq!(decode::decode_nopad_len(base32::base(), MAX - 1), Err(decode::Error::BadLength));
+}
+
+#[test]
+#[should_panic]
+fn encode_len_overflow() {
+    data_encoding::base64::encode_len(::std::usize::MAX);
+}
+
+#[test]
+fn try_encode() {
+    use data_encoding::{base64, encode, specification};
+    assert_eq!(base64::try_encode(b"foob"), Ok("Zm9vYg==".to_string()));
+    assert_eq!(base64::try_encode_nopad(b"foob"), Ok("Zm9vYg".to_string()));
+    assert_eq!(encode::try_encode_wrap(base64::base(), 4, "\n", b"foob"),
+               Ok("Zm9v\nYg==\n".to_string()));
+    let mut spec = specification::Specification::new();
+    spec.symbols.push_str("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/");
+    spec.wrap.width = 4;
+    spec.wrap.separator.push_str("\n");
+    let encoding = spec.encoding().unwrap();
+    assert_eq!(encoding.try_encode(b"foob"), Ok("Zm9v\nYg==\n".to_string()));
+    assert_eq!(encoding.try_encode_nopad(b"foob"), Ok("Zm9vYg".to_string()));
+}
+
+#[test]
+fn try_mut() {
+    use data_encoding::base64;
+    use data_encoding::decode::Error::*;
+    let mut buffer = [0u8; 8];
+    assert_eq!(base64::try_encode_mut(b"foob", &mut buffer), Ok(()));
+    assert_eq!(&buffer, b"Zm9vYg==");
+    assert_eq!(base64::try_encode_mut(b"foob", &mut buffer[.. 6]),
+               Err(BadOutput));
+    assert_eq!(base64::try_encode_nopad_mut(b"foob", &mut buffer[.. 6]), Ok(()));
+    assert_eq!(&buffer[.. 6], b"Zm9vYg");
+    assert_eq!(base64::try_encode_nopad_mut(b"foob", &mut buffer),
+               Err(BadOutput));
+    assert_eq!(base64::try_decode_mut(b"Zm9vYg==", &mut buffer[.. 6]), Ok(4));
+    assert_eq!(&buffer[.. 4], b"foob");
+    assert_eq!(base64::try_decode_mut(b"Zm9vYg==", &mut buffer), Err(BadOutput));
+    assert_eq!(base64::try_decode_mut(b"Zm9vYg=", &mut buffer), Err(BadLength));
+    assert_eq!(base64::try_decode_nopad_mut(b"Zm9vYg", &mut buffer[.. 4]), Ok(()));
+    assert_eq!(base64::try_decode_nopad_mut(b"Zm9vYg", &mut buffer), Err(BadOutput));
+    assert_eq!(base64::try_decode_nopad_mut(b"Zm9v.g", &mut buffer[.. 4]),
+               Err(BadCharacter(4)));
+}
+
+// The input must be larger than an eighth of the address space for
+// the output length to overflow, which is only possible on 32-bit
+// targets.
+#[cfg(target_pointer_width = "32")]
+#[test]
+fn try_mut_overflow() {
+    use data_encoding::base2;
+    use data_encoding::decode::Error::*;
+    let input = vec![0u8; (::std::usize::MAX >> 3) + 1];
+    assert_eq!(base2::try_encode_mut(&input, &mut []), Err(BadOutput));
+    assert_eq!(base2::try_encode_nopad_mut(&input, &mut []), Err(BadOutput));
+}
+
+#[test]
+#[should_panic]
+fn decode_mut_output() {
+    let _ = data_encoding::base64::decode_mut(b"Zm9vYg==", &mut [0; 8]);
+}
+
+#[test]
+fn uninit_mut() {
+    use std::mem::MaybeUninit;
+    use data_encoding::{base32, base64, decode, encode};
+    use data_encoding::decode::Error::*;
+    let mut buffer = [MaybeUninit::uninit(); 16];
+    for &(d, e) in &[(&b""[..], &b""[..]), (b"f", b"Zg=="), (b"fo", b"Zm8="),
+                     (b"foo", b"Zm9v"), (b"foob", b"Zm9vYg=="), (b"fooba", b"Zm9vYmE=")] {
+        let n = base64::encode_len(d.len());
+        assert_eq!(base64::encode_uninit_mut(d, &mut buffer[.. n]), e);
+        let n = base64::decode_len(e.len());
+        assert_eq!(base64::decode_uninit_mut(e, &mut buffer[.. n]).unwrap(), d);
+    }
+    assert_eq!(encode::encode_uninit_mut(base32::base(), b"foobar", &mut buffer),
+               b"MZXW6YTBOI======");
+    assert_eq!(decode::decode_uninit_mut(base32::base(), b"MZXW6YTBOI======",
+                                         &mut buffer[.. 10]).unwrap(), b"foobar");
+    assert_eq!(base64::decode_uninit_mut(b"Zm9", &mut buffer[.. 3]), Err(BadLength));
+    assert_eq!(base64::decode_uninit_mut(b"Zm9vY.==", &mut buffer[.. 6]),
+               Err(BadCharacter(5)));
+    assert_eq!(base64::decode_uninit_mut(b"Zm9vYh==", &mut buffer[.. 6]), Err(BadPadding));
+}
+
+#[test]
+#[should_panic]
+fn decode_uninit_mut_empty() {
+    use std::mem::MaybeUninit;
+    let mut buffer = [MaybeUninit::uninit(); 1];
+    let _ = data_encoding::base64::decode_uninit_mut(b"", &mut buffer);
+}
+
+#[test]
+fn encode_mut_str() {
+    use data_encoding::{base32, base64, encode};
+    use data_encoding::specification::BASE64;
+    let mut buffer = [0u8; 16];
+    assert_eq!(base64::encode_mut_str(b"foob", &mut buffer[.. 8]), "Zm9vYg==");
+    assert_eq!(base64::encode_nopad_mut_str(b"foob", &mut buffer[.. 6]), "Zm9vYg");
+    assert_eq!(base32::encode_mut_str(b"", &mut buffer[.. 0]), "");
+    let output = base32::encode_mut_str(b"f", &mut buffer[.. 8]);
+    output.make_ascii_lowercase();
+    assert_eq!(output, "my======");
+    let mime = BASE64.wrap(4, "\n").unwrap();
+    assert_eq!(mime.encode_mut_str(b"foob", &mut buffer[.. 10]), "Zm9v\nYg==\n");
+    assert_eq!(mime.encode_nopad_mut_str(b"foob", &mut buffer[.. 6]), "Zm9vYg");
+    let base = data_encoding::base::Spec { val: &[(0, 127)], pad: 128 };
+    assert_eq!(encode::encode_nopad_mut_str(&base, b"\xff", &mut buffer[.. 2]), "\x7f\x40");
+}
+
+#[test]
+#[should_panic]
+fn encode_mut_str_utf8() {
+    let base = data_encoding::base::Spec { val: &[(128, 255)], pad: b'=' };
+    data_encoding::encode::encode_nopad_mut_str(&base, b"\x00", &mut [0; 2]);
+}
+
+#[test]
+fn base128() {
+    use data_encoding::base::{Spec, valid};
+    use data_encoding::decode::{decode_mut, decode_nopad_mut};
+    use data_encoding::decode::Error::*;
+    use data_encoding::encode::{encode_mut, encode_nopad_mut};
+    let base = Spec { val: &[(128, 255)], pad: b'=' };
+    valid(&base).unwrap();
+    fn test(base: &Spec, x: &[u8], y: &[u8]) {
+        let mut output = vec![0u8; y.len()];
+        encode_mut(base, x, &mut output);
+        assert_eq!(output, y);
+        let mut output = vec![0u8; y.len() / 8 * 7];
+        assert_eq!(decode_mut(base, y, &mut output), Ok(x.len()));
+        assert_eq!(&output[.. x.len()], x);
+    }
+    test(&base, b"", b"");
+    test(&base, b"a", b"\xb0\xc0======");
+    test(&base, &[0xff; 7], &[0xff; 8]);
+    test(&base, &[0; 8], b"\x80\x80\x80\x80\x80\x80\x80\x80\x80\x80======");
+    let mut output = [0u8; 2];
+    encode_nopad_mut(&base, b"a", &mut output);
+    assert_eq!(&output, b"\xb0\xc0");
+    let mut output = [0u8; 1];
+    assert_eq!(decode_nopad_mut(&base, b"\xb0\xc0", &mut output), Ok(()));
+    assert_eq!(&output, b"a");
+    let mut output = [0u8; 7];
+    assert_eq!(decode_mut(&base, b"\xb0\xc1======", &mut output), Err(BadPadding));
+    assert_eq!(decode_mut(&base, b"\xb0a======", &mut output), Err(BadCharacter(1)));
+    assert_eq!(decode_mut(&base, b"\xb0\xc0", &mut output), Err(BadLength));
+}
+
+#[test]
+fn encode_bytes() {
+    use std::panic::catch_unwind;
+    use data_encoding::base::Spec;
+    use data_encoding::encode::{encode, encode_bytes, encode_nopad, encode_nopad_bytes};
+    let base = Spec { val: &[(128, 255)], pad: b'=' };
+    assert_eq!(encode_bytes(&base, b"a"), b"\xb0\xc0======");
+    assert_eq!(encode_nopad_bytes(&base, b"a"), b"\xb0\xc0");
+    assert_eq!(encode_bytes(data_encoding::base64::base(), b"foob"), b"Zm9vYg==");
+    assert!(catch_unwind(|| encode(&base, b"")).is_err());
+    assert!(catch_unwind(|| encode_nopad(&base, b"")).is_err());
+    let base = Spec { val: &[(0, 127)], pad: 128 };
+    assert_eq!(encode_nopad(&base, &[0xff; 7]), "\x7f".repeat(8));
+    assert!(catch_unwind(|| encode(&base, &[0xff; 7])).is_err());
+    assert_eq!(encode_bytes(&base, b"\xff"), b"\x7f\x40\x80\x80\x80\x80\x80\x80");
+}
+
+#[test]
+fn unicode() {
+    use data_encoding::decode::Error::*;
+    use data_encoding::unicode::*;
+    let wide = Spec {
+        sym: &[('\u{20000}', '\u{2ffff}')], bit: 16,
+        tail_sym: &[('\u{1500}', '\u{15ff}')], tail: 8,
+    };
+    let dense = Spec {
+        sym: &[('\u{4e00}', '\u{cdff}')], bit: 15,
+        tail_sym: &[('\u{3041}', '\u{30c0}')], tail: 7,
+    };
+    assert!(valid(&wide));
+    assert!(valid(&dense));
+    assert!(!valid(&Spec { tail: 7, ..wide }));
+    assert!(!valid(&Spec { tail_sym: &[('\u{4e00}', '\u{4e7f}')], ..dense }));
+    fn test(spec: &Spec, x: &[u8], y: &str) {
+        assert_eq!(encode(spec, x), y);
+        assert_eq!(encode_chars(spec, x.len()), y.chars().count());
+        assert_eq!(decode(spec, y.as_bytes()).unwrap(), x);
+    }
+    test(&wide, b"", "");
+    test(&wide, b"a", "\u{1561}");
+    test(&wide, b"ab", "\u{26162}");
+    test(&wide, b"abc", "\u{26162}\u{1563}");
+    test(&dense, b"a", "\u{7eff}");
+    test(&dense, b"ab", "\u{7eb1}\u{3080}");
+    test(&dense, b"abcdefg", "\u{7eb1}\u{66d9}\u{5aac}\u{b47f}");
+    test(&dense, b"abcdefghijklmno",
+         "\u{7eb1}\u{66d9}\u{5aac}\u{b476}\u{914b}\u{77ad}\u{a6da}\u{bc6f}");
+    assert_eq!(decode(&dense, b"a"), Err(BadCharacter(0)));
+    assert_eq!(decode(&dense, b"\xe7\xbb"), Err(BadCharacter(0)));
+    assert_eq!(decode(&dense, "\u{3080}\u{7eb1}".as_bytes()), Err(BadCharacter(3)));
+    assert_eq!(decode(&dense, "\u{3080}".as_bytes()), Err(BadLength));
+    assert_eq!(decode(&dense, "\u{7efe}".as_bytes()), Err(BadPadding));
+    let small = Spec {
+        sym: &[('\u{4e00}', '\u{51ff}')], bit: 10,
+        tail_sym: &[('\u{3041}', '\u{3050}')], tail: 4,
+    };
+    assert!(valid(&small));
+    test(&small, b"abc", "\u{4f85}\u{5026}\u{3044}");
+    assert_eq!(decode(&small, "\u{4f85}\u{5026}\u{4eff}".as_bytes()), Err(BadCharacter(6)));
+}
+
 #[test]
 fn exhaustive() {
     use data_encoding::base64::{encode_mut, decode_mut};
@@ -120,6 +2790,17 @@ fn exhaustive() {
     assert_eq!(t, 0x1010100_u32);
 }
 
+#[test]
+fn radix() {
+    use data_encoding::base36::{encode, decode};
+    use data_encoding::base62::{encode_chunked, decode_chunked};
+    for i in 0 .. 0x10000u32 {
+        let x = [(i >> 8) as u8, i as u8];
+        assert_eq!(decode(encode(&x).as_bytes()).unwrap(), x);
+        assert_eq!(decode_chunked(encode_chunked(&x).as_bytes()).unwrap(), x);
+    }
+}
+
 #[test]
 fn nopad() {
     use data_encoding::base64::{encode_nopad, decode_nopad};